@@ -0,0 +1,89 @@
+use std::{fmt, str::FromStr};
+
+use dioxus_logger::tracing;
+use serde::{Deserialize, Serialize};
+
+// ApiError is carried to the client as `ServerFnError::WrappedServerError`,
+// so presentations can match on the variant instead of parsing messages.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ApiError {
+    InvalidCredentials,
+    // seconds until the next login attempt is allowed
+    TooManyAttempts(u64),
+    Unauthorized,
+    // signed in, but the role does not allow the action
+    Forbidden,
+    DuplicateEmail,
+    InvalidPassword,
+    InvalidBusinessNumber,
+    TermsNotAgreed,
+    InvalidVerificationCode,
+    VerificationExpired,
+    NotVerified,
+    InvalidPhoneNumber,
+    InvalidResetToken,
+    InvalidInvitation,
+    // an organization must keep at least one owner
+    LastOwner,
+    NotFound,
+    // the named field of the request is invalid
+    InvalidInput(String),
+    // the survey status does not allow the action
+    InvalidTransition,
+    // the survey is not in progress and does not take answers
+    SurveyClosed,
+    // the respondent already answered the survey
+    AlreadyAnswered,
+    // another request changed the item meanwhile; reload it and try again
+    Conflict,
+    // the details stay on the server, see `ApiError::dynamo` and `ApiError::internal`
+    DynamoException(#[serde(skip)] String),
+    InternalServerError(#[serde(skip)] String),
+}
+
+impl ApiError {
+    // dynamo logs a DynamoDB failure and returns an error which does not reveal it to clients.
+    pub fn dynamo(e: impl fmt::Debug) -> Self {
+        tracing::error!("dynamodb error: {:?}", e);
+        ApiError::DynamoException(format!("{:?}", e))
+    }
+
+    pub fn internal(e: impl fmt::Debug) -> Self {
+        tracing::error!("internal error: {:?}", e);
+        ApiError::InternalServerError(format!("{:?}", e))
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match serde_json::to_string(self) {
+            Ok(s) => write!(f, "{s}"),
+            Err(_) => write!(f, "{self:?}"),
+        }
+    }
+}
+
+impl FromStr for ApiError {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        serde_json::from_str(s).map_err(|e| format!("Invalid api error: {s} ({e})"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn internal_details_are_not_sent() {
+        let e = ApiError::dynamo("ResourceNotFoundException: table voice-korea");
+
+        assert!(!e.to_string().contains("voice-korea"));
+        assert_eq!(
+            e.to_string().parse::<ApiError>(),
+            Ok(ApiError::DynamoException(String::new()))
+        );
+    }
+}
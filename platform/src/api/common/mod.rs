@@ -1,5 +1,11 @@
 use serde::{Deserialize, Serialize};
 
+mod error;
+mod survey_path;
+pub use error::ApiError;
+pub(crate) use survey_path::survey_path;
+pub use survey_path::{path_survey_id, with_survey_id, SurveyGet, SurveyJson, SURVEY_ID};
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CommonQueryResponse<T> {
     pub items: Vec<T>,
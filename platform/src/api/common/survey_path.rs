@@ -0,0 +1,114 @@
+use dioxus::prelude::{
+    server_fn::codec::{Encoding, GetUrl, Json},
+    *,
+};
+use http::Method;
+
+// SURVEY_ID is the path segment of an endpoint which names the survey, such as
+// `/v1/surveys/:survey_id/questions`.
+pub const SURVEY_ID: &str = ":survey_id";
+
+// SurveyJson sends the arguments as JSON in the body of a `POST` request, and the survey in the path.
+// The survey id of the arguments is skipped by serde, see `survey_path!`.
+pub struct SurveyJson;
+
+impl Encoding for SurveyJson {
+    const CONTENT_TYPE: &'static str = Json::CONTENT_TYPE;
+    const METHOD: Method = Method::POST;
+}
+
+// SurveyGet sends a `GET` request for the survey in its path, which is the only argument.
+pub struct SurveyGet;
+
+impl Encoding for SurveyGet {
+    const CONTENT_TYPE: &'static str = GetUrl::CONTENT_TYPE;
+    const METHOD: Method = Method::GET;
+}
+
+// with_survey_id fills the survey segment of an endpoint `path`.
+pub fn with_survey_id(path: &str, survey_id: &str) -> String {
+    path.replace(SURVEY_ID, survey_id)
+}
+
+// path_survey_id returns the survey segment of the path requested to the endpoint `path`.
+pub fn path_survey_id<CustErr>(path: &str) -> Result<String, ServerFnError<CustErr>> {
+    #[cfg(feature = "server")]
+    let requested = server_context().request_parts().uri.path().to_string();
+    #[cfg(not(feature = "server"))]
+    let requested = String::new();
+
+    path.split('/')
+        .zip(requested.split('/'))
+        .find(|(segment, _)| *segment == SURVEY_ID)
+        .map(|(_, survey_id)| survey_id.to_string())
+        .ok_or_else(|| ServerFnError::MissingArg("survey_id".to_string()))
+}
+
+// survey_path implements the encoding of the arguments struct of a server function
+// taking the survey in its path, whose survey id is the field `$field`.
+macro_rules! survey_path {
+    (SurveyJson: $args:ty, $($field:ident).+) => {
+        impl<CustErr, Request> dioxus::prelude::server_fn::codec::IntoReq<$crate::api::common::SurveyJson, Request, CustErr> for $args
+        where
+            Request: dioxus::prelude::server_fn::request::ClientReq<CustErr>,
+        {
+            fn into_req(self, path: &str, accepts: &str) -> Result<Request, dioxus::prelude::ServerFnError<CustErr>> {
+                let path = $crate::api::common::with_survey_id(path, self.$($field).+.as_str());
+                <Self as dioxus::prelude::server_fn::codec::IntoReq<
+                    dioxus::prelude::server_fn::codec::Json,
+                    Request,
+                    CustErr,
+                >>::into_req(self, &path, accepts)
+            }
+        }
+
+        impl<CustErr, Request> dioxus::prelude::server_fn::codec::FromReq<$crate::api::common::SurveyJson, Request, CustErr> for $args
+        where
+            Request: dioxus::prelude::server_fn::request::Req<CustErr> + Send + 'static,
+        {
+            async fn from_req(req: Request) -> Result<Self, dioxus::prelude::ServerFnError<CustErr>> {
+                let survey_id = $crate::api::common::path_survey_id(
+                    <Self as dioxus::prelude::server_fn::ServerFn>::PATH,
+                )?;
+                let mut args = <Self as dioxus::prelude::server_fn::codec::FromReq<
+                    dioxus::prelude::server_fn::codec::Json,
+                    Request,
+                    CustErr,
+                >>::from_req(req)
+                .await?;
+                args.$($field).+ = survey_id;
+
+                Ok(args)
+            }
+        }
+    };
+    (SurveyGet: $args:ty, $field:ident) => {
+        impl<CustErr, Request> dioxus::prelude::server_fn::codec::IntoReq<$crate::api::common::SurveyGet, Request, CustErr> for $args
+        where
+            Request: dioxus::prelude::server_fn::request::ClientReq<CustErr>,
+        {
+            fn into_req(self, path: &str, accepts: &str) -> Result<Request, dioxus::prelude::ServerFnError<CustErr>> {
+                Request::try_new_get(
+                    &$crate::api::common::with_survey_id(path, &self.$field),
+                    accepts,
+                    <$crate::api::common::SurveyGet as dioxus::prelude::server_fn::codec::Encoding>::CONTENT_TYPE,
+                    "",
+                )
+            }
+        }
+
+        impl<CustErr, Request> dioxus::prelude::server_fn::codec::FromReq<$crate::api::common::SurveyGet, Request, CustErr> for $args
+        where
+            Request: dioxus::prelude::server_fn::request::Req<CustErr> + Send + 'static,
+        {
+            async fn from_req(_: Request) -> Result<Self, dioxus::prelude::ServerFnError<CustErr>> {
+                Ok(Self {
+                    $field: $crate::api::common::path_survey_id(
+                        <Self as dioxus::prelude::server_fn::ServerFn>::PATH,
+                    )?,
+                })
+            }
+        }
+    };
+}
+pub(crate) use survey_path;
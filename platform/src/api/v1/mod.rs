@@ -1,3 +1,4 @@
 pub mod attrcerts;
+pub mod organizations;
 pub mod surveys;
 pub mod users;
@@ -0,0 +1,343 @@
+#![allow(unused_imports)]
+use dioxus::prelude::{
+    server_fn::codec::{GetUrl, Json, PostUrl},
+    *,
+};
+use dioxus_logger::tracing;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    api::common::{ApiError, CommonQueryResponse},
+    models::{
+        organization::{Invitation, Member, Organization, OrganizationMembership},
+        role::{Permission, Role, RoleBinding},
+        user::{User, UserProfile},
+    },
+    utils::context::Language,
+};
+
+#[server(endpoint = "/v1/organizations", input = GetUrl, output = Json)]
+pub async fn list_organizations() -> Result<Vec<OrganizationMembership>, ServerFnError<ApiError>> {
+    use crate::utils::{database, session};
+
+    tracing::debug!("/v1/organizations");
+    let now = chrono::Utc::now().timestamp() as u64;
+    let user = session::current_user(now).await?;
+
+    let bindings = CommonQueryResponse::<RoleBinding>::query(
+        &database::log(),
+        "gsi1-index",
+        None,
+        Some(100),
+        vec![("gsi1", RoleBinding::gsi1(&user.id))],
+    )
+    .await
+    .map_err(ApiError::dynamo)?;
+
+    let mut organizations = vec![];
+    for binding in bindings
+        .items
+        .into_iter()
+        .filter(|b| Organization::is_organization_id(&b.resource_id))
+    {
+        if let Some(organization) = database::get::<Organization>(&binding.resource_id).await? {
+            organizations.push(OrganizationMembership {
+                organization,
+                role: binding.role,
+            });
+        }
+    }
+
+    Ok(organizations)
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct CreateOrganizationRequest {
+    pub name: String,
+}
+
+// create_organization creates an organization owned by the caller and switches to it.
+#[server(endpoint = "/v1/organizations/create", input = Json, output = Json)]
+pub async fn create_organization(
+    req: CreateOrganizationRequest,
+) -> Result<UserProfile, ServerFnError<ApiError>> {
+    use crate::utils::{account, database, organization, session};
+
+    tracing::debug!("/v1/organizations/create: {}", req.name);
+    let now = chrono::Utc::now().timestamp() as u64;
+    let mut user = session::current_user(now).await?;
+
+    let organization = organization::create(&req.name, &user.id, now).await?;
+    user.organization_id = organization.id;
+    user.updated_at = now;
+    database::put_fields(&user, &["organization_id", "updated_at"]).await?;
+
+    Ok(account::profile(&user).await?)
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct SwitchOrganizationRequest {
+    pub organization_id: String,
+}
+
+#[server(endpoint = "/v1/organizations/switch", input = Json, output = Json)]
+pub async fn switch_organization(
+    req: SwitchOrganizationRequest,
+) -> Result<UserProfile, ServerFnError<ApiError>> {
+    use crate::utils::{account, authz, database, session};
+
+    tracing::debug!("/v1/organizations/switch: {}", req.organization_id);
+    let now = chrono::Utc::now().timestamp() as u64;
+    let mut user = session::current_user(now).await?;
+
+    authz::require_organization(&user, &req.organization_id, Permission::Read).await?;
+    user.organization_id = req.organization_id;
+    user.updated_at = now;
+    database::put_fields(&user, &["organization_id", "updated_at"]).await?;
+
+    Ok(account::profile(&user).await?)
+}
+
+// list_members lists the members of the current organization.
+#[server(endpoint = "/v1/organizations/members", input = GetUrl, output = Json)]
+pub async fn list_members() -> Result<Vec<Member>, ServerFnError<ApiError>> {
+    use crate::utils::{authz, database, organization, session};
+
+    tracing::debug!("/v1/organizations/members");
+    let now = chrono::Utc::now().timestamp() as u64;
+    let user = session::current_user(now).await?;
+    authz::require_organization(&user, &user.organization_id, Permission::Read).await?;
+
+    let mut members = vec![];
+    for binding in organization::members(&user.organization_id).await? {
+        if let Some(member) = database::get::<User>(&binding.user_id).await? {
+            members.push(Member {
+                user_id: member.id,
+                email: member.email,
+                name: member.name,
+                role: binding.role,
+            });
+        }
+    }
+
+    Ok(members)
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct UpdateMemberRoleRequest {
+    pub user_id: String,
+    pub role: Role,
+}
+
+#[server(endpoint = "/v1/organizations/members/role", input = Json, output = Json)]
+pub async fn update_member_role(
+    req: UpdateMemberRoleRequest,
+) -> Result<(), ServerFnError<ApiError>> {
+    use crate::utils::{audit, authz, database, organization, session};
+
+    tracing::debug!(
+        "/v1/organizations/members/role: {} {:?}",
+        req.user_id,
+        req.role
+    );
+    let now = chrono::Utc::now().timestamp() as u64;
+    let user = session::current_user(now).await?;
+    let organization_id = user.organization_id.clone();
+    authz::require_organization(&user, &organization_id, Permission::ManageMembers).await?;
+
+    let mut binding =
+        database::get::<RoleBinding>(&RoleBinding::key(&organization_id, &req.user_id))
+            .await?
+            .ok_or(ApiError::NotFound)?;
+    if binding.role == Role::Owner && req.role != Role::Owner {
+        organization::ensure_other_owner(&organization_id, &req.user_id).await?;
+    }
+    binding.role = req.role;
+    database::put(&binding).await?;
+
+    audit::record(
+        "update_member_role",
+        &user.id,
+        &organization_id,
+        format!("{} changed to {:?}", req.user_id, req.role),
+        now,
+    )
+    .await?;
+
+    Ok(())
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct RemoveMemberRequest {
+    pub user_id: String,
+}
+
+// remove_member removes a member from the current organization.
+// Any member may remove itself to leave the organization.
+#[server(endpoint = "/v1/organizations/members/remove", input = Json, output = Json)]
+pub async fn remove_member(req: RemoveMemberRequest) -> Result<(), ServerFnError<ApiError>> {
+    use crate::utils::{audit, authz, database, organization, session};
+
+    tracing::debug!("/v1/organizations/members/remove: {}", req.user_id);
+    let now = chrono::Utc::now().timestamp() as u64;
+    let user = session::current_user(now).await?;
+    let organization_id = user.organization_id.clone();
+    if req.user_id != user.id {
+        authz::require_organization(&user, &organization_id, Permission::ManageMembers).await?;
+    }
+
+    let key = RoleBinding::key(&organization_id, &req.user_id);
+    let binding = database::get::<RoleBinding>(&key)
+        .await?
+        .ok_or(ApiError::NotFound)?;
+    if binding.role == Role::Owner {
+        organization::ensure_other_owner(&organization_id, &req.user_id).await?;
+    }
+    database::delete(&key).await?;
+    organization::detach(&req.user_id, &organization_id, now).await?;
+
+    audit::record(
+        "remove_member",
+        &user.id,
+        &organization_id,
+        format!("{} removed", req.user_id),
+        now,
+    )
+    .await?;
+
+    Ok(())
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct InviteMemberRequest {
+    pub email: String,
+    pub role: Role,
+    // language of the invitation link
+    pub lang: Language,
+}
+
+// invite_member mails an invitation link to join the current organization.
+#[server(endpoint = "/v1/organizations/invitations", input = Json, output = Json)]
+pub async fn invite_member(req: InviteMemberRequest) -> Result<(), ServerFnError<ApiError>> {
+    use crate::utils::{
+        audit, authz, database,
+        mail::{mail_sender, Mail},
+        session,
+    };
+
+    tracing::debug!(
+        "/v1/organizations/invitations: {} {:?}",
+        req.email,
+        req.role
+    );
+    let now = chrono::Utc::now().timestamp() as u64;
+    let user = session::current_user(now).await?;
+    authz::require_organization(&user, &user.organization_id, Permission::ManageMembers).await?;
+
+    let organization = database::get::<Organization>(&user.organization_id)
+        .await?
+        .ok_or(ApiError::NotFound)?;
+    let email = req.email.trim().to_lowercase();
+
+    let token = database::new_id("invitation");
+    database::put(&Invitation {
+        id: Invitation::key(&token),
+        r#type: "invitation".to_string(),
+        organization_id: organization.id.clone(),
+        email: email.clone(),
+        role: req.role,
+        invited_by: user.id.clone(),
+        expired_at: now + Invitation::TTL,
+        created_at: now,
+        gsi1: Invitation::gsi1(&organization.id),
+    })
+    .await?;
+
+    let app_url = std::env::var("APP_URL").unwrap_or("http://localhost:8080".to_string());
+    let link = format!("{app_url}/{}/invitations/{token}", req.lang);
+    mail_sender()?
+        .send(Mail {
+            to: email.clone(),
+            subject: format!("[Voice Korea] {} 초대 (Invitation to {})", organization.name, organization.name),
+            body: format!(
+                "{}님이 {}에 초대했습니다.\n아래 링크에서 초대를 수락해 주세요. (7일간 유효)\n\n{} invited you to {}.\nPlease accept the invitation within 7 days.\n\n{link}",
+                user.name, organization.name, user.name, organization.name
+            ),
+        })
+        .await?;
+
+    audit::record(
+        "invite_member",
+        &user.id,
+        &organization.id,
+        format!("{email} invited as {:?}", req.role),
+        now,
+    )
+    .await?;
+
+    Ok(())
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct AcceptInvitationRequest {
+    pub token: String,
+}
+
+// accept_invitation adds the caller to the inviting organization and switches to it.
+// The caller must be signed in with the invited email.
+#[server(endpoint = "/v1/organizations/invitations/accept", input = Json, output = Json)]
+pub async fn accept_invitation(
+    req: AcceptInvitationRequest,
+) -> Result<OrganizationMembership, ServerFnError<ApiError>> {
+    use crate::utils::{audit, database, session};
+
+    tracing::debug!("/v1/organizations/invitations/accept");
+    let now = chrono::Utc::now().timestamp() as u64;
+    let mut user = session::current_user(now).await?;
+
+    let key = Invitation::key(&req.token);
+    let invitation = match database::get::<Invitation>(&key).await? {
+        Some(invitation) if invitation.expired_at > now && invitation.email == user.email => {
+            invitation
+        }
+        _ => return Err(ApiError::InvalidInvitation.into()),
+    };
+    let organization = database::get::<Organization>(&invitation.organization_id)
+        .await?
+        .ok_or(ApiError::InvalidInvitation)?;
+    // invitations are single-use, so of concurrent accepts only the first takes it
+    if database::take::<Invitation>(&key).await?.is_none() {
+        return Err(ApiError::InvalidInvitation.into());
+    }
+
+    // an existing member keeps the higher role
+    let binding_key = RoleBinding::key(&organization.id, &user.id);
+    let role = match database::get::<RoleBinding>(&binding_key).await? {
+        Some(binding) if binding.role.rank() >= invitation.role.rank() => binding.role,
+        _ => {
+            database::put(&RoleBinding::new(
+                &organization.id,
+                &user.id,
+                invitation.role,
+                now,
+            ))
+            .await?;
+            invitation.role
+        }
+    };
+
+    user.organization_id = organization.id.clone();
+    user.updated_at = now;
+    database::put_fields(&user, &["organization_id", "updated_at"]).await?;
+
+    audit::record(
+        "accept_invitation",
+        &user.id,
+        &organization.id,
+        format!("joined as {:?}", role),
+        now,
+    )
+    .await?;
+
+    Ok(OrganizationMembership { organization, role })
+}
@@ -0,0 +1,192 @@
+#![allow(unused_imports)]
+use dioxus::prelude::{
+    server_fn::codec::{GetUrl, Json, PostUrl},
+    *,
+};
+use dioxus_logger::tracing;
+use serde::{Deserialize, Serialize};
+
+use super::CreateSurveyResponse;
+use crate::{
+    api::common::{ApiError, CommonQueryResponse},
+    models::{
+        question::Question,
+        role::Permission,
+        survey::{SurveyStatus, SurveySummary},
+    },
+};
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct UpdateSurveyRequest {
+    pub survey_id: String,
+    pub title: Option<String>,
+    pub description: Option<String>,
+}
+
+// update_survey renames a survey or edits its description.
+#[server(endpoint = "/v1/surveys/update", input = Json, output = Json)]
+pub async fn update_survey(
+    req: UpdateSurveyRequest,
+) -> Result<SurveySummary, ServerFnError<ApiError>> {
+    use crate::utils::{audit, authz, database, session};
+
+    tracing::debug!("/v1/surveys/update: {:?}", req);
+    let now = chrono::Utc::now().timestamp() as u64;
+    let user = session::current_user(now).await?;
+    let mut survey = authz::load_survey(&user, &req.survey_id, Permission::Edit).await?;
+
+    if let Some(title) = req.title {
+        let title = title.trim().to_string();
+        if title.is_empty() {
+            return Err(ApiError::InvalidInput("title".to_string()).into());
+        }
+        survey.title = title;
+    }
+    if let Some(description) = req.description {
+        survey.description = description.trim().to_string();
+    }
+    survey.updated_at = now;
+    database::put_fields(&survey, &["title", "description", "updated_at"]).await?;
+
+    audit::record(
+        "update_survey",
+        &user.id,
+        &survey.id,
+        survey.title.clone(),
+        now,
+    )
+    .await?;
+
+    Ok(survey)
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct DuplicateSurveyRequest {
+    pub survey_id: String,
+}
+
+// duplicate_survey copies a survey with its questions and quotas into a new draft
+// of the current organization.
+#[server(endpoint = "/v1/surveys/duplicate", input = Json, output = Json)]
+pub async fn duplicate_survey(
+    req: DuplicateSurveyRequest,
+) -> Result<CreateSurveyResponse, ServerFnError<ApiError>> {
+    use crate::utils::{audit, authz, database, question, session};
+    use std::collections::HashMap;
+
+    tracing::debug!("/v1/surveys/duplicate: {}", req.survey_id);
+    let now = chrono::Utc::now().timestamp() as u64;
+    let user = session::current_user(now).await?;
+    let source = authz::load_survey(&user, &req.survey_id, Permission::Read).await?;
+    authz::require_organization(&user, &user.organization_id, Permission::Edit).await?;
+
+    let questions = question::list(&source.id).await?;
+
+    let id = database::new_id("survey");
+    let mut survey = SurveySummary {
+        id: id.clone(),
+        status: SurveyStatus::Draft,
+        updated_at: now,
+        responses: None,
+        archived_at: None,
+        schedule: None,
+        gsi1: user.organization_id.clone(),
+        ..source.clone()
+    };
+    survey.gsi2 = survey.status_index();
+
+    // the logic of the copies refers to the new question ids
+    let ids: HashMap<String, String> = questions
+        .iter()
+        .map(|q| (q.id.clone(), database::new_id("question")))
+        .collect();
+    let mut questions: Vec<Question> = questions
+        .into_iter()
+        .map(|mut question| {
+            question.logic.rename(&ids);
+            Question {
+                id: ids[&question.id].clone(),
+                survey_id: id.clone(),
+                gsi1: id.clone(),
+                ..question
+            }
+        })
+        .collect();
+    // the new survey is stored first, as its questions are synced against it
+    database::put(&survey).await?;
+    question::sync(&mut survey, &mut questions, &[], now).await?;
+
+    audit::record(
+        "duplicate_survey",
+        &user.id,
+        &id,
+        format!("duplicated from {}", source.id),
+        now,
+    )
+    .await?;
+
+    Ok(CreateSurveyResponse { id })
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ArchiveSurveyRequest {
+    pub survey_id: String,
+}
+
+// archive_survey soft-deletes a survey. It is kept with its questions and responses
+// and can be brought back by `restore_survey`.
+#[server(endpoint = "/v1/surveys/archive", input = Json, output = Json)]
+pub async fn archive_survey(req: ArchiveSurveyRequest) -> Result<(), ServerFnError<ApiError>> {
+    use crate::utils::{audit, authz, database, session};
+
+    tracing::debug!("/v1/surveys/archive: {}", req.survey_id);
+    let now = chrono::Utc::now().timestamp() as u64;
+    let user = session::current_user(now).await?;
+    let mut survey = authz::load_survey(&user, &req.survey_id, Permission::Edit).await?;
+
+    if survey.archived_at.is_none() {
+        survey.archived_at = Some(now);
+        survey.gsi2 = survey.status_index();
+        survey.updated_at = now;
+        database::put_fields(&survey, &["archived_at", "gsi2", "updated_at"]).await?;
+
+        audit::record(
+            "archive_survey",
+            &user.id,
+            &survey.id,
+            "archived".to_string(),
+            now,
+        )
+        .await?;
+    }
+
+    Ok(())
+}
+
+#[server(endpoint = "/v1/surveys/restore", input = Json, output = Json)]
+pub async fn restore_survey(req: ArchiveSurveyRequest) -> Result<(), ServerFnError<ApiError>> {
+    use crate::utils::{audit, authz, database, session};
+
+    tracing::debug!("/v1/surveys/restore: {}", req.survey_id);
+    let now = chrono::Utc::now().timestamp() as u64;
+    let user = session::current_user(now).await?;
+    let mut survey = authz::load_survey(&user, &req.survey_id, Permission::Edit).await?;
+
+    if survey.archived_at.is_some() {
+        survey.archived_at = None;
+        survey.gsi2 = survey.status_index();
+        survey.updated_at = now;
+        database::put_fields(&survey, &["archived_at", "gsi2", "updated_at"]).await?;
+
+        audit::record(
+            "restore_survey",
+            &user.id,
+            &survey.id,
+            "restored".to_string(),
+            now,
+        )
+        .await?;
+    }
+
+    Ok(())
+}
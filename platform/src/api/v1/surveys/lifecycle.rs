@@ -0,0 +1,67 @@
+#![allow(unused_imports)]
+use dioxus::prelude::{
+    server_fn::codec::{GetUrl, Json, PostUrl},
+    *,
+};
+use dioxus_logger::tracing;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    api::common::{ApiError, CommonQueryResponse},
+    models::{
+        audit::AuditLog,
+        role::Permission,
+        survey::{SurveyAction, SurveySummary},
+    },
+};
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct TransitionSurveyRequest {
+    pub survey_id: String,
+    pub action: SurveyAction,
+    // new end time for launch, resume and reopen
+    pub ended_at: Option<u64>,
+}
+
+// transition_survey launches, pauses, resumes, closes or reopens a survey.
+#[server(endpoint = "/v1/surveys/transition", input = Json, output = Json)]
+pub async fn transition_survey(
+    req: TransitionSurveyRequest,
+) -> Result<SurveySummary, ServerFnError<ApiError>> {
+    use crate::utils::{authz, lifecycle, session};
+
+    tracing::debug!("/v1/surveys/transition: {:?}", req);
+    let now = chrono::Utc::now().timestamp() as u64;
+    let user = session::current_user(now).await?;
+    let mut survey = authz::load_survey(&user, &req.survey_id, Permission::Launch).await?;
+
+    lifecycle::transition(&mut survey, req.action, req.ended_at, &user.id, now).await?;
+
+    Ok(survey)
+}
+
+// list_survey_history lists the audit trail of a survey, e.g. its status transitions.
+#[server(endpoint = "/v1/surveys/history", input = GetUrl, output = Json)]
+pub async fn list_survey_history(
+    survey_id: String,
+    bookmark: Option<String>,
+) -> Result<CommonQueryResponse<AuditLog>, ServerFnError<ApiError>> {
+    use crate::utils::{authz, database, session};
+
+    tracing::debug!("/v1/surveys/history: {}", survey_id);
+    let now = chrono::Utc::now().timestamp() as u64;
+    let user = session::current_user(now).await?;
+    let survey = authz::load_survey(&user, &survey_id, Permission::Read).await?;
+
+    let res = CommonQueryResponse::<AuditLog>::query(
+        &database::log(),
+        "gsi1-index",
+        bookmark,
+        Some(50),
+        vec![("gsi1", AuditLog::gsi1(&survey.id))],
+    )
+    .await
+    .map_err(ApiError::dynamo)?;
+
+    Ok(res)
+}
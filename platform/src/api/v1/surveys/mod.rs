@@ -1,4 +1,12 @@
 #![allow(unused_imports)]
+pub mod edit;
+pub mod lifecycle;
+pub mod questions;
+pub mod respond;
+pub mod results;
+pub mod roles;
+pub mod schedule;
+
 use dioxus::prelude::{
     server_fn::codec::{GetUrl, Json, PostUrl},
     *,
@@ -7,123 +15,151 @@ use dioxus_logger::tracing;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    api::common::CommonQueryResponse,
+    api::common::{survey_path, ApiError, CommonQueryResponse, SurveyGet},
     models::{
-        question::{Question, QuestionAnswer, QuestionType},
-        survey::{Age, Gender, ProofId, Quota, SurveyStatus, SurveySummary},
+        question::{validate_answers, Question, QuestionAnswer, QuestionType},
+        response::SurveyResponse,
+        role::Permission,
+        survey::{ProofId, SurveyStatus, SurveySummary},
     },
 };
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
-#[serde(untagged)]
 pub enum Status {
     #[serde(rename = "draft")]
     Draft,
     #[serde(rename = "in_progress")]
     InProgress,
+    #[serde(rename = "paused")]
+    Paused,
     #[serde(rename = "finished")]
     Finished,
+    #[serde(rename = "archived")]
+    Archived,
+}
+
+impl Status {
+    // name matches `SurveyStatus::name`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Status::Draft => "draft",
+            Status::InProgress => "in_progress",
+            Status::Paused => "paused",
+            Status::Finished => "finished",
+            Status::Archived => SurveySummary::ARCHIVED,
+        }
+    }
 }
 
+// list_surveys lists the surveys of the current organization, optionally filtered by status.
+// Pass the returned `bookmark` to fetch the next page.
 #[server(endpoint = "/v1/surveys", input = GetUrl, output = Json)]
 pub async fn list_surveys(
-    _size: Option<i32>,
-    _bookmark: Option<String>,
-    _status: Option<Status>,
-) -> Result<CommonQueryResponse<SurveySummary>, ServerFnError> {
-    dioxus_logger::tracing::debug!("/v1/surveys",);
-
-    Ok(CommonQueryResponse {
-        items: vec![
-            SurveySummary {
-                id: "survey-id".to_string(),
-                title: "설문지 타이틀".to_string(),
-                status: SurveyStatus::Finished,
-                updated_at: 1725548400,
-                responses: Some(500),
-                expected_responses: Some(500),
-                questions: 1,
-                quotas: Some(vec![Quota::Attribute {
-                    salary_tier: Some(1),
-                    region_code: Some(02),
-                    gender: Some(Gender::Male),
-                    age: Some(Age::Specific(20)),
-                    quota: 500,
-                }]),
-                r#type: "survey".to_string(),
-                gsi1: "account-id".to_string(),
-                gsi2: "status".to_string(),
-            },
-            SurveySummary {
-                id: "survey-id2".to_string(),
-                title: "설문지 타이틀2".to_string(),
-                status: SurveyStatus::Draft,
-                updated_at: 1725548400,
-                responses: None,
-                expected_responses: None,
-                questions: 1,
-                quotas: Some(vec![Quota::Attribute {
-                    salary_tier: Some(1),
-                    region_code: Some(02),
-                    gender: Some(Gender::Male),
-                    age: Some(Age::Specific(20)),
-                    quota: 500,
-                }]),
-                r#type: "survey".to_string(),
-                gsi1: "account-id".to_string(),
-                gsi2: "status".to_string(),
-            },
-            SurveySummary {
-                id: "survey-id3".to_string(),
-                title: "설문지 타이틀2".to_string(),
-                status: SurveyStatus::InProgress {
-                    started_at: 1725548400,
-                    ended_at: Some(1825548400),
-                },
-                updated_at: 1725548400,
-                responses: Some(100),
-                expected_responses: Some(200),
-                questions: 3,
-                quotas: Some(vec![
-                    Quota::Attribute {
-                        salary_tier: Some(1),
-                        region_code: Some(02),
-                        gender: Some(Gender::Female),
-                        age: Some(Age::Specific(20)),
-                        quota: 100,
-                    },
-                    Quota::Attribute {
-                        salary_tier: Some(1),
-                        region_code: Some(02),
-                        gender: Some(Gender::Male),
-                        age: Some(Age::Range {
-                            inclusive_min: 20,
-                            inclusive_max: 30,
-                        }),
-                        quota: 100,
-                    },
-                    Quota::Panel("proof-id".to_string()),
-                ]),
-                r#type: "survey".to_string(),
-                gsi1: "account-id".to_string(),
-                gsi2: "status".to_string(),
-            },
-        ],
-        bookmark: None,
-    })
+    size: Option<i32>,
+    bookmark: Option<String>,
+    status: Option<Status>,
+) -> Result<CommonQueryResponse<SurveySummary>, ServerFnError<ApiError>> {
+    use crate::utils::{authz, session, survey};
+
+    dioxus_logger::tracing::debug!("/v1/surveys: {:?} {:?} {:?}", size, bookmark, status);
+    let now = chrono::Utc::now().timestamp() as u64;
+    let user = session::current_user(now).await?;
+    let organization_id = user.organization_id.clone();
+    authz::require_organization(&user, &organization_id, Permission::Read).await?;
+
+    // archived surveys are listed only with `Status::Archived`
+    Ok(survey::list(
+        &organization_id,
+        status.map(|s| s.name()),
+        size.unwrap_or(survey::MAX_PAGE_SIZE),
+        bookmark,
+        now,
+    )
+    .await?)
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct NewQuestion {
+    pub title: String,
+    pub question: QuestionType,
+    #[serde(default)]
+    pub required: bool,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct CreateSurveyRequest {
+    pub title: String,
+    pub description: String,
+    pub questions: Vec<NewQuestion>,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
-pub struct CreateSurveyRequest {}
+pub struct CreateSurveyResponse {
+    pub id: String,
+}
+
+// create_survey creates a draft survey in the current organization.
+#[server(endpoint = "/v1/surveys/create", input = Json, output = Json)]
+pub async fn create_survey(
+    req: CreateSurveyRequest,
+) -> Result<CreateSurveyResponse, ServerFnError<ApiError>> {
+    use crate::utils::{audit, authz, database, question, session};
+
+    tracing::debug!("/v1/surveys/create: {:?}", req);
+    let now = chrono::Utc::now().timestamp() as u64;
+    let user = session::current_user(now).await?;
+    authz::require_organization(&user, &user.organization_id, Permission::Edit).await?;
+
+    let title = req.title.trim().to_string();
+    if title.is_empty() {
+        return Err(ApiError::InvalidInput("title".to_string()).into());
+    }
 
-#[server(endpoint = "/v1/surveys", input = Json, output = Json)]
-pub async fn create_survey(req: CreateSurveyRequest) -> Result<(), ServerFnError> {
-    tracing::debug!("/v1/surveys: {:?}", req);
+    let id = database::new_id("survey");
+    let mut questions: Vec<Question> = req
+        .questions
+        .into_iter()
+        .enumerate()
+        .map(|(position, q)| Question {
+            id: database::new_id("question"),
+            r#type: "question".to_string(),
+            survey_id: id.clone(),
+            title: q.title.trim().to_string(),
+            question: q.question,
+            required: q.required,
+            position: position as u32,
+            logic: Default::default(),
+            carry_forward: None,
+            gsi1: id.clone(),
+        })
+        .collect();
+    question::validate_all(&questions)?;
 
-    Err(ServerFnError::ServerError(
-        "not implemented yet".to_string(),
-    ))
+    let status = SurveyStatus::Draft;
+    let mut survey = SurveySummary {
+        id: id.clone(),
+        title,
+        description: req.description.trim().to_string(),
+        updated_at: now,
+        questions: 0,
+        responses: None,
+        expected_responses: None,
+        quotas: None,
+        archived_at: None,
+        schedule: None,
+        r#type: "survey".to_string(),
+        gsi1: user.organization_id.clone(),
+        gsi2: SurveySummary::gsi2(&user.organization_id, status.name()),
+        status,
+    };
+    // the new survey is stored first, as its questions are synced against it
+    database::put(&survey).await?;
+    question::sync(&mut survey, &mut questions, &[], now).await?;
+
+    audit::record("create_survey", &user.id, &id, survey.title, now).await?;
+
+    Ok(CreateSurveyResponse { id })
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -133,106 +169,81 @@ pub struct GetSurveyResponse {
     pub questions: Vec<Question>,
 }
 
-#[server(endpoint = "/v1/surveys/:survey-id", input = GetUrl, output = Json)]
-pub async fn get_survey() -> Result<GetSurveyResponse, ServerFnError> {
-    dioxus_logger::tracing::debug!("/v1/surveys/:survey-id:");
+// get_survey loads a survey with its questions.
+#[server(endpoint = "/v1/surveys/:survey_id", input = SurveyGet, output = Json)]
+pub async fn get_survey(survey_id: String) -> Result<GetSurveyResponse, ServerFnError<ApiError>> {
+    use crate::utils::{authz, lifecycle, question, session};
+
+    dioxus_logger::tracing::debug!("/v1/surveys/{}", survey_id);
+    let now = chrono::Utc::now().timestamp() as u64;
+    let user = session::current_user(now).await?;
+
+    let mut survey = authz::load_survey(&user, &survey_id, Permission::Read).await?;
+    lifecycle::close_if_due(&mut survey, now).await?;
+
+    let questions = question::list(&survey.id).await?;
+
+    // responders are the panels the survey is sent to
+    let responders = survey.panels().into_iter().cloned().collect();
 
     Ok(GetSurveyResponse {
-        survey: SurveySummary {
-            id: "survey-id3".to_string(),
-            title: "설문지 타이틀2".to_string(),
-            status: SurveyStatus::InProgress {
-                started_at: 1725548400,
-                ended_at: Some(1825548400),
-            },
-            updated_at: 1725548400,
-            responses: Some(100),
-            expected_responses: Some(200),
-            questions: 3,
-            quotas: Some(vec![
-                Quota::Attribute {
-                    salary_tier: Some(1),
-                    region_code: Some(02),
-                    gender: Some(Gender::Female),
-                    age: Some(Age::Specific(20)),
-                    quota: 100,
-                },
-                Quota::Attribute {
-                    salary_tier: Some(1),
-                    region_code: Some(02),
-                    gender: Some(Gender::Male),
-                    age: Some(Age::Range {
-                        inclusive_min: 20,
-                        inclusive_max: 30,
-                    }),
-                    quota: 100,
-                },
-                Quota::Panel("proof-id".to_string()),
-            ]),
-            r#type: "survey".to_string(),
-            gsi1: "account-id".to_string(),
-            gsi2: "status".to_string(),
-        },
-        responders: vec!["proof-id".to_string()],
-        questions: vec![
-            Question {
-                id: "question-id".to_string(),
-                survey_id: "survey-id3".to_string(),
-                title: "질문 타이틀".to_string(),
-                question: QuestionType::Text(Some("질문 내용".to_string())),
-                gsi1: "survey-id3".to_string(),
-            },
-            Question {
-                id: "question-id".to_string(),
-                survey_id: "survey-id3".to_string(),
-                title: "질문 타이틀".to_string(),
-                question: QuestionType::Text(None),
-                gsi1: "survey-id3".to_string(),
-            },
-            Question {
-                id: "question-id".to_string(),
-                survey_id: "survey-id3".to_string(),
-                title: "질문 타이틀".to_string(),
-                question: QuestionType::LongText(Some("질문 내용".to_string())),
-                gsi1: "survey-id3".to_string(),
-            },
-            Question {
-                id: "question-id".to_string(),
-                survey_id: "survey-id3".to_string(),
-                title: "질문 타이틀".to_string(),
-                question: QuestionType::LongText(None),
-                gsi1: "survey-id3".to_string(),
-            },
-            Question {
-                id: "question-id".to_string(),
-                survey_id: "survey-id3".to_string(),
-                title: "질문 타이틀".to_string(),
-                question: QuestionType::SingleChoice {
-                    question: Some("질문 내용".to_string()),
-                    options: vec!["선택지1".to_string(), "선택지2".to_string()],
-                },
-                gsi1: "survey-id3".to_string(),
-            },
-            Question {
-                id: "question-id".to_string(),
-                survey_id: "survey-id3".to_string(),
-                title: "질문 타이틀".to_string(),
-                question: QuestionType::SingleChoice {
-                    question: None,
-                    options: vec!["선택지1".to_string(), "선택지2".to_string()],
-                },
-                gsi1: "survey-id3".to_string(),
-            },
-        ],
+        survey,
+        responders,
+        questions,
     })
 }
 
+survey_path!(SurveyGet: GetSurvey, survey_id);
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct AnswerSurveyRequest {
+    pub survey_id: String,
+    // the respondent token of `SurveyForm::token`
+    pub token: String,
+    // in the order of `GetSurveyResponse::questions`; `None` skips an optional question
+    pub answers: Vec<Option<QuestionAnswer>>,
+}
+
+// answer_survey stores the answers of a respondent to an in-progress survey.
+// The respondent is the one of the token issued with the survey form, and can answer only once.
 #[server(endpoint = "/v1/surveys/answer", input = Json, output = Json)]
-pub async fn answer_survey(
-    survey_id: String,
-    answers: Vec<QuestionAnswer>,
-) -> Result<(), ServerFnError> {
-    dioxus_logger::tracing::debug!("/v1/surveys/:survey-id: {} {:?}", survey_id, answers);
+pub async fn answer_survey(req: AnswerSurveyRequest) -> Result<(), ServerFnError<ApiError>> {
+    use crate::utils::{database, lifecycle, question, session::Respondent};
+
+    dioxus_logger::tracing::debug!("/v1/surveys/answer: {} {:?}", req.survey_id, req.answers);
+    let now = chrono::Utc::now().timestamp() as u64;
+    let respondent = Respondent::from_token(&req.token)
+        .filter(|r| r.survey_id == req.survey_id)
+        .ok_or(ApiError::InvalidInput("token".to_string()))?;
+    let mut survey = database::get_typed::<SurveySummary>(&req.survey_id, "survey")
+        .await?
+        .filter(|s| s.archived_at.is_none())
+        .ok_or(ApiError::NotFound)?;
+
+    lifecycle::close_if_due(&mut survey, now).await?;
+    if !matches!(survey.status, SurveyStatus::InProgress { .. }) {
+        return Err(ApiError::SurveyClosed.into());
+    }
+
+    let panels = survey.panels();
+    if !panels.is_empty() && !panels.contains(&&respondent.proof_id) {
+        return Err(ApiError::Forbidden.into());
+    }
+
+    let questions = question::list(&survey.id).await?;
+    if let Err(i) = validate_answers(&questions, &req.answers) {
+        return Err(ApiError::InvalidInput(format!("answers[{i}]")).into());
+    }
+
+    // of the same respondent answering twice at once, only one is stored
+    let response = SurveyResponse::new(&survey.id, &respondent.proof_id, req.answers, now);
+    if !database::put_if_absent(&response).await? {
+        return Err(ApiError::AlreadyAnswered.into());
+    }
+
+    // counted in one write, so that exactly one response fills the quotas
+    let mut survey = database::add::<SurveySummary>(&survey.id, "responses", 1).await?;
+    lifecycle::close_if_due(&mut survey, now).await?;
 
     Ok(())
 }
@@ -0,0 +1,346 @@
+#![allow(unused_imports)]
+use dioxus::prelude::{
+    server_fn::codec::{GetUrl, Json, PostUrl},
+    *,
+};
+use dioxus_logger::tracing;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    api::common::{survey_path, ApiError, SurveyGet, SurveyJson},
+    models::{
+        question::{Question, QuestionLogic, QuestionType, MAX_QUESTIONS},
+        role::Permission,
+    },
+};
+
+// QuestionDraft is a question being edited; `id` is None for a new one.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct QuestionDraft {
+    pub id: Option<String>,
+    pub title: String,
+    pub question: QuestionType,
+    pub required: bool,
+    #[serde(default)]
+    pub logic: QuestionLogic,
+    // the options are the ones selected in this earlier question, counted from 1
+    #[serde(default)]
+    pub carry_forward: Option<u32>,
+}
+
+impl From<Question> for QuestionDraft {
+    fn from(q: Question) -> Self {
+        QuestionDraft {
+            id: Some(q.id),
+            title: q.title,
+            question: q.question,
+            required: q.required,
+            logic: q.logic,
+            carry_forward: q.carry_forward,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct SaveQuestionsRequest {
+    // sent in the path
+    #[serde(skip)]
+    pub survey_id: String,
+    // in the order to show to respondents
+    pub questions: Vec<QuestionDraft>,
+}
+
+// list_questions lists the questions of a survey in their order.
+#[server(endpoint = "/v1/surveys/:survey_id/questions", input = SurveyGet, output = Json)]
+pub async fn list_questions(survey_id: String) -> Result<Vec<Question>, ServerFnError<ApiError>> {
+    use crate::utils::{authz, question, session};
+
+    tracing::debug!("/v1/surveys/{}/questions", survey_id);
+    let now = chrono::Utc::now().timestamp() as u64;
+    let user = session::current_user(now).await?;
+    let survey = authz::load_survey(&user, &survey_id, Permission::Read).await?;
+
+    Ok(question::list(&survey.id).await?)
+}
+
+survey_path!(SurveyGet: ListQuestions, survey_id);
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct CreateQuestionRequest {
+    #[serde(skip)]
+    pub survey_id: String,
+    pub title: String,
+    pub question: QuestionType,
+    pub required: bool,
+    // inserts before the question at `position`; None appends it
+    pub position: Option<u32>,
+}
+
+#[server(
+    endpoint = "/v1/surveys/:survey_id/questions/create",
+    input = SurveyJson,
+    output = Json
+)]
+pub async fn create_question(
+    req: CreateQuestionRequest,
+) -> Result<Question, ServerFnError<ApiError>> {
+    use crate::utils::{audit, database, question, session};
+
+    tracing::debug!("/v1/surveys/{}/questions/create: {:?}", req.survey_id, req);
+    let now = chrono::Utc::now().timestamp() as u64;
+    let user = session::current_user(now).await?;
+    let mut survey = question::load_editable(&user, &req.survey_id).await?;
+    question::validate("question", &req.title, &req.question)?;
+
+    let existing = question::list(&survey.id).await?;
+    let mut questions = existing.clone();
+    if questions.len() >= MAX_QUESTIONS {
+        return Err(ApiError::InvalidInput("questions".to_string()).into());
+    }
+    let position = req
+        .position
+        .map(|p| (p as usize).min(questions.len()))
+        .unwrap_or(questions.len());
+    let created = Question {
+        id: database::new_id("question"),
+        r#type: "question".to_string(),
+        survey_id: survey.id.clone(),
+        title: req.title.trim().to_string(),
+        question: req.question,
+        required: req.required,
+        position: position as u32,
+        logic: QuestionLogic::default(),
+        carry_forward: None,
+        gsi1: survey.id.clone(),
+    };
+    questions.insert(position, created);
+    question::renumber(&mut questions, &existing);
+    question::validate_logic(&questions)?;
+
+    question::sync(&mut survey, &mut questions, &existing, now).await?;
+    let created = questions[position].clone();
+    audit::record(
+        "create_question",
+        &user.id,
+        &survey.id,
+        created.id.clone(),
+        now,
+    )
+    .await?;
+
+    Ok(created)
+}
+
+survey_path!(SurveyJson: CreateQuestion, req.survey_id);
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct UpdateQuestionRequest {
+    #[serde(skip)]
+    pub survey_id: String,
+    pub question_id: String,
+    pub title: String,
+    pub question: QuestionType,
+    pub required: bool,
+    #[serde(default)]
+    pub logic: QuestionLogic,
+    #[serde(default)]
+    pub carry_forward: Option<u32>,
+}
+
+#[server(
+    endpoint = "/v1/surveys/:survey_id/questions/update",
+    input = SurveyJson,
+    output = Json
+)]
+pub async fn update_question(
+    req: UpdateQuestionRequest,
+) -> Result<Question, ServerFnError<ApiError>> {
+    use crate::utils::{audit, database, question, session};
+
+    tracing::debug!("/v1/surveys/{}/questions/update: {:?}", req.survey_id, req);
+    let now = chrono::Utc::now().timestamp() as u64;
+    let user = session::current_user(now).await?;
+    let mut survey = question::load_editable(&user, &req.survey_id).await?;
+    question::validate("question", &req.title, &req.question)?;
+
+    let existing = question::list(&survey.id).await?;
+    let mut questions = existing.clone();
+    let index = questions
+        .iter()
+        .position(|q| q.id == req.question_id)
+        .ok_or(ApiError::NotFound)?;
+    let updated = &mut questions[index];
+    updated.title = req.title.trim().to_string();
+    updated.question = req.question;
+    updated.required = req.required;
+    updated.logic = req.logic;
+    updated.carry_forward = req.carry_forward;
+    question::validate_logic(&questions)?;
+
+    // the questions carrying forward its options are stored with them
+    question::sync(&mut survey, &mut questions, &existing, now).await?;
+    let updated = questions[index].clone();
+    audit::record(
+        "update_question",
+        &user.id,
+        &survey.id,
+        updated.id.clone(),
+        now,
+    )
+    .await?;
+
+    Ok(updated)
+}
+
+survey_path!(SurveyJson: UpdateQuestion, req.survey_id);
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct DeleteQuestionRequest {
+    #[serde(skip)]
+    pub survey_id: String,
+    pub question_id: String,
+}
+
+#[server(
+    endpoint = "/v1/surveys/:survey_id/questions/delete",
+    input = SurveyJson,
+    output = Json
+)]
+pub async fn delete_question(req: DeleteQuestionRequest) -> Result<(), ServerFnError<ApiError>> {
+    use crate::utils::{audit, database, question, session};
+
+    tracing::debug!("/v1/surveys/{}/questions/delete: {:?}", req.survey_id, req);
+    let now = chrono::Utc::now().timestamp() as u64;
+    let user = session::current_user(now).await?;
+    let mut survey = question::load_editable(&user, &req.survey_id).await?;
+
+    let existing = question::list(&survey.id).await?;
+    let mut questions = existing.clone();
+    let index = questions
+        .iter()
+        .position(|q| q.id == req.question_id)
+        .ok_or(ApiError::NotFound)?;
+    questions.remove(index);
+    question::renumber(&mut questions, &existing);
+    question::validate_logic(&questions)?;
+
+    question::sync(&mut survey, &mut questions, &existing, now).await?;
+    audit::record(
+        "delete_question",
+        &user.id,
+        &survey.id,
+        req.question_id,
+        now,
+    )
+    .await?;
+
+    Ok(())
+}
+
+survey_path!(SurveyJson: DeleteQuestion, req.survey_id);
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct MoveQuestionRequest {
+    #[serde(skip)]
+    pub survey_id: String,
+    pub question_id: String,
+    // the new position, counted from 0
+    pub position: u32,
+}
+
+// move_question moves a question to `position` and returns the questions in their new order.
+#[server(
+    endpoint = "/v1/surveys/:survey_id/questions/move",
+    input = SurveyJson,
+    output = Json
+)]
+pub async fn move_question(
+    req: MoveQuestionRequest,
+) -> Result<Vec<Question>, ServerFnError<ApiError>> {
+    use crate::utils::{question, session};
+
+    tracing::debug!("/v1/surveys/{}/questions/move: {:?}", req.survey_id, req);
+    let now = chrono::Utc::now().timestamp() as u64;
+    let user = session::current_user(now).await?;
+    let mut survey = question::load_editable(&user, &req.survey_id).await?;
+
+    let existing = question::list(&survey.id).await?;
+    let mut questions = existing.clone();
+    let index = questions
+        .iter()
+        .position(|q| q.id == req.question_id)
+        .ok_or(ApiError::NotFound)?;
+    let moved = questions.remove(index);
+    let position = (req.position as usize).min(questions.len());
+    questions.insert(position, moved);
+    question::renumber(&mut questions, &existing);
+    question::validate_logic(&questions)?;
+
+    question::sync(&mut survey, &mut questions, &existing, now).await?;
+
+    Ok(questions)
+}
+
+survey_path!(SurveyJson: MoveQuestion, req.survey_id);
+
+// save_questions replaces the questions of a survey with `questions`:
+// new ones are created, existing ones updated and the missing ones deleted.
+#[server(
+    endpoint = "/v1/surveys/:survey_id/questions/save",
+    input = SurveyJson,
+    output = Json
+)]
+pub async fn save_questions(
+    req: SaveQuestionsRequest,
+) -> Result<Vec<Question>, ServerFnError<ApiError>> {
+    use crate::utils::{audit, database, question, session};
+
+    tracing::debug!("/v1/surveys/{}/questions/save: {:?}", req.survey_id, req);
+    let now = chrono::Utc::now().timestamp() as u64;
+    let user = session::current_user(now).await?;
+    let mut survey = question::load_editable(&user, &req.survey_id).await?;
+
+    let existing = question::list(&survey.id).await?;
+    if req
+        .questions
+        .iter()
+        .filter_map(|q| q.id.as_ref())
+        .any(|id| !existing.iter().any(|e| &e.id == id))
+    {
+        return Err(ApiError::NotFound.into());
+    }
+
+    let mut questions: Vec<Question> = req
+        .questions
+        .into_iter()
+        .enumerate()
+        .map(|(position, q)| Question {
+            id: q.id.unwrap_or_else(|| database::new_id("question")),
+            r#type: "question".to_string(),
+            survey_id: survey.id.clone(),
+            title: q.title.trim().to_string(),
+            question: q.question,
+            required: q.required,
+            position: position as u32,
+            logic: q.logic,
+            carry_forward: q.carry_forward,
+            gsi1: survey.id.clone(),
+        })
+        .collect();
+    question::validate_all(&questions)?;
+
+    // the editor numbers the references in the order it sends
+    question::sync(&mut survey, &mut questions, &existing, now).await?;
+    audit::record(
+        "save_questions",
+        &user.id,
+        &survey.id,
+        format!("{} questions", questions.len()),
+        now,
+    )
+    .await?;
+
+    Ok(questions)
+}
+
+survey_path!(SurveyJson: SaveQuestions, req.survey_id);
@@ -0,0 +1,96 @@
+#![allow(unused_imports)]
+use dioxus::prelude::{
+    server_fn::codec::{GetUrl, Json, PostUrl},
+    *,
+};
+use dioxus_logger::tracing;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    api::common::ApiError,
+    models::{
+        question::Question,
+        survey::{SurveyStatus, SurveySummary},
+    },
+};
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FormStatus {
+    Open,
+    // paused or finished before filling its quotas
+    Closed,
+    // finished with its quotas filled, see `SurveySummary::quota_filled`
+    QuotaFull,
+}
+
+// SurveyForm is what a respondent sees of a survey, without its quotas or panels.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SurveyForm {
+    pub id: String,
+    pub title: String,
+    pub description: String,
+    pub status: FormStatus,
+    // in the order of the answers expected by `answer_survey`
+    pub questions: Vec<Question>,
+    // identifies the respondent to `answer_survey`; keep it to answer as the same respondent
+    pub token: String,
+}
+
+impl SurveyForm {
+    pub fn status(survey: &SurveySummary) -> FormStatus {
+        match survey.status {
+            SurveyStatus::InProgress { .. } => FormStatus::Open,
+            _ if survey.quota_filled() => FormStatus::QuotaFull,
+            _ => FormStatus::Closed,
+        }
+    }
+}
+
+// get_survey_form loads a launched survey for respondents, who do not sign in.
+// Drafts and archived surveys are not found.
+// The form carries a respondent token: the one passed in if it is of this survey,
+// else one of `proof_id` if the survey is sent to that panel, else one of a new respondent.
+// Only the respondents of a panel are known in advance: the browser keeps the token of an open form,
+// but a respondent who clears it gets a new one, so duplicate responses to an open survey are accepted.
+#[server(endpoint = "/v1/surveys/form", input = GetUrl, output = Json)]
+pub async fn get_survey_form(
+    survey_id: String,
+    token: Option<String>,
+    proof_id: Option<String>,
+) -> Result<SurveyForm, ServerFnError<ApiError>> {
+    use crate::utils::{database, lifecycle, question, session::Respondent};
+
+    tracing::debug!("/v1/surveys/form: {}", survey_id);
+    let now = chrono::Utc::now().timestamp() as u64;
+    let mut survey = database::get_typed::<SurveySummary>(&survey_id, "survey")
+        .await?
+        .filter(|s| s.archived_at.is_none() && s.status != SurveyStatus::Draft)
+        .ok_or(ApiError::NotFound)?;
+    lifecycle::close_if_due(&mut survey, now).await?;
+
+    let status = SurveyForm::status(&survey);
+    let questions = match status {
+        FormStatus::Open => question::list(&survey.id).await?,
+        _ => vec![],
+    };
+
+    let respondent = token
+        .and_then(|t| Respondent::from_token(&t))
+        .filter(|r| r.survey_id == survey.id)
+        .unwrap_or_else(|| Respondent {
+            survey_id: survey.id.clone(),
+            proof_id: proof_id
+                .filter(|p| survey.panels().contains(&p))
+                .unwrap_or_else(|| database::new_id("proof")),
+        });
+
+    Ok(SurveyForm {
+        id: survey.id,
+        title: survey.title,
+        description: survey.description,
+        status,
+        questions,
+        token: respondent.token()?,
+    })
+}
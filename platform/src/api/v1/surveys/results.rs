@@ -0,0 +1,55 @@
+#![allow(unused_imports)]
+use dioxus::prelude::{
+    server_fn::codec::{GetUrl, Json, PostUrl},
+    *,
+};
+use dioxus_logger::tracing;
+
+use crate::{
+    api::common::{ApiError, CommonQueryResponse},
+    models::{
+        response::SurveyResponse,
+        result::{aggregate, SurveyResult},
+        role::Permission,
+    },
+};
+
+// get_survey_results summarizes the answers to each question of a survey.
+#[server(endpoint = "/v1/surveys/results", input = GetUrl, output = Json)]
+pub async fn get_survey_results(
+    survey_id: String,
+) -> Result<SurveyResult, ServerFnError<ApiError>> {
+    use crate::utils::{authz, database, question, session};
+
+    tracing::debug!("/v1/surveys/results: {}", survey_id);
+    let now = chrono::Utc::now().timestamp() as u64;
+    let user = session::current_user(now).await?;
+    let survey = authz::load_survey(&user, &survey_id, Permission::ViewResults).await?;
+    let questions = question::list(&survey.id).await?;
+
+    let mut responses = vec![];
+    let mut bookmark = None;
+    loop {
+        let res = CommonQueryResponse::<SurveyResponse>::query(
+            &database::log(),
+            "gsi1-index",
+            bookmark,
+            Some(100),
+            vec![("gsi1", SurveyResponse::gsi1(&survey.id))],
+        )
+        .await
+        .map_err(ApiError::dynamo)?;
+        responses.extend(res.items);
+
+        match res.bookmark {
+            Some(next) => bookmark = Some(next),
+            None => break,
+        }
+    }
+
+    Ok(SurveyResult {
+        responses: responses.len() as u64,
+        questions: aggregate(&questions, &responses),
+        survey,
+    })
+}
@@ -0,0 +1,87 @@
+#![allow(unused_imports)]
+use dioxus::prelude::{
+    server_fn::codec::{GetUrl, Json, PostUrl},
+    *,
+};
+use dioxus_logger::tracing;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    api::common::ApiError,
+    models::{
+        role::{Permission, Role, RoleBinding},
+        survey::SurveySummary,
+        user::User,
+    },
+};
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct GrantSurveyRoleRequest {
+    pub survey_id: String,
+    pub email: String,
+    pub role: Role,
+}
+
+// grant_survey_role shares a single survey with a user outside of the owning organization.
+#[server(endpoint = "/v1/surveys/roles", input = Json, output = Json)]
+pub async fn grant_survey_role(req: GrantSurveyRoleRequest) -> Result<(), ServerFnError<ApiError>> {
+    use crate::utils::{audit, authz, database, session};
+
+    tracing::debug!("/v1/surveys/roles: {} {:?}", req.survey_id, req.role);
+    let now = chrono::Utc::now().timestamp() as u64;
+    let user = session::current_user(now).await?;
+
+    let survey = authz::load_survey(&user, &req.survey_id, Permission::ManageMembers).await?;
+
+    let member = database::find_one::<User>("gsi1-index", "gsi1", &User::gsi1(&req.email))
+        .await?
+        .ok_or(ApiError::NotFound)?;
+    database::put(&RoleBinding::new(&survey.id, &member.id, req.role, now)).await?;
+
+    audit::record(
+        "grant_survey_role",
+        &user.id,
+        &survey.id,
+        format!("{} granted {:?}", member.id, req.role),
+        now,
+    )
+    .await?;
+
+    Ok(())
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct RevokeSurveyRoleRequest {
+    pub survey_id: String,
+    pub user_id: String,
+}
+
+#[server(endpoint = "/v1/surveys/roles/revoke", input = Json, output = Json)]
+pub async fn revoke_survey_role(
+    req: RevokeSurveyRoleRequest,
+) -> Result<(), ServerFnError<ApiError>> {
+    use crate::utils::{audit, authz, database, session};
+
+    tracing::debug!(
+        "/v1/surveys/roles/revoke: {} {}",
+        req.survey_id,
+        req.user_id
+    );
+    let now = chrono::Utc::now().timestamp() as u64;
+    let user = session::current_user(now).await?;
+
+    let survey = authz::load_survey(&user, &req.survey_id, Permission::ManageMembers).await?;
+
+    database::delete(&RoleBinding::key(&survey.id, &req.user_id)).await?;
+
+    audit::record(
+        "revoke_survey_role",
+        &user.id,
+        &survey.id,
+        format!("{} revoked", req.user_id),
+        now,
+    )
+    .await?;
+
+    Ok(())
+}
@@ -0,0 +1,120 @@
+#![allow(unused_imports)]
+use dioxus::prelude::{
+    server_fn::codec::{GetUrl, Json, PostUrl},
+    *,
+};
+use dioxus_logger::tracing;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    api::common::ApiError,
+    models::{
+        role::Permission,
+        schedule::{ScheduledJob, SurveySchedule},
+        survey::{SurveyAction, SurveyStatus, SurveySummary},
+    },
+};
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ScheduleSurveyRequest {
+    pub survey_id: String,
+    // unix timestamps; `None` clears the schedule
+    pub start_at: Option<u64>,
+    pub end_at: Option<u64>,
+}
+
+// schedule_survey sets when a survey opens and closes automatically.
+// Only drafts can be scheduled to open; surveys already running can only change their end.
+#[server(endpoint = "/v1/surveys/schedule", input = Json, output = Json)]
+pub async fn schedule_survey(
+    req: ScheduleSurveyRequest,
+) -> Result<SurveySummary, ServerFnError<ApiError>> {
+    use crate::utils::{audit, authz, database, session};
+
+    tracing::debug!("/v1/surveys/schedule: {:?}", req);
+    let now = chrono::Utc::now().timestamp() as u64;
+    let user = session::current_user(now).await?;
+    let mut survey = authz::load_survey(&user, &req.survey_id, Permission::Launch).await?;
+
+    if survey.archived_at.is_some() {
+        return Err(ApiError::InvalidTransition.into());
+    }
+    if req.start_at.map_or(false, |start_at| start_at <= now) {
+        return Err(ApiError::InvalidInput("start_at".to_string()).into());
+    }
+    if req.end_at.map_or(false, |end_at| {
+        end_at <= req.start_at.unwrap_or(now).max(now)
+    }) {
+        return Err(ApiError::InvalidInput("end_at".to_string()).into());
+    }
+
+    let status = match survey.status {
+        SurveyStatus::Draft => SurveyStatus::Draft,
+        _ if req.start_at.is_some() => return Err(ApiError::InvalidTransition.into()),
+        SurveyStatus::InProgress { started_at, .. } => SurveyStatus::InProgress {
+            started_at,
+            ended_at: req.end_at,
+        },
+        SurveyStatus::Paused {
+            started_at,
+            paused_at,
+            ..
+        } => SurveyStatus::Paused {
+            started_at,
+            ended_at: req.end_at,
+            paused_at,
+        },
+        SurveyStatus::Finished => return Err(ApiError::InvalidTransition.into()),
+    };
+    // the end of a running survey is kept in its status as well
+    let mut fields = vec!["schedule", "updated_at"];
+    if status != survey.status {
+        survey.status = status;
+        fields.push("status");
+    }
+
+    for (action, run_at) in [
+        (SurveyAction::Launch, req.start_at),
+        (SurveyAction::Close, req.end_at),
+    ] {
+        match run_at {
+            Some(run_at) => {
+                database::put(&ScheduledJob::new(&survey.id, action, run_at, now)).await?
+            }
+            None => database::delete(&ScheduledJob::key(&survey.id, action)).await?,
+        }
+    }
+
+    survey.schedule = match (req.start_at, req.end_at) {
+        (None, None) => None,
+        (start_at, end_at) => Some(SurveySchedule { start_at, end_at }),
+    };
+    survey.updated_at = now;
+    database::put_fields(&survey, &fields).await?;
+    audit::record(
+        "schedule_survey",
+        &user.id,
+        &survey.id,
+        format!("{:?} - {:?}", req.start_at, req.end_at),
+        now,
+    )
+    .await?;
+
+    Ok(survey)
+}
+
+// run_scheduler runs the due scheduled jobs and returns how many ran.
+// The lambda build has no background thread, so a periodic caller set up outside calls this
+// every minute with `SCHEDULER_TOKEN`, see the scheduler section of the README.
+#[server(endpoint = "/v1/scheduler/run", input = Json, output = Json)]
+pub async fn run_scheduler(token: String) -> Result<usize, ServerFnError<ApiError>> {
+    use crate::utils::{password::constant_time_eq, scheduler};
+
+    tracing::debug!("/v1/scheduler/run");
+    let expected = std::env::var("SCHEDULER_TOKEN").unwrap_or_default();
+    if expected.is_empty() || !constant_time_eq(expected.as_bytes(), token.as_bytes()) {
+        return Err(ApiError::Unauthorized.into());
+    }
+
+    Ok(scheduler::run_due(&scheduler::SystemClock).await?)
+}
@@ -0,0 +1,124 @@
+#![allow(unused_imports)]
+use dioxus::prelude::{
+    server_fn::codec::{GetUrl, Json, PostUrl},
+    *,
+};
+use dioxus_logger::tracing;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    api::common::{ApiError, CommonQueryResponse},
+    models::user::{mask_email, normalize_phone, User},
+};
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct SendPhoneVerificationCodeRequest {
+    pub phone: String,
+}
+
+// send_phone_verification_code sends a code whether or not an account uses the phone,
+// so that it can not be used to find out registered phone numbers.
+#[server(endpoint = "/v1/users/phone/code", input = Json, output = Json)]
+pub async fn send_phone_verification_code(
+    req: SendPhoneVerificationCodeRequest,
+) -> Result<(), ServerFnError<ApiError>> {
+    use crate::{
+        models::verification::VerificationKind,
+        utils::{
+            sms::{sms_sender, Sms},
+            verification,
+        },
+    };
+
+    tracing::debug!("/v1/users/phone/code");
+    let now = chrono::Utc::now().timestamp() as u64;
+    let phone = normalize_phone(&req.phone);
+    if phone.is_empty() {
+        return Err(ApiError::InvalidPhoneNumber.into());
+    }
+
+    let code = verification::issue(VerificationKind::Contact, &phone, now).await?;
+    sms_sender()?
+        .send(Sms {
+            to: phone,
+            body: format!("[Voice Korea] 인증번호 [{code}]를 입력해 주세요."),
+        })
+        .await?;
+
+    Ok(())
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct FindEmailRequest {
+    pub name: String,
+    pub phone: String,
+    pub verification_code: String,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct FindEmailResponse {
+    // masked emails, e.g. mi***@biyard.co
+    pub emails: Vec<String>,
+}
+
+#[server(endpoint = "/v1/users/find-email", input = Json, output = Json)]
+pub async fn find_email(
+    req: FindEmailRequest,
+) -> Result<FindEmailResponse, ServerFnError<ApiError>> {
+    use crate::{
+        models::verification::VerificationKind,
+        utils::{audit, database, verification},
+    };
+
+    tracing::debug!("/v1/users/find-email");
+    let now = chrono::Utc::now().timestamp() as u64;
+    let phone = normalize_phone(&req.phone);
+
+    verification::check(
+        VerificationKind::Contact,
+        &phone,
+        &req.verification_code,
+        now,
+    )
+    .await?;
+    verification::consume(VerificationKind::Contact, &phone).await?;
+
+    let users = CommonQueryResponse::<User>::query(
+        &database::log(),
+        "gsi2-index",
+        None,
+        Some(10),
+        vec![("gsi2", User::gsi2(&phone))],
+    )
+    .await
+    .map_err(ApiError::dynamo)?;
+
+    let emails: Vec<String> = users
+        .items
+        .iter()
+        .filter(|u| u.name.trim() == req.name.trim())
+        .map(|u| mask_email(&u.email))
+        .collect();
+
+    audit::record(
+        "find_email",
+        &format!("phone:{}", mask_phone(&phone)),
+        "users",
+        format!("{} account(s) found", emails.len()),
+        now,
+    )
+    .await?;
+
+    if emails.is_empty() {
+        return Err(ApiError::NotFound.into());
+    }
+
+    Ok(FindEmailResponse { emails })
+}
+
+#[cfg(feature = "server")]
+fn mask_phone(phone: &str) -> String {
+    let keep = phone.len().saturating_sub(4);
+
+    format!("{}****", &phone[..keep])
+}
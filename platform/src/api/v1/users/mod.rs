@@ -1,4 +1,6 @@
 #![allow(unused_imports)]
+pub mod find;
+pub mod reset;
 pub mod signup;
 pub mod verify;
 
@@ -8,6 +10,8 @@ use dioxus::prelude::{
 };
 use serde::{Deserialize, Serialize};
 
+use crate::{api::common::ApiError, models::user::UserProfile};
+
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub struct LoginUserRequest {
     pub email: String,
@@ -15,10 +19,42 @@ pub struct LoginUserRequest {
 }
 
 #[server(endpoint = "/v1/users", input = Json, output = Json)]
-pub async fn login_user(req: LoginUserRequest) -> Result<(), ServerFnError> {
-    dioxus_logger::tracing::debug!("/v1/users: {:?}", req);
+pub async fn login_user(req: LoginUserRequest) -> Result<UserProfile, ServerFnError<ApiError>> {
+    use crate::utils::{
+        account,
+        session::{self, Session},
+    };
+
+    dioxus_logger::tracing::debug!("/v1/users: {}", req.email);
+    let now = chrono::Utc::now().timestamp() as u64;
+
+    let user = account::login(&req.email, &req.password, now).await?;
+    session::set_session_cookie(&Session::for_user(&user, now))?;
+
+    Ok(account::profile(&user).await?)
+}
+
+#[server(endpoint = "/v1/users/logout", input = Json, output = Json)]
+pub async fn logout_user() -> Result<(), ServerFnError<ApiError>> {
+    use crate::utils::session;
+
+    let now = chrono::Utc::now().timestamp() as u64;
+    // a copied cookie must not keep working after logout
+    if let Ok(mut user) = session::current_user(now).await {
+        session::revoke(&mut user, now).await?;
+    }
+    session::clear_session_cookie();
+
+    Ok(())
+}
+
+// get_current_user returns the profile of the signed-in user, or `ApiError::Unauthorized`.
+#[server(endpoint = "/v1/users/me", input = GetUrl, output = Json)]
+pub async fn get_current_user() -> Result<UserProfile, ServerFnError<ApiError>> {
+    use crate::utils::{account, session};
+
+    let now = chrono::Utc::now().timestamp() as u64;
+    let user = session::current_user(now).await?;
 
-    Err(ServerFnError::ServerError(
-        "not implemented yet".to_string(),
-    ))
+    Ok(account::profile(&user).await?)
 }
@@ -0,0 +1,151 @@
+#![allow(unused_imports)]
+use dioxus::prelude::{
+    server_fn::codec::{GetUrl, Json, PostUrl},
+    *,
+};
+use dioxus_logger::tracing;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    api::common::ApiError,
+    models::user::{is_valid_password, normalize_phone, LoginAttempt, PasswordResetToken, User},
+};
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct RequestPasswordResetRequest {
+    pub email: String,
+    pub name: String,
+    pub phone: String,
+}
+
+// request_password_reset succeeds even if no account matches
+// so that it can not be used to find out registered emails.
+#[server(endpoint = "/v1/users/password-reset", input = Json, output = Json)]
+pub async fn request_password_reset(
+    req: RequestPasswordResetRequest,
+) -> Result<(), ServerFnError<ApiError>> {
+    use crate::{
+        models::verification::VerificationKind,
+        utils::{
+            database,
+            mail::{mail_sender, Mail},
+            verification,
+        },
+    };
+
+    tracing::debug!("/v1/users/password-reset: {}", req.email);
+    let now = chrono::Utc::now().timestamp() as u64;
+
+    let user = database::find_one::<User>("gsi1-index", "gsi1", &User::gsi1(&req.email)).await?;
+    let user = match user {
+        Some(user)
+            if user.name.trim() == req.name.trim()
+                && normalize_phone(&user.phone) == normalize_phone(&req.phone) =>
+        {
+            user
+        }
+        _ => {
+            tracing::info!("password reset requested for an unknown account");
+            return Ok(());
+        }
+    };
+
+    let code = verification::issue(VerificationKind::PasswordReset, &user.email, now).await?;
+    mail_sender()?
+        .send(Mail {
+            to: user.email,
+            subject: "[Voice Korea] 비밀번호 재설정 인증번호 (Password reset code)".to_string(),
+            body: format!(
+                "인증번호: {code}\n3분 이내로 입력해 주세요.\n\nVerification code: {code}\nPlease enter it within 3 minutes."
+            ),
+        })
+        .await?;
+
+    Ok(())
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct VerifyPasswordResetRequest {
+    pub email: String,
+    pub verification_code: String,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct VerifyPasswordResetResponse {
+    pub token: String,
+}
+
+#[server(endpoint = "/v1/users/password-reset/verify", input = Json, output = Json)]
+pub async fn verify_password_reset(
+    req: VerifyPasswordResetRequest,
+) -> Result<VerifyPasswordResetResponse, ServerFnError<ApiError>> {
+    use crate::{
+        models::verification::VerificationKind,
+        utils::{database, verification},
+    };
+
+    tracing::debug!("/v1/users/password-reset/verify: {}", req.email);
+    let now = chrono::Utc::now().timestamp() as u64;
+
+    verification::check(
+        VerificationKind::PasswordReset,
+        &req.email,
+        &req.verification_code,
+        now,
+    )
+    .await?;
+    verification::consume(VerificationKind::PasswordReset, &req.email).await?;
+
+    let user = database::find_one::<User>("gsi1-index", "gsi1", &User::gsi1(&req.email))
+        .await?
+        .ok_or(ApiError::InvalidVerificationCode)?;
+
+    let token = database::new_id("reset");
+    database::put(&PasswordResetToken {
+        id: PasswordResetToken::key(&token),
+        r#type: "password_reset_token".to_string(),
+        user_id: user.id,
+        expired_at: now + PasswordResetToken::TTL,
+        created_at: now,
+    })
+    .await?;
+
+    Ok(VerifyPasswordResetResponse { token })
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ResetPasswordRequest {
+    pub token: String,
+    pub new_password: String,
+}
+
+#[server(endpoint = "/v1/users/password-reset/complete", input = Json, output = Json)]
+pub async fn reset_password(req: ResetPasswordRequest) -> Result<(), ServerFnError<ApiError>> {
+    use crate::utils::{database, password};
+
+    tracing::debug!("/v1/users/password-reset/complete");
+    let now = chrono::Utc::now().timestamp() as u64;
+
+    if !is_valid_password(&req.new_password) {
+        return Err(ApiError::InvalidPassword.into());
+    }
+
+    let key = PasswordResetToken::key(&req.token);
+    // tokens are single-use, so of concurrent resets with one token only the first takes it
+    let token = match database::take::<PasswordResetToken>(&key).await? {
+        Some(token) if token.expired_at > now => token,
+        _ => return Err(ApiError::InvalidResetToken.into()),
+    };
+
+    let mut user = database::get::<User>(&token.user_id)
+        .await?
+        .ok_or(ApiError::InvalidResetToken)?;
+    user.password = password::hash_password(&req.new_password)?;
+    user.sessions_valid_after = now;
+    user.updated_at = now;
+    database::put_fields(&user, &["password", "sessions_valid_after", "updated_at"]).await?;
+
+    database::delete(&LoginAttempt::key(&user.email)).await?;
+
+    Ok(())
+}
@@ -5,6 +5,11 @@ use dioxus::prelude::{
 };
 use serde::{Deserialize, Serialize};
 
+use crate::{
+    api::common::ApiError,
+    models::user::{TermsAgreement, UserKind, UserProfile},
+};
+
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub struct SignupUserRequest {
     pub email: String,
@@ -12,13 +17,17 @@ pub struct SignupUserRequest {
     pub name: String,
     pub phone: String,
     pub address: String,
+    pub kind: UserKind,
+    pub agreed_terms: Vec<TermsAgreement>,
 }
 
 #[server(endpoint = "/v1/users/signup", input = Json, output = Json)]
-pub async fn signup_user(req: SignupUserRequest) -> Result<(), ServerFnError> {
-    dioxus_logger::tracing::debug!("/v1/users/signup: {:?}", req);
+pub async fn signup_user(req: SignupUserRequest) -> Result<UserProfile, ServerFnError<ApiError>> {
+    use crate::utils::account;
+
+    dioxus_logger::tracing::debug!("/v1/users/signup: {} {:?}", req.email, req.kind);
+    let now = chrono::Utc::now().timestamp() as u64;
+    let user = account::signup(req, now).await?;
 
-    Err(ServerFnError::ServerError(
-        "not implemented yet".to_string(),
-    ))
+    Ok(account::profile(&user).await?)
 }
@@ -6,6 +6,8 @@ use dioxus::prelude::{
 use dioxus_logger::tracing;
 use serde::{Deserialize, Serialize};
 
+use crate::api::common::ApiError;
+
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub struct VerifyEmailRequest {
     pub email: String,
@@ -16,12 +18,23 @@ pub struct VerifyEmailRequest {
 pub struct VerifyEmailResponse {}
 
 #[server(endpoint = "/v1/users/verify", input = PostUrl, output = Json)]
-pub async fn verify_email(req: VerifyEmailRequest) -> Result<VerifyEmailResponse, ServerFnError> {
-    tracing::debug!("/v1/users/verify: {:?}", req);
+pub async fn verify_email(
+    req: VerifyEmailRequest,
+) -> Result<VerifyEmailResponse, ServerFnError<ApiError>> {
+    use crate::{models::verification::VerificationKind, utils::verification};
+
+    tracing::debug!("/v1/users/verify: {}", req.email);
+    let now = chrono::Utc::now().timestamp() as u64;
+
+    verification::check(
+        VerificationKind::Contact,
+        &req.email,
+        &req.verification_code,
+        now,
+    )
+    .await?;
 
-    Err(ServerFnError::ServerError(
-        "not implemented yet".to_string(),
-    ))
+    Ok(VerifyEmailResponse {})
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
@@ -30,16 +43,38 @@ pub struct GetVerificationCodeRequest {
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
-pub struct GetVerificationCodeResponse {}
+pub struct GetVerificationCodeResponse {
+    // seconds until the code expires
+    pub expires_in: u64,
+}
 
-#[server(endpoint = "/v1/users/verify", input = GetUrl, output = Json)]
+#[server(endpoint = "/v1/users/verify/code", input = GetUrl, output = Json)]
 pub async fn get_verification_code(
     req: GetVerificationCodeRequest,
-) -> Result<GetVerificationCodeResponse, ServerFnError> {
-    tracing::debug!("/v1/users/verify: {:?}", req);
-    // TODO: Send a verification code to the email
+) -> Result<GetVerificationCodeResponse, ServerFnError<ApiError>> {
+    use crate::{
+        models::verification::{Verification, VerificationKind},
+        utils::{
+            mail::{mail_sender, Mail},
+            verification,
+        },
+    };
+
+    tracing::debug!("/v1/users/verify/code: {:?}", req);
+    let now = chrono::Utc::now().timestamp() as u64;
+
+    let code = verification::issue(VerificationKind::Contact, &req.email, now).await?;
+    mail_sender()?
+        .send(Mail {
+            to: req.email.trim().to_string(),
+            subject: "[Voice Korea] 인증번호 안내 (Verification code)".to_string(),
+            body: format!(
+                "인증번호: {code}\n3분 이내로 입력해 주세요.\n\nVerification code: {code}\nPlease enter it within 3 minutes."
+            ),
+        })
+        .await?;
 
-    Err(ServerFnError::ServerError(
-        "not implemented yet".to_string(),
-    ))
+    Ok(GetVerificationCodeResponse {
+        expires_in: Verification::CODE_TTL,
+    })
 }
@@ -4,6 +4,7 @@ use dioxus::prelude::*;
 #[derive(PartialEq, Props, Clone)]
 pub struct HeaderProps {
     logout: String,
+    onlogout: EventHandler<MouseEvent>,
 }
 
 #[component]
@@ -25,6 +26,7 @@ pub fn Header(props: HeaderProps) -> Element {
             }
             div {
                 class: "flex flex-row w-[105px] h-[45px] rounded-[8px] cursor-pointer hover:bg-[#9a9a9a] bg-[#b0b0b0] mr-[45px]",
+                onclick: move |evt| props.onlogout.call(evt),
                 div {
                     class: "flex flex-row w-full h-full justify-center items-center text-[16px] font-bold text-white",
                     "{props.logout}"
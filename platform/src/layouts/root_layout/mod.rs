@@ -1,5 +1,8 @@
 #![allow(non_snake_case)]
-use crate::prelude::*;
+use crate::{
+    api::v1::users::{get_current_user, logout_user},
+    prelude::*,
+};
 use dioxus::prelude::*;
 use header::Header;
 use side_bar::{SelectedMenu, SideBar};
@@ -12,11 +15,57 @@ pub mod side_bar;
 pub fn RootLayout(lang: Language) -> Element {
     use dioxus_logger::tracing;
     let translates = i18n::translate(lang.clone());
+    let mut auth = use_auth_context();
+    let mut login_state = use_login_context();
+    let route = use_route::<Route>();
+    let navigator = use_navigator();
+
+    let _ = use_resource(move || async move {
+        if auth.peek().checked {
+            return;
+        }
+
+        let user = match get_current_user().await {
+            Ok(user) => Some(user),
+            Err(e) => {
+                tracing::debug!("not signed in: {:?}", e);
+                None
+            }
+        };
+        auth.set(AuthContext {
+            checked: true,
+            user,
+        });
+    });
+
+    // Unauthenticated users are sent to the login page,
+    // which brings them back to the intended route after signing in.
+    use_effect(move || {
+        let ctx = auth();
+        if ctx.checked && !ctx.is_authenticated() {
+            login_state.set(LoginPopupState(false, Some(route.clone())));
+            navigator.replace(Route::LoginPage { lang });
+        }
+    });
+
+    if !auth().is_authenticated() {
+        return rsx! {};
+    }
+
     rsx! {
         div {
             class: "flex flex-col w-screen min-h-screen bg-white text-black",
             Header {
                 logout: translates.logout,
+                onlogout: move |_| async move {
+                    if let Err(e) = logout_user().await {
+                        tracing::error!("failed to logout: {:?}", e);
+                    }
+                    auth.set(AuthContext {
+                        checked: true,
+                        user: None,
+                    });
+                },
             }
             div {
                 class: "flex flex-row min-w-full max-w-full grow",
@@ -55,7 +55,8 @@ pub fn SideBar(props: SidebarProps) -> Element {
                 }
                 div {
                     class: "flex flex-row w-full h-full justify-end items-end p-[10px]",
-                    div {
+                    Link {
+                        to: Route::MembersPage { lang: props.lang },
                         class: "flex flex-row w-[110px]",
                         div {
                             class: "text-white text-[14px] font-normal pr-[10px]",
@@ -14,19 +14,60 @@ pub mod presentations {
     pub mod create;
     pub mod dashboard;
     pub mod find_email;
+    pub mod invitation;
     pub mod login;
+    pub mod members;
     pub mod reset_password;
+    pub mod respond;
+    pub mod result;
     pub mod write_question;
     pub mod write_title;
 }
 
 pub mod models {
+    pub mod audit;
+    pub mod organization;
     pub mod question;
+    pub mod response;
+    pub mod result;
+    pub mod role;
+    pub mod schedule;
     pub mod survey;
+    pub mod user;
+    pub mod verification;
 }
 
 pub mod utils {
+    #[cfg(feature = "server")]
+    pub mod account;
+    #[cfg(feature = "server")]
+    pub mod audit;
+    #[cfg(feature = "server")]
+    pub mod authz;
     pub mod context;
+    #[cfg(feature = "server")]
+    pub mod database;
+    #[cfg(feature = "server")]
+    pub mod lifecycle;
+    #[cfg(feature = "server")]
+    pub mod mail;
+    #[cfg(feature = "server")]
+    pub mod organization;
+    #[cfg(feature = "server")]
+    pub mod password;
+    #[cfg(feature = "server")]
+    pub mod question;
+    #[cfg(feature = "server")]
+    pub mod scheduler;
+    #[cfg(feature = "server")]
+    pub mod session;
+    #[cfg(feature = "server")]
+    pub mod sms;
+    #[cfg(feature = "server")]
+    pub mod survey;
+    pub mod time;
+    #[cfg(feature = "server")]
+    pub mod verification;
 }
 
 pub mod layouts {
@@ -4,7 +4,12 @@ use dioxus_logger::tracing::{self, Level};
 
 use dioxus::prelude::*;
 
-use voice_korea::{routes::Route, utils::context::use_iitp_context_provider};
+use voice_korea::{
+    routes::Route,
+    utils::context::{
+        use_auth_context_provider, use_iitp_context_provider, use_login_context_provider,
+    },
+};
 
 fn main() {
     dioxus_logger::init(match option_env!("LOG_LEVEL") {
@@ -17,12 +22,24 @@ fn main() {
     })
     .expect("failed to init logger");
 
+    #[cfg(feature = "server")]
+    {
+        voice_korea::utils::session::init().expect("SESSION_SECRET is missing or too short");
+        voice_korea::utils::mail::init().expect("MAIL_SENDER is missing or invalid");
+        voice_korea::utils::sms::init().expect("SMS_SENDER is missing or invalid");
+        voice_korea::utils::database::init();
+    }
+    #[cfg(all(feature = "server", not(feature = "lambda")))]
+    voice_korea::utils::scheduler::spawn();
+
     tracing::info!("starting app");
     dioxus_aws::launch(App);
 }
 
 fn App() -> Element {
     use_iitp_context_provider();
+    use_auth_context_provider();
+    use_login_context_provider();
 
     rsx! {
         head {
@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+
+// AuditLog records who did what to which entity.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct AuditLog {
+    pub id: String,
+    pub r#type: String,
+    pub action: String,
+    // user id, or a description of an anonymous requester
+    pub actor: String,
+    pub target: String,
+    pub detail: String,
+    pub created_at: u64,
+
+    // list audit logs by target
+    pub gsi1: String,
+}
+
+impl AuditLog {
+    pub fn gsi1(target: &str) -> String {
+        format!("audit#{target}")
+    }
+}
@@ -0,0 +1,65 @@
+use serde::{Deserialize, Serialize};
+
+use super::role::Role;
+
+// Organization owns surveys and is shared by its members.
+// Members are `RoleBinding`s whose resource is the organization.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize, Default)]
+pub struct Organization {
+    pub id: String,
+    pub r#type: String,
+    pub name: String,
+    pub created_by: String,
+    pub created_at: u64,
+    pub updated_at: u64,
+}
+
+impl Organization {
+    pub const ID_PREFIX: &'static str = "org";
+
+    pub fn is_organization_id(id: &str) -> bool {
+        id.starts_with(&format!("{}-", Self::ID_PREFIX))
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct OrganizationMembership {
+    pub organization: Organization,
+    pub role: Role,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Member {
+    pub user_id: String,
+    pub email: String,
+    pub name: String,
+    pub role: Role,
+}
+
+// Invitation is sent by email and accepted by a signed-in user with the same email.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Invitation {
+    pub id: String,
+    pub r#type: String,
+    pub organization_id: String,
+    pub email: String,
+    pub role: Role,
+    pub invited_by: String,
+    pub expired_at: u64,
+    pub created_at: u64,
+
+    // list invitations by organization
+    pub gsi1: String,
+}
+
+impl Invitation {
+    pub const TTL: u64 = 7 * 24 * 60 * 60;
+
+    pub fn key(token: &str) -> String {
+        format!("invitation#{token}")
+    }
+
+    pub fn gsi1(organization_id: &str) -> String {
+        format!("invitation#{organization_id}")
+    }
+}
@@ -0,0 +1,159 @@
+use std::{
+    collections::HashMap,
+    sync::{Mutex, OnceLock},
+};
+
+use chrono::NaiveDate;
+use regex::{Regex, RegexBuilder};
+
+// numbers are kept as text and compared as integers scaled by 10^decimals
+pub const NUMBER_MAX_DECIMALS: u8 = 6;
+pub const PATTERN_MAX_LENGTH: usize = 200;
+// compiled patterns kept by `pattern_regex`; the cache is emptied when full
+const PATTERN_CACHE_SIZE: usize = 256;
+
+// parse_number reads a number like "-12.5" with at most `decimals` fractional digits,
+// returning it multiplied by 10^decimals.
+pub fn parse_number(text: &str, decimals: u8) -> Option<i64> {
+    let text = text.trim();
+    let (negative, digits) = match text.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, text),
+    };
+    let (int, frac) = digits.split_once('.').unwrap_or((digits, ""));
+    let is_digits = |s: &str| s.chars().all(|c| c.is_ascii_digit());
+
+    if int.is_empty() || !is_digits(int) || !is_digits(frac) || frac.len() > decimals as usize {
+        return None;
+    }
+    if digits.ends_with('.') {
+        return None;
+    }
+
+    let scale = 10i64.checked_pow(decimals as u32)?;
+    let frac = format!("{frac:0<width$}", width = decimals as usize);
+    let value = int
+        .parse::<i64>()
+        .ok()?
+        .checked_mul(scale)?
+        .checked_add(frac.parse::<i64>().unwrap_or(0))?;
+
+    Some(if negative { -value } else { value })
+}
+
+// format_number writes a number scaled by 10^decimals, the inverse of `parse_number`.
+pub fn format_number(value: i64, decimals: u8) -> String {
+    let scale = 10u64.pow(decimals as u32);
+    let sign = if value < 0 { "-" } else { "" };
+    let abs = value.unsigned_abs();
+
+    match decimals {
+        0 => format!("{sign}{abs}"),
+        _ => format!(
+            "{sign}{}.{:0width$}",
+            abs / scale,
+            abs % scale,
+            width = decimals as usize
+        ),
+    }
+}
+
+// parse_date reads a date as "YYYY-MM-DD", the value of a date input.
+pub fn parse_date(text: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(text.trim(), "%Y-%m-%d").ok()
+}
+
+pub fn is_email(text: &str) -> bool {
+    let text = text.trim();
+    match text.split_once('@') {
+        Some((local, domain)) => {
+            text.len() <= 254
+                && !local.is_empty()
+                && !text.chars().any(char::is_whitespace)
+                && !domain.contains('@')
+                && domain.split('.').count() >= 2
+                && domain.split('.').all(|label| !label.is_empty())
+        }
+        None => false,
+    }
+}
+
+// is_phone accepts 7 to 15 digits, optionally with a leading "+" and "-", " ", "(" or ")".
+pub fn is_phone(text: &str) -> bool {
+    let text = text.trim();
+    let digits = text.chars().filter(|c| c.is_ascii_digit()).count();
+
+    (7..=15).contains(&digits)
+        && text
+            .strip_prefix('+')
+            .unwrap_or(text)
+            .chars()
+            .all(|c| c.is_ascii_digit() || "- ()".contains(c))
+}
+
+// pattern_regex compiles `pattern` to match a whole answer.
+// Patterns are kept once compiled, so that checking every answer does not compile them again.
+pub fn pattern_regex(pattern: &str) -> Option<Regex> {
+    static PATTERNS: OnceLock<Mutex<HashMap<String, Option<Regex>>>> = OnceLock::new();
+
+    let mut patterns = PATTERNS
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(|e| e.into_inner());
+    if let Some(re) = patterns.get(pattern) {
+        return re.clone();
+    }
+    if patterns.len() >= PATTERN_CACHE_SIZE {
+        patterns.clear();
+    }
+
+    patterns
+        .entry(pattern.to_string())
+        .or_insert_with(|| compile_pattern(pattern))
+        .clone()
+}
+
+fn compile_pattern(pattern: &str) -> Option<Regex> {
+    if pattern.trim().is_empty() || pattern.len() > PATTERN_MAX_LENGTH {
+        return None;
+    }
+    let build = |pattern: &str| RegexBuilder::new(pattern).size_limit(1 << 20).build().ok();
+
+    // a pattern must be valid by itself, or it could close the group it is wrapped in
+    // and match more than whole answers, as `a)|(?:.*` would
+    build(pattern)?;
+    build(&format!("^(?:{pattern})$"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numbers_out_of_range_are_rejected() {
+        assert_eq!(parse_number("-12.5", 2), Some(-1250));
+        assert_eq!(parse_number(&i64::MAX.to_string(), 0), Some(i64::MAX));
+        assert_eq!(parse_number("9223372036854775808", 0), None);
+        // fits as an integer, but not once scaled by 10^decimals
+        assert_eq!(parse_number("9223372036854775807", 1), None);
+        assert_eq!(parse_number("92233720368547.75808", 6), None);
+    }
+
+    #[test]
+    fn pattern_matches_whole_answers() {
+        let re = pattern_regex("[0-9]{3}").unwrap();
+
+        assert!(re.is_match("123"));
+        assert!(!re.is_match("1234"));
+        assert!(!re.is_match("a123"));
+        assert!(pattern_regex("a|ab").unwrap().is_match("ab"));
+    }
+
+    #[test]
+    fn pattern_closing_its_group_is_rejected() {
+        assert!(pattern_regex("a)|(?:.*").is_none());
+        assert!(pattern_regex("(a").is_none());
+        assert!(pattern_regex("").is_none());
+        assert!(pattern_regex(&"a".repeat(PATTERN_MAX_LENGTH + 1)).is_none());
+    }
+}
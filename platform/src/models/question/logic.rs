@@ -0,0 +1,381 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::{carried_options, parse_number, Question, QuestionAnswer, QuestionType};
+
+// QuestionLogic decides whether a question is shown and where to go after it.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize, Default)]
+pub struct QuestionLogic {
+    // the question is shown only if all of them hold; they refer to earlier questions
+    #[serde(default)]
+    pub display: Vec<Condition>,
+    // after the question, the first jump whose rule holds for its answer is taken
+    #[serde(default)]
+    pub jumps: Vec<Jump>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Condition {
+    pub question_id: String,
+    pub rule: Rule,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Jump {
+    pub rule: Rule,
+    pub to: JumpTarget,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JumpTarget {
+    Question(String),
+    // skips the remaining questions
+    End,
+}
+
+// Rule tests the answer to a question; a question that is not shown has no answer.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Rule {
+    Answered,
+    Skipped,
+    // the option at the index is selected in a choice question
+    Selected(usize),
+    NotSelected(usize),
+    // bounds of a scale or number answer, as text, see `parse_number`
+    AtLeast(String),
+    AtMost(String),
+}
+
+// LogicError is the index of the question whose logic is invalid.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum LogicError {
+    Display(usize),
+    Jump(usize),
+    // the jumps can come back to the question
+    Cycle(usize),
+}
+
+impl Rule {
+    pub const KINDS: [&'static str; 6] = [
+        "answered",
+        "skipped",
+        "selected",
+        "not_selected",
+        "at_least",
+        "at_most",
+    ];
+
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Rule::Answered => "answered",
+            Rule::Skipped => "skipped",
+            Rule::Selected(_) => "selected",
+            Rule::NotSelected(_) => "not_selected",
+            Rule::AtLeast(_) => "at_least",
+            Rule::AtMost(_) => "at_most",
+        }
+    }
+
+    // new returns the rule of `kind` with a default value.
+    pub fn new(kind: &str) -> Option<Rule> {
+        match kind {
+            "answered" => Some(Rule::Answered),
+            "skipped" => Some(Rule::Skipped),
+            "selected" => Some(Rule::Selected(0)),
+            "not_selected" => Some(Rule::NotSelected(0)),
+            "at_least" => Some(Rule::AtLeast("".to_string())),
+            "at_most" => Some(Rule::AtMost("".to_string())),
+            _ => None,
+        }
+    }
+
+    // kinds are the `KINDS` of the rules that can test the answers to `question`.
+    pub fn kinds(question: &QuestionType) -> Vec<&'static str> {
+        let choice = matches!(
+            question,
+            QuestionType::SingleChoice { .. } | QuestionType::MultipleChoice { .. }
+        );
+        let numeric = matches!(question, QuestionType::Number { .. }) || question.scale().is_some();
+
+        Self::KINDS
+            .into_iter()
+            .filter(|kind| match *kind {
+                "selected" | "not_selected" => choice,
+                "at_least" | "at_most" => numeric,
+                _ => true,
+            })
+            .collect()
+    }
+
+    // applies is true if the rule can test the answers to `question`.
+    pub fn applies(&self, question: &QuestionType) -> bool {
+        match self {
+            Rule::Answered | Rule::Skipped => true,
+            Rule::Selected(option) | Rule::NotSelected(option) => match question {
+                QuestionType::SingleChoice { options, .. }
+                | QuestionType::MultipleChoice { options, .. } => *option < options.len(),
+                _ => false,
+            },
+            Rule::AtLeast(value) | Rule::AtMost(value) => numeric_bound(question, value).is_some(),
+        }
+    }
+
+    pub fn matches(&self, question: &QuestionType, answer: Option<&QuestionAnswer>) -> bool {
+        let answer = answer.filter(|a| !a.is_empty());
+        let selected = |option: &usize| match answer {
+            Some(QuestionAnswer::SingleChoice(index)) => index == option,
+            Some(QuestionAnswer::MultipleChoice(selected)) => selected.contains(option),
+            _ => false,
+        };
+        let value = answer.and_then(|a| numeric_answer(question, a));
+
+        match self {
+            Rule::Answered => answer.is_some(),
+            Rule::Skipped => answer.is_none(),
+            Rule::Selected(option) => selected(option),
+            Rule::NotSelected(option) => answer.is_some() && !selected(option),
+            Rule::AtLeast(bound) => value
+                .zip(numeric_bound(question, bound))
+                .map_or(false, |(v, b)| v >= b),
+            Rule::AtMost(bound) => value
+                .zip(numeric_bound(question, bound))
+                .map_or(false, |(v, b)| v <= b),
+        }
+    }
+}
+
+// numeric_bound reads `value` as a number comparable to the answers to `question`.
+fn numeric_bound(question: &QuestionType, value: &str) -> Option<i64> {
+    match question {
+        QuestionType::Number { decimals, .. } => parse_number(value, *decimals),
+        q if q.scale().is_some() => parse_number(value, 0),
+        _ => None,
+    }
+}
+
+fn numeric_answer(question: &QuestionType, answer: &QuestionAnswer) -> Option<i64> {
+    match (question, answer) {
+        (QuestionType::Number { decimals, .. }, QuestionAnswer::Number(text)) => {
+            parse_number(text, *decimals)
+        }
+        _ => answer.scale_value().map(|v| v as i64),
+    }
+}
+
+impl QuestionLogic {
+    // rename replaces the question ids it refers to, e.g. when the questions are copied.
+    pub fn rename(&mut self, ids: &HashMap<String, String>) {
+        for condition in self.display.iter_mut() {
+            if let Some(id) = ids.get(&condition.question_id) {
+                condition.question_id = id.clone();
+            }
+        }
+        for jump in self.jumps.iter_mut() {
+            if let JumpTarget::Question(target) = &mut jump.to {
+                if let Some(id) = ids.get(target) {
+                    *target = id.clone();
+                }
+            }
+        }
+    }
+}
+
+// route returns whether each of `questions` is shown to a respondent given `answers`,
+// in the same order; the answers to the questions not shown are ignored.
+pub fn route(questions: &[Question], answers: &[Option<QuestionAnswer>]) -> Vec<bool> {
+    let index: HashMap<&str, usize> = questions
+        .iter()
+        .enumerate()
+        .map(|(i, q)| (q.id.as_str(), i))
+        .collect();
+    let mut shown = vec![false; questions.len()];
+
+    let mut i = 0;
+    while i < questions.len() {
+        let answer = |j: usize| match shown[j] {
+            true => answers.get(j).and_then(|a| a.as_ref()),
+            false => None,
+        };
+        let question = &questions[i];
+        let visible = question.logic.display.iter().all(|c| {
+            index
+                .get(c.question_id.as_str())
+                .filter(|j| **j < i)
+                .map_or(false, |j| {
+                    c.rule.matches(&questions[*j].question, answer(*j))
+                })
+        });
+        // a question carrying forward no selected options has nothing to choose
+        let visible = visible
+            && carried_options(questions, i, answer).map_or(true, |options| !options.is_empty());
+        if !visible {
+            i += 1;
+            continue;
+        }
+
+        shown[i] = true;
+        let answer = answers.get(i).and_then(|a| a.as_ref());
+        // jumps only go forward, which `check` enforces on save
+        i = match question
+            .logic
+            .jumps
+            .iter()
+            .find(|j| j.rule.matches(&question.question, answer))
+            .map(|j| &j.to)
+        {
+            Some(JumpTarget::End) => questions.len(),
+            Some(JumpTarget::Question(id)) => match index.get(id.as_str()) {
+                Some(target) if *target > i => *target,
+                _ => i + 1,
+            },
+            None => i + 1,
+        };
+    }
+
+    shown
+}
+
+// check validates the logic of `questions` before they are stored:
+// the rules must fit the questions they test, display conditions refer to earlier questions
+// and the jumps must not come back to a question.
+pub fn check(questions: &[Question]) -> Result<(), LogicError> {
+    let index: HashMap<&str, usize> = questions
+        .iter()
+        .enumerate()
+        .map(|(i, q)| (q.id.as_str(), i))
+        .collect();
+
+    let mut next: Vec<Vec<usize>> = vec![];
+    for (i, question) in questions.iter().enumerate() {
+        for condition in question.logic.display.iter() {
+            match index.get(condition.question_id.as_str()) {
+                Some(j) if *j < i && condition.rule.applies(&questions[*j].question) => {}
+                _ => return Err(LogicError::Display(i)),
+            }
+        }
+
+        // the questions that can follow this one; `questions.len()` is the end
+        let mut edges = vec![i + 1];
+        for jump in question.logic.jumps.iter() {
+            if !jump.rule.applies(&question.question) {
+                return Err(LogicError::Jump(i));
+            }
+            match &jump.to {
+                JumpTarget::End => edges.push(questions.len()),
+                JumpTarget::Question(id) => match index.get(id.as_str()) {
+                    Some(target) if *target != i => edges.push(*target),
+                    _ => return Err(LogicError::Jump(i)),
+                },
+            }
+        }
+        next.push(edges);
+    }
+
+    // depth first search for a question reachable from itself
+    let mut state = vec![0u8; questions.len()];
+    for start in 0..questions.len() {
+        if state[start] != 0 {
+            continue;
+        }
+        let mut stack = vec![(start, 0)];
+        state[start] = 1;
+        while let Some((node, edge)) = stack.pop() {
+            match next[node].get(edge) {
+                Some(&target) => {
+                    stack.push((node, edge + 1));
+                    match state.get(target) {
+                        Some(1) => return Err(LogicError::Cycle(node)),
+                        Some(0) => {
+                            state[target] = 1;
+                            stack.push((target, 0));
+                        }
+                        _ => {}
+                    }
+                }
+                None => state[node] = 2,
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // questions numbered from 1, whose jumps are given by the numbers they go to
+    fn questions(jumps: &[&[usize]]) -> Vec<Question> {
+        jumps
+            .iter()
+            .enumerate()
+            .map(|(i, jumps)| Question {
+                id: format!("question-{}", i + 1),
+                r#type: "question".to_string(),
+                survey_id: "survey-1".to_string(),
+                title: format!("Q{}", i + 1),
+                question: QuestionType::Text(None),
+                required: false,
+                position: i as u32,
+                logic: QuestionLogic {
+                    display: vec![],
+                    jumps: jumps
+                        .iter()
+                        .map(|to| Jump {
+                            rule: Rule::Answered,
+                            to: JumpTarget::Question(format!("question-{to}")),
+                        })
+                        .collect(),
+                },
+                carry_forward: None,
+                gsi1: "survey-1".to_string(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn forward_jumps_are_allowed() {
+        assert_eq!(check(&questions(&[&[3], &[4], &[], &[]])), Ok(()));
+    }
+
+    #[test]
+    fn backward_jumps_are_cycles() {
+        assert_eq!(
+            check(&questions(&[&[], &[], &[1]])),
+            Err(LogicError::Cycle(2))
+        );
+        // Q1 skips to Q3, which goes back to Q2 and on to Q3 again
+        assert_eq!(
+            check(&questions(&[&[3], &[], &[2]])),
+            Err(LogicError::Cycle(2))
+        );
+    }
+
+    #[test]
+    fn jump_to_itself_is_rejected() {
+        assert_eq!(check(&questions(&[&[], &[2]])), Err(LogicError::Jump(1)));
+    }
+
+    #[test]
+    fn carried_forward_question_is_hidden_without_selected_options() {
+        let choice = |question: &Question| Question {
+            question: QuestionType::MultipleChoice {
+                question: None,
+                options: vec!["a".to_string(), "b".to_string()],
+                min: None,
+                max: None,
+            },
+            ..question.clone()
+        };
+        let mut questions: Vec<Question> = questions(&[&[], &[], &[]]).iter().map(choice).collect();
+        questions[1].carry_forward = Some(1);
+
+        let answer = |selected: Vec<usize>| vec![Some(QuestionAnswer::MultipleChoice(selected))];
+        assert_eq!(route(&questions, &answer(vec![1])), [true, true, true]);
+        assert_eq!(route(&questions, &answer(vec![])), [true, false, true]);
+        assert_eq!(route(&questions, &[]), [true, false, true]);
+    }
+}
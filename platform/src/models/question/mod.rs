@@ -1,14 +1,49 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+mod format;
+mod logic;
+mod template;
+
+pub use format::{
+    format_number, is_email, is_phone, parse_date, parse_number, pattern_regex,
+    NUMBER_MAX_DECIMALS, PATTERN_MAX_LENGTH,
+};
+pub use logic::{check, route, Condition, Jump, JumpTarget, LogicError, QuestionLogic, Rule};
+pub use template::{
+    answer_text, carried_options, carry_options, check_templates, fill, references, renumber,
+    renumber_question, resolve,
+};
+
+// answers longer than these are rejected, counted in characters
+pub const TEXT_MAX_LENGTH: usize = 200;
+pub const LONG_TEXT_MAX_LENGTH: usize = 5000;
+pub const MAX_QUESTIONS: usize = 100;
+// scale questions answer from 1 to their points, and an NPS from 0 to 10
+pub const LIKERT_MAX_POINTS: u8 = 11;
+pub const RATING_MAX: u8 = 10;
+pub const NPS_MAX: u8 = 10;
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub struct Question {
     pub id: String,
+    #[serde(default)]
+    pub r#type: String,
     pub survey_id: String,
     pub title: String,
     pub question: QuestionType,
+    #[serde(default)]
+    pub required: bool,
+    // questions are listed in ascending position
+    #[serde(default)]
+    pub position: u32,
+    #[serde(default)]
+    pub logic: QuestionLogic,
+    // the options are the ones selected in this earlier question, counted from 1
+    #[serde(default)]
+    pub carry_forward: Option<u32>,
 
     // list questions by survey id
-    #[serde(skip)]
+    #[serde(default)]
     pub gsi1: String,
 }
 
@@ -21,6 +56,442 @@ pub enum QuestionType {
         question: Option<String>,
         options: Vec<String>,
     },
+    // `min` and `max` bound the number of selected options when answered
+    MultipleChoice {
+        question: Option<String>,
+        options: Vec<String>,
+        min: Option<usize>,
+        max: Option<usize>,
+    },
+    // agree/disagree scale from 1 to `points` with labels at both ends
+    Likert {
+        question: Option<String>,
+        points: u8,
+        min_label: String,
+        max_label: String,
+    },
+    Rating {
+        question: Option<String>,
+        max: u8,
+    },
+    // net promoter score, how likely to recommend from 0 to 10
+    Nps {
+        question: Option<String>,
+    },
+    // the same `columns` asked for each of `rows`; `multi` allows several columns per row
+    Matrix {
+        question: Option<String>,
+        rows: Vec<String>,
+        columns: Vec<String>,
+        multi: bool,
+    },
+    // respondents order all of `options` from the most to the least preferred
+    Ranking {
+        question: Option<String>,
+        options: Vec<String>,
+    },
+    // an integer when `decimals` is 0; the bounds and step are numbers as text, see `parse_number`
+    Number {
+        question: Option<String>,
+        decimals: u8,
+        min: Option<String>,
+        max: Option<String>,
+        step: Option<String>,
+    },
+    // the bounds are dates as "YYYY-MM-DD"
+    Date {
+        question: Option<String>,
+        min: Option<String>,
+        max: Option<String>,
+    },
+    DateRange {
+        question: Option<String>,
+        min: Option<String>,
+        max: Option<String>,
+    },
+    Phone(Option<String>),
+    Email(Option<String>),
+    // short text matching the regular expression `pattern` as a whole
+    Pattern {
+        question: Option<String>,
+        pattern: String,
+    },
+}
+
+impl QuestionType {
+    pub const KINDS: [&'static str; 16] = [
+        "text",
+        "long_text",
+        "single_choice",
+        "multiple_choice",
+        "likert",
+        "rating",
+        "nps",
+        "matrix",
+        "ranking",
+        "integer",
+        "decimal",
+        "date",
+        "date_range",
+        "phone",
+        "email",
+        "pattern",
+    ];
+
+    // kind is the name of the variant, one of `KINDS`; a number is an integer or a decimal.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            QuestionType::Text(_) => "text",
+            QuestionType::LongText(_) => "long_text",
+            QuestionType::SingleChoice { .. } => "single_choice",
+            QuestionType::MultipleChoice { .. } => "multiple_choice",
+            QuestionType::Likert { .. } => "likert",
+            QuestionType::Rating { .. } => "rating",
+            QuestionType::Nps { .. } => "nps",
+            QuestionType::Matrix { .. } => "matrix",
+            QuestionType::Ranking { .. } => "ranking",
+            QuestionType::Number { decimals: 0, .. } => "integer",
+            QuestionType::Number { .. } => "decimal",
+            QuestionType::Date { .. } => "date",
+            QuestionType::DateRange { .. } => "date_range",
+            QuestionType::Phone(_) => "phone",
+            QuestionType::Email(_) => "email",
+            QuestionType::Pattern { .. } => "pattern",
+        }
+    }
+
+    // scale is the range of the answer to a scale question.
+    pub fn scale(&self) -> Option<(u8, u8)> {
+        match self {
+            QuestionType::Likert { points, .. } => Some((1, *points)),
+            QuestionType::Rating { max, .. } => Some((1, *max)),
+            QuestionType::Nps { .. } => Some((0, NPS_MAX)),
+            _ => None,
+        }
+    }
+
+    // scale_answer is the answer of `value` to a scale question.
+    pub fn scale_answer(&self, value: u8) -> Option<QuestionAnswer> {
+        match self {
+            QuestionType::Likert { .. } => Some(QuestionAnswer::Likert(value)),
+            QuestionType::Rating { .. } => Some(QuestionAnswer::Rating(value)),
+            QuestionType::Nps { .. } => Some(QuestionAnswer::Nps(value)),
+            _ => None,
+        }
+    }
+
+    // hint is the placeholder of a text question, or the description of the others.
+    pub fn hint(&self) -> &Option<String> {
+        match self {
+            QuestionType::Text(hint)
+            | QuestionType::LongText(hint)
+            | QuestionType::Phone(hint)
+            | QuestionType::Email(hint) => hint,
+            QuestionType::SingleChoice { question, .. }
+            | QuestionType::MultipleChoice { question, .. }
+            | QuestionType::Likert { question, .. }
+            | QuestionType::Rating { question, .. }
+            | QuestionType::Nps { question }
+            | QuestionType::Matrix { question, .. }
+            | QuestionType::Ranking { question, .. }
+            | QuestionType::Number { question, .. }
+            | QuestionType::Date { question, .. }
+            | QuestionType::DateRange { question, .. }
+            | QuestionType::Pattern { question, .. } => question,
+        }
+    }
+
+    pub fn hint_mut(&mut self) -> &mut Option<String> {
+        match self {
+            QuestionType::Text(hint)
+            | QuestionType::LongText(hint)
+            | QuestionType::Phone(hint)
+            | QuestionType::Email(hint) => hint,
+            QuestionType::SingleChoice { question, .. }
+            | QuestionType::MultipleChoice { question, .. }
+            | QuestionType::Likert { question, .. }
+            | QuestionType::Rating { question, .. }
+            | QuestionType::Nps { question }
+            | QuestionType::Matrix { question, .. }
+            | QuestionType::Ranking { question, .. }
+            | QuestionType::Number { question, .. }
+            | QuestionType::Date { question, .. }
+            | QuestionType::DateRange { question, .. }
+            | QuestionType::Pattern { question, .. } => question,
+        }
+    }
+
+    // options are the choices of a choice question.
+    pub fn options(&self) -> Option<&Vec<String>> {
+        match self {
+            QuestionType::SingleChoice { options, .. }
+            | QuestionType::MultipleChoice { options, .. }
+            | QuestionType::Ranking { options, .. } => Some(options),
+            _ => None,
+        }
+    }
+
+    pub fn options_mut(&mut self) -> Option<&mut Vec<String>> {
+        match self {
+            QuestionType::SingleChoice { options, .. }
+            | QuestionType::MultipleChoice { options, .. }
+            | QuestionType::Ranking { options, .. } => Some(options),
+            _ => None,
+        }
+    }
+
+    // convert changes the kind of a question, keeping its hint and options where possible.
+    pub fn convert(&self, kind: &str) -> Option<QuestionType> {
+        let hint = self.hint().clone();
+        let options = match self.options() {
+            Some(options) if !options.is_empty() => options.clone(),
+            _ => vec!["".to_string(), "".to_string()],
+        };
+
+        match kind {
+            "text" => Some(QuestionType::Text(hint)),
+            "long_text" => Some(QuestionType::LongText(hint)),
+            "single_choice" => Some(QuestionType::SingleChoice {
+                question: hint,
+                options,
+            }),
+            "multiple_choice" => Some(QuestionType::MultipleChoice {
+                question: hint,
+                options,
+                min: None,
+                max: None,
+            }),
+            "likert" => Some(QuestionType::Likert {
+                question: hint,
+                points: 5,
+                min_label: "".to_string(),
+                max_label: "".to_string(),
+            }),
+            "rating" => Some(QuestionType::Rating {
+                question: hint,
+                max: RATING_MAX,
+            }),
+            "nps" => Some(QuestionType::Nps { question: hint }),
+            "matrix" => Some(QuestionType::Matrix {
+                question: hint,
+                rows: options,
+                columns: vec!["".to_string(), "".to_string()],
+                multi: false,
+            }),
+            "ranking" => Some(QuestionType::Ranking {
+                question: hint,
+                options,
+            }),
+            "integer" | "decimal" => Some(QuestionType::Number {
+                question: hint,
+                decimals: if kind == "integer" { 0 } else { 2 },
+                min: None,
+                max: None,
+                step: None,
+            }),
+            "date" => Some(QuestionType::Date {
+                question: hint,
+                min: None,
+                max: None,
+            }),
+            "date_range" => Some(QuestionType::DateRange {
+                question: hint,
+                min: None,
+                max: None,
+            }),
+            "phone" => Some(QuestionType::Phone(hint)),
+            "email" => Some(QuestionType::Email(hint)),
+            "pattern" => Some(QuestionType::Pattern {
+                question: hint,
+                pattern: "".to_string(),
+            }),
+            _ => None,
+        }
+    }
+
+    // is_valid is true if the question can be answered, e.g. a choice has two or more options.
+    pub fn is_valid(&self) -> bool {
+        let options_valid = |options: &Vec<String>| {
+            options.len() >= 2 && options.iter().all(|o| !o.trim().is_empty())
+        };
+
+        match self {
+            QuestionType::Text(_)
+            | QuestionType::LongText(_)
+            | QuestionType::Phone(_)
+            | QuestionType::Email(_) => true,
+            QuestionType::SingleChoice { options, .. } | QuestionType::Ranking { options, .. } => {
+                options_valid(options)
+            }
+            QuestionType::MultipleChoice {
+                options, min, max, ..
+            } => {
+                let max_valid = max.map_or(true, |max| max >= 1 && max <= options.len());
+                let min_valid = min.map_or(true, |min| {
+                    min <= options.len() && min <= max.unwrap_or(options.len())
+                });
+
+                options_valid(options) && min_valid && max_valid
+            }
+            QuestionType::Likert { points, .. } => (2..=LIKERT_MAX_POINTS).contains(points),
+            QuestionType::Rating { max, .. } => (2..=RATING_MAX).contains(max),
+            QuestionType::Nps { .. } => true,
+            QuestionType::Matrix { rows, columns, .. } => {
+                !rows.is_empty()
+                    && rows.iter().all(|r| !r.trim().is_empty())
+                    && options_valid(columns)
+            }
+            QuestionType::Number {
+                decimals,
+                min,
+                max,
+                step,
+                ..
+            } => {
+                let parse = |v: &Option<String>| v.as_ref().map(|v| parse_number(v, *decimals));
+                match (parse(min), parse(max), parse(step)) {
+                    (Some(None), _, _) | (_, Some(None), _) | (_, _, Some(None)) => false,
+                    (min, max, step) => {
+                        let range_valid = match (min.flatten(), max.flatten()) {
+                            (Some(min), Some(max)) => min <= max,
+                            _ => true,
+                        };
+
+                        *decimals <= NUMBER_MAX_DECIMALS
+                            && range_valid
+                            && step.flatten().map_or(true, |step| step > 0)
+                    }
+                }
+            }
+            QuestionType::Date { min, max, .. } | QuestionType::DateRange { min, max, .. } => {
+                let parse = |v: &Option<String>| v.as_ref().map(|v| parse_date(v));
+                match (parse(min), parse(max)) {
+                    (Some(None), _) | (_, Some(None)) => false,
+                    (Some(min), Some(max)) => min <= max,
+                    _ => true,
+                }
+            }
+            QuestionType::Pattern { pattern, .. } => pattern_regex(pattern).is_some(),
+        }
+    }
+
+    // accepts is true if `answer` is of the same type and within its limits.
+    pub fn accepts(&self, answer: &QuestionAnswer) -> bool {
+        match (self, answer) {
+            (QuestionType::Text(_), QuestionAnswer::Text(text)) => {
+                text.chars().count() <= TEXT_MAX_LENGTH
+            }
+            (QuestionType::LongText(_), QuestionAnswer::LongText(text)) => {
+                text.chars().count() <= LONG_TEXT_MAX_LENGTH
+            }
+            (QuestionType::SingleChoice { options, .. }, QuestionAnswer::SingleChoice(index)) => {
+                *index < options.len()
+            }
+            // no selection skips the question, see `QuestionAnswer::is_empty`
+            (
+                QuestionType::MultipleChoice {
+                    options, min, max, ..
+                },
+                QuestionAnswer::MultipleChoice(selected),
+            ) => {
+                let mut unique = selected.clone();
+                unique.sort();
+                unique.dedup();
+
+                unique.len() == selected.len()
+                    && selected.iter().all(|i| *i < options.len())
+                    && (selected.is_empty()
+                        || (selected.len() >= min.unwrap_or(0)
+                            && selected.len() <= max.unwrap_or(options.len())))
+            }
+            (QuestionType::Likert { .. }, QuestionAnswer::Likert(value))
+            | (QuestionType::Rating { .. }, QuestionAnswer::Rating(value))
+            | (QuestionType::Nps { .. }, QuestionAnswer::Nps(value)) => self
+                .scale()
+                .map_or(false, |(min, max)| (min..=max).contains(value)),
+            // every row is answered, or none to skip the question
+            (
+                QuestionType::Matrix {
+                    rows,
+                    columns,
+                    multi,
+                    ..
+                },
+                QuestionAnswer::Matrix(selected),
+            ) => {
+                let row_valid = |row: &Vec<usize>| {
+                    let mut unique = row.clone();
+                    unique.sort();
+                    unique.dedup();
+
+                    unique.len() == row.len()
+                        && row.iter().all(|c| *c < columns.len())
+                        && (*multi || row.len() <= 1)
+                };
+
+                selected.len() == rows.len()
+                    && selected.iter().all(row_valid)
+                    && (selected.iter().all(|r| r.is_empty())
+                        || selected.iter().all(|r| !r.is_empty()))
+            }
+            // a permutation of all the options, or none to skip the question
+            (QuestionType::Ranking { options, .. }, QuestionAnswer::Ranking(order)) => {
+                let mut sorted = order.clone();
+                sorted.sort();
+
+                order.is_empty() || sorted.into_iter().eq(0..options.len())
+            }
+            // blank text skips the following ones, see `QuestionAnswer::is_empty`
+            (
+                QuestionType::Number {
+                    decimals,
+                    min,
+                    max,
+                    step,
+                    ..
+                },
+                QuestionAnswer::Number(text),
+            ) => {
+                if text.trim().is_empty() {
+                    return true;
+                }
+                let bound =
+                    |v: &Option<String>| v.as_ref().and_then(|v| parse_number(v, *decimals));
+                match parse_number(text, *decimals) {
+                    Some(value) => {
+                        let base = bound(min).unwrap_or(0);
+                        bound(min).map_or(true, |min| value >= min)
+                            && bound(max).map_or(true, |max| value <= max)
+                            && bound(step).map_or(true, |step| {
+                                step <= 0 || (value as i128 - base as i128) % step as i128 == 0
+                            })
+                    }
+                    None => false,
+                }
+            }
+            (QuestionType::Date { min, max, .. }, QuestionAnswer::Date(date)) => {
+                date.trim().is_empty() || date_within(date, min, max)
+            }
+            (QuestionType::DateRange { min, max, .. }, QuestionAnswer::DateRange(start, end)) => {
+                (start.trim().is_empty() && end.trim().is_empty())
+                    || (date_within(start, min, max)
+                        && date_within(end, min, max)
+                        && parse_date(start) <= parse_date(end))
+            }
+            (QuestionType::Phone(_), QuestionAnswer::Phone(text)) => {
+                text.trim().is_empty() || is_phone(text)
+            }
+            (QuestionType::Email(_), QuestionAnswer::Email(text)) => {
+                text.trim().is_empty() || is_email(text)
+            }
+            (QuestionType::Pattern { pattern, .. }, QuestionAnswer::Text(text)) => {
+                text.trim().is_empty()
+                    || (text.chars().count() <= TEXT_MAX_LENGTH
+                        && pattern_regex(pattern).map_or(false, |re| re.is_match(text.trim())))
+            }
+            _ => false,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
@@ -29,4 +500,198 @@ pub enum QuestionAnswer {
     Text(String),
     LongText(String),
     SingleChoice(usize),
+    // indexes of the selected options
+    MultipleChoice(Vec<usize>),
+    Likert(u8),
+    Rating(u8),
+    Nps(u8),
+    // indexes of the selected columns for each row
+    Matrix(Vec<Vec<usize>>),
+    // indexes of the options from the first to the last rank
+    Ranking(Vec<usize>),
+    // as typed, see `parse_number`
+    Number(String),
+    Date(String),
+    // the first and the last day
+    DateRange(String, String),
+    Phone(String),
+    Email(String),
+}
+
+impl QuestionAnswer {
+    // is_empty is true for blank text or no selection, which does not answer a required question.
+    pub fn is_empty(&self) -> bool {
+        match self {
+            QuestionAnswer::Text(text)
+            | QuestionAnswer::LongText(text)
+            | QuestionAnswer::Number(text)
+            | QuestionAnswer::Date(text)
+            | QuestionAnswer::Phone(text)
+            | QuestionAnswer::Email(text) => text.trim().is_empty(),
+            QuestionAnswer::DateRange(start, end) => {
+                start.trim().is_empty() && end.trim().is_empty()
+            }
+            QuestionAnswer::MultipleChoice(selected) | QuestionAnswer::Ranking(selected) => {
+                selected.is_empty()
+            }
+            QuestionAnswer::Matrix(selected) => selected.iter().all(|r| r.is_empty()),
+            QuestionAnswer::SingleChoice(_)
+            | QuestionAnswer::Likert(_)
+            | QuestionAnswer::Rating(_)
+            | QuestionAnswer::Nps(_) => false,
+        }
+    }
+
+    // scale_value is the answer to a scale question.
+    pub fn scale_value(&self) -> Option<u8> {
+        match self {
+            QuestionAnswer::Likert(value)
+            | QuestionAnswer::Rating(value)
+            | QuestionAnswer::Nps(value) => Some(*value),
+            _ => None,
+        }
+    }
+}
+
+// date_within is true if `date` is a date between the optional bounds.
+fn date_within(date: &str, min: &Option<String>, max: &Option<String>) -> bool {
+    let bound = |v: &Option<String>| v.as_ref().and_then(|v| parse_date(v));
+    match parse_date(date) {
+        Some(date) => {
+            bound(min).map_or(true, |min| date >= min) && bound(max).map_or(true, |max| date <= max)
+        }
+        None => false,
+    }
+}
+
+// validate_answer is true if `answer` fits `question`, or it is skipped while optional.
+pub fn validate_answer(question: &Question, answer: Option<&QuestionAnswer>) -> bool {
+    match answer {
+        Some(answer) => {
+            question.question.accepts(answer) && !(question.required && answer.is_empty())
+        }
+        None => !question.required,
+    }
+}
+
+// validate_answers checks `answers`, given in the order of `questions`,
+// and returns the index of the first invalid or missing answer.
+// The questions not shown by their logic must not be answered, see `route`.
+pub fn validate_answers(
+    questions: &[Question],
+    answers: &[Option<QuestionAnswer>],
+) -> Result<(), usize> {
+    if answers.len() > questions.len() {
+        return Err(questions.len());
+    }
+
+    let shown = route(questions, answers);
+    match questions.iter().enumerate().find(|(i, q)| {
+        let answer = answers.get(*i).and_then(|a| a.as_ref());
+        match shown[*i] {
+            true => {
+                let allowed = carried_options(questions, *i, |j| match shown[j] {
+                    true => answers.get(j).and_then(|a| a.as_ref()),
+                    false => None,
+                });
+                !validate_answer(q, answer)
+                    || !answer.map_or(true, |a| a.selects_within(allowed.as_deref()))
+            }
+            false => answer.is_some(),
+        }
+    }) {
+        Some((i, _)) => Err(i),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn multiple_choice(min: Option<usize>, max: Option<usize>) -> QuestionType {
+        QuestionType::MultipleChoice {
+            question: None,
+            options: vec!["a".to_string(), "b".to_string(), "c".to_string()],
+            min,
+            max,
+        }
+    }
+
+    #[test]
+    fn multiple_choice_answers_are_within_the_limits() {
+        let question = multiple_choice(Some(2), Some(2));
+        let accepts =
+            |selected: Vec<usize>| question.accepts(&QuestionAnswer::MultipleChoice(selected));
+
+        assert!(!accepts(vec![0]));
+        assert!(accepts(vec![0, 2]));
+        assert!(!accepts(vec![0, 1, 2]));
+        assert!(!accepts(vec![0, 0]));
+        assert!(!accepts(vec![0, 3]));
+    }
+
+    #[test]
+    fn multiple_choice_limits_are_validated() {
+        assert!(multiple_choice(None, None).is_valid());
+        assert!(multiple_choice(Some(1), Some(3)).is_valid());
+        assert!(!multiple_choice(Some(3), Some(2)).is_valid());
+        assert!(!multiple_choice(None, Some(4)).is_valid());
+        assert!(!multiple_choice(Some(4), None).is_valid());
+    }
+
+    fn matrix(multi: bool) -> QuestionType {
+        QuestionType::Matrix {
+            question: None,
+            rows: vec!["r1".to_string(), "r2".to_string()],
+            columns: vec!["c1".to_string(), "c2".to_string(), "c3".to_string()],
+            multi,
+        }
+    }
+
+    #[test]
+    fn matrix_answers_are_within_its_rows_and_columns() {
+        let accepts = |multi: bool, selected: Vec<Vec<usize>>| {
+            matrix(multi).accepts(&QuestionAnswer::Matrix(selected))
+        };
+
+        assert!(accepts(false, vec![vec![0], vec![2]]));
+        assert!(accepts(true, vec![vec![0, 1], vec![2]]));
+        assert!(!accepts(false, vec![vec![0, 1], vec![2]]));
+        // a column or a row out of range
+        assert!(!accepts(false, vec![vec![0], vec![3]]));
+        assert!(!accepts(false, vec![vec![0], vec![1], vec![2]]));
+        assert!(!accepts(false, vec![vec![0]]));
+    }
+
+    #[test]
+    fn matrix_answers_every_row_or_none() {
+        let question = Question {
+            id: "question-1".to_string(),
+            r#type: "question".to_string(),
+            survey_id: "survey-1".to_string(),
+            title: "title".to_string(),
+            question: matrix(false),
+            required: true,
+            position: 0,
+            logic: Default::default(),
+            carry_forward: None,
+            gsi1: "survey-1".to_string(),
+        };
+        let valid = |selected: Vec<Vec<usize>>| {
+            validate_answer(&question, Some(&QuestionAnswer::Matrix(selected)))
+        };
+
+        assert!(valid(vec![vec![0], vec![1]]));
+        assert!(!valid(vec![vec![0], vec![]]));
+        // skipping a required question
+        assert!(!valid(vec![vec![], vec![]]));
+        assert!(validate_answer(
+            &Question {
+                required: false,
+                ..question.clone()
+            },
+            Some(&QuestionAnswer::Matrix(vec![vec![], vec![]]))
+        ));
+    }
 }
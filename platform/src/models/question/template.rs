@@ -0,0 +1,239 @@
+use std::sync::OnceLock;
+
+use regex::{Captures, Regex};
+
+use super::{route, Question, QuestionAnswer, QuestionType};
+
+// `{Qn}` in a title, description or option is replaced by the answer to the n-th question,
+// counted from 1 as the questions are numbered to the author.
+fn reference() -> &'static Regex {
+    static REFERENCE: OnceLock<Regex> = OnceLock::new();
+    REFERENCE.get_or_init(|| Regex::new(r"\{Q([0-9]+)\}").unwrap())
+}
+
+// references returns the question numbers `text` refers to.
+pub fn references(text: &str) -> Vec<usize> {
+    reference()
+        .captures_iter(text)
+        .map(|c| c[1].parse().unwrap_or(usize::MAX))
+        .collect()
+}
+
+// fill replaces the references of `text` with `value` of the question number.
+pub fn fill(text: &str, value: impl Fn(usize) -> String) -> String {
+    reference()
+        .replace_all(text, |c: &Captures| match c[1].parse() {
+            Ok(n) => value(n),
+            Err(_) => "".to_string(),
+        })
+        .into_owned()
+}
+
+// renumber changes the question numbers `text` refers to with `map`;
+// a question it does not map is gone, so its references become `{Q0}`, which `check_templates` rejects.
+pub fn renumber(text: &str, map: &dyn Fn(usize) -> Option<usize>) -> String {
+    reference()
+        .replace_all(text, |c: &Captures| {
+            let n = c[1].parse().ok().and_then(|n| map(n)).unwrap_or_default();
+            format!("{{Q{n}}}")
+        })
+        .into_owned()
+}
+
+// renumber_question applies `renumber` to the title, texts and carried forward options of a question.
+pub fn renumber_question(
+    title: &mut String,
+    question: &mut QuestionType,
+    carry_forward: &mut Option<u32>,
+    map: &dyn Fn(usize) -> Option<usize>,
+) {
+    *title = renumber(title, map);
+    for text in question.texts_mut() {
+        *text = renumber(text, map);
+    }
+    if let Some(n) = carry_forward {
+        *n = map(*n as usize).unwrap_or_default() as u32;
+    }
+}
+
+impl QuestionType {
+    // texts are the description and options, which may refer to earlier answers.
+    pub fn texts(&self) -> Vec<&String> {
+        let mut texts: Vec<&String> = self.hint().iter().collect();
+        match self {
+            QuestionType::Matrix { rows, columns, .. } => {
+                texts.extend(rows.iter().chain(columns.iter()))
+            }
+            _ => texts.extend(self.options().into_iter().flatten()),
+        }
+
+        texts
+    }
+
+    pub fn texts_mut(&mut self) -> Vec<&mut String> {
+        match self {
+            QuestionType::SingleChoice { question, options }
+            | QuestionType::MultipleChoice {
+                question, options, ..
+            }
+            | QuestionType::Ranking { question, options } => {
+                question.iter_mut().chain(options.iter_mut()).collect()
+            }
+            QuestionType::Matrix {
+                question,
+                rows,
+                columns,
+                ..
+            } => question
+                .iter_mut()
+                .chain(rows.iter_mut())
+                .chain(columns.iter_mut())
+                .collect(),
+            question => question.hint_mut().iter_mut().collect(),
+        }
+    }
+
+    // pipes is true if an answer to the question can be written into a text.
+    fn pipes(&self) -> bool {
+        !matches!(self, QuestionType::Matrix { .. })
+    }
+
+    fn chooses(&self) -> bool {
+        matches!(
+            self,
+            QuestionType::SingleChoice { .. } | QuestionType::MultipleChoice { .. }
+        )
+    }
+}
+
+// answer_text writes `answer` to `question` as it is filled into a text.
+pub fn answer_text(question: &QuestionType, answer: &QuestionAnswer) -> String {
+    let option = |i: &usize| {
+        question
+            .options()
+            .and_then(|options| options.get(*i))
+            .cloned()
+            .unwrap_or_default()
+    };
+
+    match answer {
+        QuestionAnswer::Text(text)
+        | QuestionAnswer::LongText(text)
+        | QuestionAnswer::Number(text)
+        | QuestionAnswer::Date(text)
+        | QuestionAnswer::Phone(text)
+        | QuestionAnswer::Email(text) => text.trim().to_string(),
+        QuestionAnswer::DateRange(start, end) => format!("{start} ~ {end}"),
+        QuestionAnswer::SingleChoice(i) => option(i),
+        QuestionAnswer::MultipleChoice(selected) | QuestionAnswer::Ranking(selected) => {
+            selected.iter().map(option).collect::<Vec<_>>().join(", ")
+        }
+        QuestionAnswer::Likert(v) | QuestionAnswer::Rating(v) | QuestionAnswer::Nps(v) => {
+            v.to_string()
+        }
+        QuestionAnswer::Matrix(_) => "".to_string(),
+    }
+}
+
+// carried_options returns the options of the question at `index` a respondent may select
+// when it carries forward the ones selected in an earlier question; `answer` gives the
+// answers to the questions shown so far.
+pub fn carried_options<'a>(
+    questions: &[Question],
+    index: usize,
+    answer: impl Fn(usize) -> Option<&'a QuestionAnswer>,
+) -> Option<Vec<usize>> {
+    let source = (questions.get(index)?.carry_forward? as usize).checked_sub(1);
+
+    Some(match source.filter(|s| *s < index).and_then(answer) {
+        Some(QuestionAnswer::SingleChoice(i)) => vec![*i],
+        Some(QuestionAnswer::MultipleChoice(selected)) => selected.clone(),
+        _ => vec![],
+    })
+}
+
+impl QuestionAnswer {
+    // selects_within is true if the answer selects only `allowed` options, see `carried_options`.
+    pub fn selects_within(&self, allowed: Option<&[usize]>) -> bool {
+        let Some(allowed) = allowed else {
+            return true;
+        };
+        match self {
+            QuestionAnswer::SingleChoice(i) => allowed.contains(i),
+            QuestionAnswer::MultipleChoice(selected) => {
+                selected.iter().all(|i| allowed.contains(i))
+            }
+            _ => true,
+        }
+    }
+}
+
+// carry_options copies the options of the questions carried forward to the ones carrying them,
+// so that their answers count the same options; it serves both stored and edited questions.
+pub fn carry_options<T>(
+    questions: &mut [T],
+    carry_forward: fn(&T) -> Option<u32>,
+    question: fn(&mut T) -> &mut QuestionType,
+) {
+    for i in 0..questions.len() {
+        let source = carry_forward(&questions[i])
+            .and_then(|n| (n as usize).checked_sub(1))
+            .filter(|s| *s < i)
+            .and_then(|s| question(&mut questions[s]).options().cloned());
+        if let (Some(source), Some(options)) = (source, question(&mut questions[i]).options_mut()) {
+            *options = source;
+        }
+    }
+}
+
+// resolve returns the question at `index` as shown to a respondent,
+// with its references filled with the earlier answers shown.
+pub fn resolve(
+    questions: &[Question],
+    answers: &[Option<QuestionAnswer>],
+    index: usize,
+) -> Option<Question> {
+    let shown = route(questions, answers);
+    let value = |n: usize| match n.checked_sub(1).filter(|j| *j < index && shown[*j]) {
+        Some(j) => answers
+            .get(j)
+            .and_then(|a| a.as_ref())
+            .map(|a| answer_text(&questions[j].question, a))
+            .unwrap_or_default(),
+        None => "".to_string(),
+    };
+
+    let mut question = questions.get(index)?.clone();
+    question.title = fill(&question.title, &value);
+    for text in question.question.texts_mut() {
+        *text = fill(text, &value);
+    }
+
+    Some(question)
+}
+
+// check_templates validates the references and carried forward options of `questions`,
+// which must refer to earlier questions, and returns the index of the first invalid one.
+pub fn check_templates(questions: &[Question]) -> Result<(), usize> {
+    for (i, question) in questions.iter().enumerate() {
+        // the number of an earlier question, counted from 1
+        let earlier = |n: usize| n.checked_sub(1).filter(|j| *j < i).map(|j| &questions[j]);
+        let texts = std::iter::once(&question.title).chain(question.question.texts());
+        let references_valid = texts
+            .flat_map(|text| references(text))
+            .all(|n| earlier(n).map_or(false, |q| q.question.pipes()));
+        let carry_valid = match question.carry_forward {
+            Some(n) => {
+                question.question.chooses()
+                    && earlier(n as usize).map_or(false, |q| q.question.chooses())
+            }
+            None => true,
+        };
+
+        if !references_valid || !carry_valid {
+            return Err(i);
+        }
+    }
+
+    Ok(())
+}
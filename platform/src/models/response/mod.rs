@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+
+use super::{question::QuestionAnswer, survey::ProofId};
+
+// SurveyResponse is the answers of one respondent, who can answer a survey only once.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SurveyResponse {
+    pub id: String,
+    pub r#type: String,
+    pub survey_id: String,
+    pub proof_id: ProofId,
+    // in the order of the survey questions; `None` is a skipped question
+    pub answers: Vec<Option<QuestionAnswer>>,
+    pub created_at: u64,
+
+    // list responses by survey id
+    pub gsi1: String,
+}
+
+impl SurveyResponse {
+    pub fn new(
+        survey_id: &str,
+        proof_id: &str,
+        answers: Vec<Option<QuestionAnswer>>,
+        now: u64,
+    ) -> Self {
+        SurveyResponse {
+            id: Self::key(survey_id, proof_id),
+            r#type: "response".to_string(),
+            survey_id: survey_id.to_string(),
+            proof_id: proof_id.to_string(),
+            answers,
+            created_at: now,
+            gsi1: Self::gsi1(survey_id),
+        }
+    }
+
+    pub fn key(survey_id: &str, proof_id: &str) -> String {
+        format!("response#{survey_id}#{proof_id}")
+    }
+
+    pub fn gsi1(survey_id: &str) -> String {
+        format!("response#{survey_id}")
+    }
+}
@@ -0,0 +1,485 @@
+use serde::{Deserialize, Serialize};
+
+use super::{
+    question::{format_number, parse_number, Question, QuestionAnswer, QuestionType},
+    response::SurveyResponse,
+    survey::SurveySummary,
+};
+
+// at most this many text answers are returned per question, newest first
+pub const TEXT_ANSWER_LIMIT: usize = 100;
+// numeric answers are counted in at most this many ranges of the same width
+pub const HISTOGRAM_BINS: i64 = 10;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct SurveyResult {
+    pub survey: SurveySummary,
+    pub responses: u64,
+    pub questions: Vec<QuestionResult>,
+}
+
+// QuestionResult summarizes the answers to a question.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QuestionResult {
+    pub question_id: String,
+    pub title: String,
+    // the number of respondents who answered it
+    pub answered: u64,
+    pub summary: AnswerSummary,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AnswerSummary {
+    Text(Vec<String>),
+    // how many respondents selected each option
+    Choice {
+        options: Vec<String>,
+        counts: Vec<u64>,
+    },
+    // `counts[i]` is the number of answers of `min + i`; a rating has no labels
+    Scale {
+        min: u8,
+        max: u8,
+        min_label: String,
+        max_label: String,
+        counts: Vec<u64>,
+        mean: f64,
+    },
+    // promoters answered 9 or 10, passives 7 or 8 and detractors 0 to 6
+    Nps {
+        counts: Vec<u64>,
+        mean: f64,
+        promoters: u64,
+        passives: u64,
+        detractors: u64,
+        // percentage of promoters minus percentage of detractors, from -100 to 100
+        score: f64,
+    },
+    // `counts[row][column]` is the number of answers selecting `column` for `row`
+    Matrix {
+        rows: Vec<String>,
+        columns: Vec<String>,
+        counts: Vec<Vec<u64>>,
+    },
+    // the options ordered from the highest borda count; the first rank of n options
+    // scores n - 1 points and the last 0, and `average_rank` counts ranks from 1
+    Ranking {
+        options: Vec<String>,
+        borda: Vec<u64>,
+        average_rank: Vec<f64>,
+    },
+    Number {
+        mean: f64,
+        median: f64,
+        min: String,
+        max: String,
+        histogram: Vec<Bin>,
+    },
+}
+
+// Bin counts the numeric answers from `start` to `end`, both inclusive.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Bin {
+    pub start: String,
+    pub end: String,
+    pub count: u64,
+}
+
+// aggregate summarizes `responses`, whose answers are in the order of `questions`.
+pub fn aggregate(questions: &[Question], responses: &[SurveyResponse]) -> Vec<QuestionResult> {
+    let mut responses: Vec<&SurveyResponse> = responses.iter().collect();
+    responses.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+
+    questions
+        .iter()
+        .enumerate()
+        .map(|(i, question)| {
+            let answers: Vec<&QuestionAnswer> = responses
+                .iter()
+                .filter_map(|r| r.answers.get(i).and_then(|a| a.as_ref()))
+                .filter(|a| !a.is_empty())
+                .collect();
+
+            QuestionResult {
+                question_id: question.id.clone(),
+                title: question.title.clone(),
+                answered: answers.len() as u64,
+                summary: summarize(&question.question, &answers),
+            }
+        })
+        .collect()
+}
+
+fn summarize(question: &QuestionType, answers: &[&QuestionAnswer]) -> AnswerSummary {
+    match question {
+        QuestionType::Text(_)
+        | QuestionType::LongText(_)
+        | QuestionType::Date { .. }
+        | QuestionType::DateRange { .. }
+        | QuestionType::Phone(_)
+        | QuestionType::Email(_)
+        | QuestionType::Pattern { .. } => AnswerSummary::Text(
+            answers
+                .iter()
+                .filter_map(|a| match a {
+                    QuestionAnswer::Text(text)
+                    | QuestionAnswer::LongText(text)
+                    | QuestionAnswer::Date(text)
+                    | QuestionAnswer::Phone(text)
+                    | QuestionAnswer::Email(text) => Some(text.clone()),
+                    QuestionAnswer::DateRange(start, end) => Some(format!("{start} ~ {end}")),
+                    _ => None,
+                })
+                .take(TEXT_ANSWER_LIMIT)
+                .collect(),
+        ),
+        QuestionType::SingleChoice { options, .. }
+        | QuestionType::MultipleChoice { options, .. } => {
+            let mut counts = vec![0; options.len()];
+            for answer in answers {
+                let selected = match answer {
+                    QuestionAnswer::SingleChoice(index) => vec![*index],
+                    QuestionAnswer::MultipleChoice(selected) => selected.clone(),
+                    _ => vec![],
+                };
+                for index in selected {
+                    if let Some(count) = counts.get_mut(index) {
+                        *count += 1;
+                    }
+                }
+            }
+
+            AnswerSummary::Choice {
+                options: options.clone(),
+                counts,
+            }
+        }
+        QuestionType::Matrix { rows, columns, .. } => {
+            let mut counts = vec![vec![0; columns.len()]; rows.len()];
+            for answer in answers {
+                if let QuestionAnswer::Matrix(selected) = answer {
+                    for (row, selected) in counts.iter_mut().zip(selected) {
+                        for column in selected {
+                            if let Some(count) = row.get_mut(*column) {
+                                *count += 1;
+                            }
+                        }
+                    }
+                }
+            }
+
+            AnswerSummary::Matrix {
+                rows: rows.clone(),
+                columns: columns.clone(),
+                counts,
+            }
+        }
+        QuestionType::Number { decimals, .. } => {
+            let mut values: Vec<i64> = answers
+                .iter()
+                .filter_map(|a| match a {
+                    QuestionAnswer::Number(text) => parse_number(text, *decimals),
+                    _ => None,
+                })
+                .collect();
+            values.sort();
+
+            summarize_numbers(&values, *decimals)
+        }
+        QuestionType::Ranking { options, .. } => {
+            let n = options.len();
+            let mut ranks = vec![vec![]; n];
+            for answer in answers {
+                if let QuestionAnswer::Ranking(order) = answer {
+                    for (rank, option) in order.iter().enumerate() {
+                        if let Some(ranks) = ranks.get_mut(*option) {
+                            ranks.push(rank);
+                        }
+                    }
+                }
+            }
+
+            let mut ranked: Vec<(String, u64, f64)> = options
+                .iter()
+                .zip(ranks)
+                .map(|(option, ranks)| {
+                    let borda = ranks.iter().map(|r| (n - 1 - r) as u64).sum();
+                    let average_rank = mean(ranks.iter().map(|r| (r + 1) as f64));
+                    (option.clone(), borda, average_rank)
+                })
+                .collect();
+            ranked.sort_by(|a, b| b.1.cmp(&a.1));
+
+            AnswerSummary::Ranking {
+                options: ranked.iter().map(|r| r.0.clone()).collect(),
+                borda: ranked.iter().map(|r| r.1).collect(),
+                average_rank: ranked.iter().map(|r| r.2).collect(),
+            }
+        }
+        QuestionType::Likert { .. } | QuestionType::Rating { .. } | QuestionType::Nps { .. } => {
+            let (min, max) = question.scale().unwrap_or_default();
+            let values: Vec<u8> = answers
+                .iter()
+                .filter_map(|a| a.scale_value())
+                .filter(|v| (min..=max).contains(v))
+                .collect();
+            // saturating for questions stored before creation validated them
+            let mut counts = vec![0; max.saturating_sub(min) as usize + 1];
+            for value in values.iter() {
+                counts[(value - min) as usize] += 1;
+            }
+            let mean = mean(values.iter().map(|v| *v as f64));
+
+            match question {
+                QuestionType::Nps { .. } => {
+                    let count = |range: std::ops::RangeInclusive<u8>| {
+                        values.iter().filter(|v| range.contains(v)).count() as u64
+                    };
+                    let (promoters, passives, detractors) =
+                        (count(9..=10), count(7..=8), count(0..=6));
+                    let score = match values.len() {
+                        0 => 0.0,
+                        n => (promoters as f64 - detractors as f64) * 100.0 / n as f64,
+                    };
+
+                    AnswerSummary::Nps {
+                        counts,
+                        mean,
+                        promoters,
+                        passives,
+                        detractors,
+                        score,
+                    }
+                }
+                QuestionType::Likert {
+                    min_label,
+                    max_label,
+                    ..
+                } => AnswerSummary::Scale {
+                    min,
+                    max,
+                    min_label: min_label.clone(),
+                    max_label: max_label.clone(),
+                    counts,
+                    mean,
+                },
+                _ => AnswerSummary::Scale {
+                    min,
+                    max,
+                    min_label: "".to_string(),
+                    max_label: "".to_string(),
+                    counts,
+                    mean,
+                },
+            }
+        }
+    }
+}
+
+// mean is the average of `values`, or 0 if there are none.
+pub fn mean(values: impl Iterator<Item = f64>) -> f64 {
+    let (sum, count) = values.fold((0.0, 0), |(sum, count), v| (sum + v, count + 1));
+
+    match count {
+        0 => 0.0,
+        count => sum / count as f64,
+    }
+}
+
+// summarize_numbers summarizes sorted `values` scaled by 10^decimals, see `parse_number`.
+fn summarize_numbers(values: &[i64], decimals: u8) -> AnswerSummary {
+    let scale = 10f64.powi(decimals as i32);
+    let (min, max) = match (values.first(), values.last()) {
+        (Some(min), Some(max)) => (*min, *max),
+        _ => (0, 0),
+    };
+    let median = match values.len() {
+        0 => 0.0,
+        n if n % 2 == 1 => values[n / 2] as f64,
+        n => (values[n / 2 - 1] as f64 + values[n / 2] as f64) / 2.0,
+    };
+
+    // the width is rounded up so that the bins cover every value
+    let width = ((max as i128 - min as i128 + 1) as u128).div_ceil(HISTOGRAM_BINS as u128) as i64;
+    let mut histogram: Vec<Bin> = vec![];
+    if !values.is_empty() {
+        let mut start = min;
+        loop {
+            let end = start.saturating_add(width - 1).min(max);
+            histogram.push(Bin {
+                start: format_number(start, decimals),
+                end: format_number(end, decimals),
+                count: values.iter().filter(|v| (start..=end).contains(v)).count() as u64,
+            });
+            if end >= max {
+                break;
+            }
+            start = end + 1;
+        }
+    }
+
+    AnswerSummary::Number {
+        mean: mean(values.iter().map(|v| *v as f64 / scale)),
+        median: median / scale,
+        min: format_number(min, decimals),
+        max: format_number(max, decimals),
+        histogram,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn number(values: &[i64], decimals: u8) -> (f64, Vec<Bin>) {
+        match summarize_numbers(values, decimals) {
+            AnswerSummary::Number {
+                median, histogram, ..
+            } => (median, histogram),
+            summary => panic!("not a number summary: {summary:?}"),
+        }
+    }
+
+    #[test]
+    fn median_of_odd_and_even_counts() {
+        assert_eq!(number(&[1, 3, 10], 0).0, 3.0);
+        assert_eq!(number(&[1, 3, 4, 10], 0).0, 3.5);
+        // scaled by 10^decimals
+        assert_eq!(number(&[125, 250], 2).0, 1.875);
+        assert_eq!(number(&[], 0).0, 0.0);
+    }
+
+    #[test]
+    fn histogram_covers_every_value() {
+        // 23 values do not split in 10 bins of the same width
+        let values: Vec<i64> = (0..=22).collect();
+        let (_, histogram) = number(&values, 0);
+
+        assert!(histogram.len() as i64 <= HISTOGRAM_BINS);
+        assert_eq!(histogram.first().unwrap().start, "0");
+        assert_eq!(histogram.last().unwrap().end, "22");
+        for bins in histogram.windows(2) {
+            let end: i64 = bins[0].end.parse().unwrap();
+            assert_eq!(bins[1].start, (end + 1).to_string());
+        }
+        assert_eq!(
+            histogram.iter().map(|b| b.count).sum::<u64>(),
+            values.len() as u64
+        );
+    }
+
+    #[test]
+    fn histogram_of_the_widest_range() {
+        let (_, histogram) = number(&[i64::MIN, 0, i64::MAX], 0);
+
+        assert!(histogram.len() as i64 <= HISTOGRAM_BINS);
+        assert_eq!(histogram.iter().map(|b| b.count).sum::<u64>(), 3);
+        assert_eq!(histogram.last().unwrap().end, i64::MAX.to_string());
+    }
+
+    fn nps(values: &[u8]) -> AnswerSummary {
+        let answers: Vec<QuestionAnswer> = values.iter().map(|v| QuestionAnswer::Nps(*v)).collect();
+        let answers: Vec<&QuestionAnswer> = answers.iter().collect();
+
+        summarize(&QuestionType::Nps { question: None }, &answers)
+    }
+
+    #[test]
+    fn nps_buckets_answers_at_their_edges() {
+        match nps(&[0, 6, 7, 8, 9, 10]) {
+            AnswerSummary::Nps {
+                counts,
+                promoters,
+                passives,
+                detractors,
+                score,
+                ..
+            } => {
+                assert_eq!(counts, vec![1, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1]);
+                assert_eq!((promoters, passives, detractors), (2, 2, 2));
+                assert_eq!(score, 0.0);
+            }
+            summary => panic!("not an nps summary: {summary:?}"),
+        }
+    }
+
+    #[test]
+    fn nps_score_is_promoters_minus_detractors() {
+        // 3 promoters, 1 passive and 1 detractor of 5
+        match nps(&[10, 9, 9, 7, 3]) {
+            AnswerSummary::Nps { score, mean, .. } => {
+                assert_eq!(score, 40.0);
+                assert_eq!(mean, 7.6);
+            }
+            summary => panic!("not an nps summary: {summary:?}"),
+        }
+        match nps(&[0, 5]) {
+            AnswerSummary::Nps { score, .. } => assert_eq!(score, -100.0),
+            summary => panic!("not an nps summary: {summary:?}"),
+        }
+    }
+
+    #[test]
+    fn nps_without_responses() {
+        assert_eq!(
+            nps(&[]),
+            AnswerSummary::Nps {
+                counts: vec![0; 11],
+                mean: 0.0,
+                promoters: 0,
+                passives: 0,
+                detractors: 0,
+                score: 0.0,
+            }
+        );
+    }
+
+    #[test]
+    fn matrix_counts_each_row_and_column() {
+        let question = QuestionType::Matrix {
+            question: None,
+            rows: vec!["r1".to_string(), "r2".to_string()],
+            columns: vec!["c1".to_string(), "c2".to_string(), "c3".to_string()],
+            multi: true,
+        };
+        let answers = [
+            QuestionAnswer::Matrix(vec![vec![0], vec![1, 2]]),
+            QuestionAnswer::Matrix(vec![vec![0, 2], vec![2]]),
+        ];
+        let answers: Vec<&QuestionAnswer> = answers.iter().collect();
+
+        assert_eq!(
+            summarize(&question, &answers),
+            AnswerSummary::Matrix {
+                rows: vec!["r1".to_string(), "r2".to_string()],
+                columns: vec!["c1".to_string(), "c2".to_string(), "c3".to_string()],
+                counts: vec![vec![2, 0, 1], vec![0, 1, 2]],
+            }
+        );
+    }
+
+    #[test]
+    fn ranking_orders_options_by_borda_count() {
+        let question = QuestionType::Ranking {
+            question: None,
+            options: vec!["a".to_string(), "b".to_string(), "c".to_string()],
+        };
+        let answers = [
+            QuestionAnswer::Ranking(vec![1, 0, 2]),
+            QuestionAnswer::Ranking(vec![1, 2, 0]),
+            QuestionAnswer::Ranking(vec![0, 1, 2]),
+        ];
+        let answers: Vec<&QuestionAnswer> = answers.iter().collect();
+
+        assert_eq!(
+            summarize(&question, &answers),
+            AnswerSummary::Ranking {
+                options: vec!["b".to_string(), "a".to_string(), "c".to_string()],
+                // 3 options score 2, 1 and 0 points from the first rank
+                borda: vec![5, 3, 1],
+                average_rank: vec![4.0 / 3.0, 2.0, 8.0 / 3.0],
+            }
+        );
+    }
+}
@@ -0,0 +1,89 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Role {
+    Owner,
+    Editor,
+    Analyst,
+    Viewer,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Permission {
+    Read,
+    Edit,
+    Launch,
+    ViewResults,
+    ManageMembers,
+}
+
+impl Role {
+    pub const ALL: [Role; 4] = [Role::Owner, Role::Editor, Role::Analyst, Role::Viewer];
+
+    pub fn allows(&self, permission: Permission) -> bool {
+        match self {
+            Role::Owner => true,
+            Role::Editor => matches!(
+                permission,
+                Permission::Read | Permission::Edit | Permission::Launch | Permission::ViewResults
+            ),
+            Role::Analyst => matches!(permission, Permission::Read | Permission::ViewResults),
+            Role::Viewer => matches!(permission, Permission::Read),
+        }
+    }
+
+    // rank orders roles from the most to the least privileged.
+    pub fn rank(&self) -> u8 {
+        match self {
+            Role::Owner => 3,
+            Role::Editor => 2,
+            Role::Analyst => 1,
+            Role::Viewer => 0,
+        }
+    }
+}
+
+// RoleBinding grants `role` on a resource (an organization or a survey) to a user.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RoleBinding {
+    pub id: String,
+    pub r#type: String,
+    pub user_id: String,
+    pub resource_id: String,
+    pub role: Role,
+    pub created_at: u64,
+
+    // list role bindings by user
+    pub gsi1: String,
+    // list role bindings by resource
+    pub gsi2: String,
+}
+
+impl RoleBinding {
+    pub fn new(resource_id: &str, user_id: &str, role: Role, now: u64) -> Self {
+        RoleBinding {
+            id: Self::key(resource_id, user_id),
+            r#type: "role_binding".to_string(),
+            user_id: user_id.to_string(),
+            resource_id: resource_id.to_string(),
+            role,
+            created_at: now,
+            gsi1: Self::gsi1(user_id),
+            gsi2: Self::gsi2(resource_id),
+        }
+    }
+
+    pub fn key(resource_id: &str, user_id: &str) -> String {
+        format!("role#{resource_id}#{user_id}")
+    }
+
+    pub fn gsi1(user_id: &str) -> String {
+        format!("role#{user_id}")
+    }
+
+    pub fn gsi2(resource_id: &str) -> String {
+        format!("role#{resource_id}")
+    }
+}
@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+
+use super::survey::SurveyAction;
+
+// SurveySchedule is when a survey opens and closes automatically.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize, Default)]
+pub struct SurveySchedule {
+    pub start_at: Option<u64>,
+    pub end_at: Option<u64>,
+}
+
+// ScheduledJob applies `action` to a survey once `run_at` has passed.
+// There is at most one job per survey and action.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScheduledJob {
+    pub id: String,
+    pub r#type: String,
+    pub survey_id: String,
+    pub action: SurveyAction,
+    pub run_at: u64,
+    pub created_at: u64,
+
+    // list pending jobs
+    pub gsi1: String,
+}
+
+impl ScheduledJob {
+    pub const PENDING: &'static str = "schedule#pending";
+
+    pub fn new(survey_id: &str, action: SurveyAction, run_at: u64, now: u64) -> Self {
+        ScheduledJob {
+            id: Self::key(survey_id, action),
+            r#type: "scheduled_job".to_string(),
+            survey_id: survey_id.to_string(),
+            action,
+            run_at,
+            created_at: now,
+            gsi1: Self::PENDING.to_string(),
+        }
+    }
+
+    pub fn key(survey_id: &str, action: SurveyAction) -> String {
+        format!("schedule#{survey_id}#{:?}", action)
+    }
+}
@@ -4,6 +4,8 @@ use dioxus::prelude::*;
 
 use serde::{Deserialize, Serialize};
 
+use super::schedule::SurveySchedule;
+
 #[derive(Debug, Clone, PartialEq, Props, Serialize, Deserialize, Default)]
 pub struct Survey {
     pub title: String,
@@ -42,22 +44,96 @@ pub struct SurveySummary {
     pub id: String,
     pub status: SurveyStatus,
     pub title: String,
+    #[serde(default)]
+    pub description: String,
     pub updated_at: u64,
     pub questions: u64,
     pub responses: Option<u64>,
     pub expected_responses: Option<u64>,
     pub quotas: Option<Vec<Quota>>,
-    #[serde(skip)]
+    // archived surveys are hidden from the dashboard until restored
+    #[serde(default)]
+    pub archived_at: Option<u64>,
+    #[serde(default)]
+    pub schedule: Option<SurveySchedule>,
+    #[serde(default)]
     pub r#type: String,
 
-    // list surveys by account
-    #[serde(skip)]
+    // list surveys by organization
+    #[serde(default)]
     pub gsi1: String,
     // list surveys by status
-    #[serde(skip)]
+    #[serde(default)]
     pub gsi2: String,
 }
 
+impl SurveySummary {
+    // gsi2 lists the surveys of an organization by status name, see `SurveyStatus::name`.
+    pub fn gsi2(organization_id: &str, status: &str) -> String {
+        format!("{organization_id}#{status}")
+    }
+
+    pub const ARCHIVED: &'static str = "archived";
+
+    // should_close is true if an in-progress survey passed its end time or filled its quotas.
+    pub fn should_close(&self, now: u64) -> bool {
+        match self.status {
+            SurveyStatus::InProgress { ended_at, .. } => {
+                ended_at.map(|t| t <= now).unwrap_or(false) || self.quota_filled()
+            }
+            _ => false,
+        }
+    }
+
+    // quota_filled is true once the survey collected all expected responses,
+    // a response of every panel or as many responses as its attribute quotas add up to.
+    // Responses carry no attributes of their respondents, so attribute quotas are filled together.
+    pub fn quota_filled(&self) -> bool {
+        let responses = self.responses.unwrap_or_default();
+        let (mut panels, mut attributes) = (0, 0);
+        for quota in self.quotas.iter().flatten() {
+            match quota {
+                Quota::Panel(_) => panels += 1,
+                Quota::Attribute { quota, .. } => attributes += quota,
+            }
+        }
+
+        [
+            self.expected_responses.unwrap_or_default(),
+            panels,
+            attributes,
+        ]
+        .into_iter()
+        .any(|expected| expected > 0 && responses >= expected)
+    }
+
+    // panels are the respondents a survey is sent to; a survey with panels only accepts them.
+    pub fn panels(&self) -> Vec<&ProofId> {
+        self.quotas
+            .iter()
+            .flatten()
+            .filter_map(|q| match q {
+                Quota::Panel(proof_id) => Some(proof_id),
+                _ => None,
+            })
+            .collect()
+    }
+
+    // questions_locked is true once a launched survey has responses,
+    // after which its questions must not change.
+    pub fn questions_locked(&self) -> bool {
+        self.status != SurveyStatus::Draft && self.responses.unwrap_or_default() > 0
+    }
+
+    // status_index is the gsi2 of the survey in its current state.
+    pub fn status_index(&self) -> String {
+        match self.archived_at {
+            Some(_) => Self::gsi2(&self.gsi1, Self::ARCHIVED),
+            None => Self::gsi2(&self.gsi1, self.status.name()),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
 #[serde(rename_all = "snake_case")]
 pub enum SurveyStatus {
@@ -67,9 +143,82 @@ pub enum SurveyStatus {
         started_at: u64,
         ended_at: Option<u64>,
     },
+    Paused {
+        started_at: u64,
+        ended_at: Option<u64>,
+        paused_at: u64,
+    },
     Finished,
 }
 
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SurveyAction {
+    Launch,
+    Pause,
+    Resume,
+    Close,
+    Reopen,
+}
+
+impl SurveyStatus {
+    pub fn name(&self) -> &'static str {
+        match self {
+            SurveyStatus::Draft => "draft",
+            SurveyStatus::InProgress { .. } => "in_progress",
+            SurveyStatus::Paused { .. } => "paused",
+            SurveyStatus::Finished => "finished",
+        }
+    }
+
+    // transition returns the status after `action`, or None if `action` is not allowed.
+    // `ended_at` replaces the end time when launching, resuming or reopening.
+    pub fn transition(
+        &self,
+        action: SurveyAction,
+        ended_at: Option<u64>,
+        now: u64,
+    ) -> Option<Self> {
+        match (self, action) {
+            (SurveyStatus::Draft, SurveyAction::Launch) => Some(SurveyStatus::InProgress {
+                started_at: now,
+                ended_at,
+            }),
+            (
+                SurveyStatus::InProgress {
+                    started_at,
+                    ended_at,
+                },
+                SurveyAction::Pause,
+            ) => Some(SurveyStatus::Paused {
+                started_at: *started_at,
+                ended_at: *ended_at,
+                paused_at: now,
+            }),
+            (
+                SurveyStatus::Paused {
+                    started_at,
+                    ended_at: previous,
+                    ..
+                },
+                SurveyAction::Resume,
+            ) => Some(SurveyStatus::InProgress {
+                started_at: *started_at,
+                ended_at: ended_at.or(*previous),
+            }),
+            (
+                SurveyStatus::InProgress { .. } | SurveyStatus::Paused { .. },
+                SurveyAction::Close,
+            ) => Some(SurveyStatus::Finished),
+            (SurveyStatus::Finished, SurveyAction::Reopen) => Some(SurveyStatus::InProgress {
+                started_at: now,
+                ended_at,
+            }),
+            _ => None,
+        }
+    }
+}
+
 impl fmt::Display for SurveyStatus {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
@@ -78,6 +227,15 @@ impl fmt::Display for SurveyStatus {
                 started_at,
                 ended_at,
             } => write!(f, "in_progress {started_at} {}", ended_at.unwrap_or(0)),
+            SurveyStatus::Paused {
+                started_at,
+                ended_at,
+                paused_at,
+            } => write!(
+                f,
+                "paused {started_at} {} {paused_at}",
+                ended_at.unwrap_or(0)
+            ),
             SurveyStatus::Finished => write!(f, "finished"),
         }
     }
@@ -127,3 +285,55 @@ pub enum Age {
         inclusive_max: u8,
     },
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn running(quotas: Vec<Quota>, responses: u64) -> SurveySummary {
+        SurveySummary {
+            status: SurveyStatus::InProgress {
+                started_at: 100,
+                ended_at: Some(200),
+            },
+            responses: Some(responses),
+            quotas: Some(quotas),
+            ..Default::default()
+        }
+    }
+
+    fn attribute(quota: u64) -> Quota {
+        Quota::Attribute {
+            salary_tier: None,
+            region_code: None,
+            gender: None,
+            age: None,
+            quota,
+        }
+    }
+
+    #[test]
+    fn closes_when_every_panel_answered() {
+        let panels = vec![Quota::Panel("a".to_string()), Quota::Panel("b".to_string())];
+
+        assert!(!running(panels.clone(), 1).should_close(150));
+        assert!(running(panels, 2).should_close(150));
+    }
+
+    #[test]
+    fn closes_when_attribute_quotas_are_filled() {
+        let quotas = vec![attribute(3), attribute(2)];
+
+        assert!(!running(quotas.clone(), 4).should_close(150));
+        assert!(running(quotas, 5).should_close(150));
+        assert!(!running(vec![attribute(0)], 5).should_close(150));
+    }
+
+    #[test]
+    fn closes_at_its_end() {
+        let survey = running(vec![], 0);
+
+        assert!(!survey.should_close(199));
+        assert!(survey.should_close(200));
+    }
+}
@@ -0,0 +1,272 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::role::Role;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct User {
+    pub id: String,
+    pub r#type: String,
+    pub email: String,
+    // Argon2id hash of the password with its salt and parameters, see `utils::password`
+    pub password: String,
+    pub name: String,
+    pub phone: String,
+    pub address: String,
+    pub kind: UserKind,
+    pub agreed_terms: Vec<TermsAgreement>,
+    // the organization the user is working in
+    #[serde(default)]
+    pub organization_id: String,
+    // sessions issued before this time are no longer valid, e.g. after a password reset
+    #[serde(default)]
+    pub sessions_valid_after: u64,
+    pub created_at: u64,
+    pub updated_at: u64,
+
+    // find a user by email
+    pub gsi1: String,
+    // find users by phone number
+    #[serde(default)]
+    pub gsi2: String,
+}
+
+impl User {
+    pub fn gsi1(email: &str) -> String {
+        format!("user#{}", email.trim().to_lowercase())
+    }
+
+    pub fn gsi2(phone: &str) -> String {
+        format!("user#{}", normalize_phone(phone))
+    }
+
+    pub fn profile(&self) -> UserProfile {
+        UserProfile {
+            id: self.id.clone(),
+            email: self.email.clone(),
+            name: self.name.clone(),
+            phone: self.phone.clone(),
+            address: self.address.clone(),
+            kind: self.kind.clone(),
+            organization_id: self.organization_id.clone(),
+            role: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum UserKind {
+    #[default]
+    Individual,
+    Corporation {
+        company: String,
+        // 사업자 등록번호, e.g. 000-00-00000
+        business_number: String,
+    },
+}
+
+// Terms lists the terms a user agrees to in the second step of signup.
+// All of them are essential.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Terms {
+    Membership,
+    PrivacyPolicy,
+    EntrustPersonalInformation,
+}
+
+impl Terms {
+    pub fn essentials() -> Vec<Terms> {
+        vec![
+            Terms::Membership,
+            Terms::PrivacyPolicy,
+            Terms::EntrustPersonalInformation,
+        ]
+    }
+}
+
+// TermsAgreement records when the user checked each of the terms.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct TermsAgreement {
+    pub terms: Terms,
+    pub agreed_at: u64,
+}
+
+impl TermsAgreement {
+    // signup must follow the agreement within a day
+    pub const MAX_AGE: u64 = 24 * 60 * 60;
+}
+
+// The password must consist of at least 8 characters
+// and a combination of letters, numbers and special symbols.
+pub fn is_valid_password(password: &str) -> bool {
+    password.chars().count() >= 8
+        && password.chars().any(|c| c.is_ascii_alphabetic())
+        && password.chars().any(|c| c.is_ascii_digit())
+        && password
+            .chars()
+            .any(|c| !c.is_ascii_alphanumeric() && !c.is_whitespace())
+}
+
+// normalize_phone keeps only the digits, e.g. 010-1234-5678 to 01012345678.
+pub fn normalize_phone(phone: &str) -> String {
+    phone.chars().filter(|c| c.is_ascii_digit()).collect()
+}
+
+// mask_email hides most of the local part, e.g. miner@biyard.co to mi***@biyard.co.
+pub fn mask_email(email: &str) -> String {
+    let (local, domain) = email.split_once('@').unwrap_or((email, ""));
+    let visible = if local.chars().count() > 2 { 2 } else { 1 };
+    let prefix: String = local.chars().take(visible).collect();
+
+    if domain.is_empty() {
+        format!("{prefix}***")
+    } else {
+        format!("{prefix}***@{domain}")
+    }
+}
+
+// A business registration number has 10 digits, e.g. 000-00-00000.
+pub fn is_valid_business_number(business_number: &str) -> bool {
+    let digits: Vec<char> = business_number.chars().filter(|c| *c != '-').collect();
+
+    digits.len() == 10 && digits.iter().all(|c| c.is_ascii_digit())
+}
+
+// UserProfile is the part of `User` which is safe to send to the client.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize, Default)]
+pub struct UserProfile {
+    pub id: String,
+    pub email: String,
+    pub name: String,
+    pub phone: String,
+    pub address: String,
+    pub kind: UserKind,
+    pub organization_id: String,
+    // the role in the organization, see `utils::account::profile`
+    #[serde(default)]
+    pub role: Option<Role>,
+}
+
+// PasswordResetToken is issued once the reset code is verified
+// and may be used only once to set a new password.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct PasswordResetToken {
+    pub id: String,
+    pub r#type: String,
+    pub user_id: String,
+    pub expired_at: u64,
+    pub created_at: u64,
+}
+
+impl PasswordResetToken {
+    pub const TTL: u64 = 10 * 60;
+
+    pub fn key(token: &str) -> String {
+        format!("password-reset#{}", token)
+    }
+}
+
+// LoginAttempt counts failed logins per email.
+// It is keyed by email rather than by user so that unknown emails are limited as well.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct LoginAttempt {
+    pub id: String,
+    pub r#type: String,
+    pub failures: u32,
+    pub locked_until: u64,
+    pub updated_at: u64,
+}
+
+impl LoginAttempt {
+    pub const MAX_FAILURES: u32 = 5;
+    pub const LOCK_SECONDS: u64 = 10 * 60;
+
+    pub fn key(email: &str) -> String {
+        format!("login-attempt#{}", email.trim().to_lowercase())
+    }
+
+    pub fn new(email: &str) -> Self {
+        LoginAttempt {
+            id: Self::key(email),
+            r#type: "login_attempt".to_string(),
+            ..Default::default()
+        }
+    }
+
+    pub fn is_locked(&self, now: u64) -> bool {
+        self.locked_until > now
+    }
+
+    // lock locks the email for LOCK_SECONDS on every MAX_FAILURES-th failure and returns whether it did.
+    // `failures` is only ever added to, so that each of concurrent failures is counted once.
+    pub fn lock(&mut self, now: u64) -> bool {
+        if self.failures == 0 || self.failures % Self::MAX_FAILURES != 0 {
+            return false;
+        }
+        self.locked_until = now + Self::LOCK_SECONDS;
+        self.updated_at = now;
+
+        true
+    }
+}
+
+// UserEmail reserves an email for one user.
+// It is written only if absent, so two signups of the same email cannot both succeed.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct UserEmail {
+    pub id: String,
+    pub r#type: String,
+    pub user_id: String,
+    pub created_at: u64,
+}
+
+impl UserEmail {
+    pub fn key(email: &str) -> String {
+        format!("user-email#{}", email.trim().to_lowercase())
+    }
+
+    pub fn new(email: &str, user_id: &str, now: u64) -> Self {
+        UserEmail {
+            id: Self::key(email),
+            r#type: "user_email".to_string(),
+            user_id: user_id.to_string(),
+            created_at: now,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn login_attempts_lock_after_max_failures() {
+        let mut attempt = LoginAttempt::new("Miner@Biyard.co ");
+        assert_eq!(attempt.id, "login-attempt#miner@biyard.co");
+
+        for failures in 1..LoginAttempt::MAX_FAILURES {
+            attempt.failures = failures;
+            assert!(!attempt.lock(100));
+            assert!(!attempt.is_locked(100));
+        }
+        attempt.failures = LoginAttempt::MAX_FAILURES;
+        assert!(attempt.lock(100));
+        assert!(attempt.is_locked(100));
+        assert!(attempt.is_locked(100 + LoginAttempt::LOCK_SECONDS - 1));
+        assert!(!attempt.is_locked(100 + LoginAttempt::LOCK_SECONDS));
+
+        attempt.failures += 1;
+        assert!(!attempt.lock(200 + LoginAttempt::LOCK_SECONDS));
+        attempt.failures = 2 * LoginAttempt::MAX_FAILURES;
+        assert!(attempt.lock(200 + LoginAttempt::LOCK_SECONDS));
+    }
+
+    #[test]
+    fn password_policy() {
+        assert!(is_valid_password("voice12!"));
+        assert!(!is_valid_password("voice1!"));
+        assert!(!is_valid_password("voicekorea!"));
+        assert!(!is_valid_password("voicekorea1"));
+    }
+}
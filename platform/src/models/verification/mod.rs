@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+
+// VerificationKind keeps the codes issued to a target for different uses apart,
+// so that e.g. a signup code can not reset the password of the same email.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VerificationKind {
+    // proves that the user owns an email or a phone number
+    Contact,
+    PasswordReset,
+}
+
+// Verification is a code sent to an email address (or a phone number)
+// to prove that the user owns it.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct Verification {
+    pub id: String,
+    pub r#type: String,
+    pub target: String,
+    // salted hash of the code
+    pub code: String,
+    pub attempts: u32,
+    pub expired_at: u64,
+    pub verified_at: Option<u64>,
+    pub created_at: u64,
+}
+
+impl Verification {
+    // a code must be entered within 3 minutes
+    pub const CODE_TTL: u64 = 3 * 60;
+    // a verified target may be used for 30 minutes, e.g. to complete signup
+    pub const VERIFIED_TTL: u64 = 30 * 60;
+    pub const MAX_ATTEMPTS: u32 = 5;
+    pub const RESEND_INTERVAL: u64 = 30;
+
+    pub fn key(kind: VerificationKind, target: &str) -> String {
+        let prefix = match kind {
+            VerificationKind::Contact => "verification",
+            VerificationKind::PasswordReset => "password-reset-code",
+        };
+
+        format!("{prefix}#{}", target.trim().to_lowercase())
+    }
+
+    pub fn is_verified(&self, now: u64) -> bool {
+        match self.verified_at {
+            Some(verified_at) => verified_at + Self::VERIFIED_TTL > now,
+            None => false,
+        }
+    }
+}
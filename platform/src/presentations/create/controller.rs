@@ -2,6 +2,20 @@
 use dioxus::prelude::*;
 use dioxus_logger::tracing;
 
+use crate::{
+    api::{
+        common::ApiError,
+        v1::users::{
+            signup::{signup_user, SignupUserRequest},
+            verify::{
+                get_verification_code, verify_email, GetVerificationCodeRequest,
+                VerifyEmailRequest,
+            },
+        },
+    },
+    models::user::{is_valid_password, Terms, TermsAgreement, UserKind},
+};
+
 #[derive(Debug, Clone, PartialEq, Copy)]
 pub struct Controller {
     authorize_type: Signal<u64>, //0: 개인, 1: 법인
@@ -15,6 +29,8 @@ pub struct Controller {
     click_first: Signal<bool>,
     click_second: Signal<bool>,
     click_third: Signal<bool>,
+    // the terms checked so far, with the time each was checked
+    agreed_terms: Signal<Vec<TermsAgreement>>,
 
     email_address: Signal<String>,
     authentication_number: Signal<String>,
@@ -24,6 +40,8 @@ pub struct Controller {
     cellphone_number: Signal<String>,
     simple_address: Signal<String>,
     detail_address: Signal<String>,
+    signup_error: Signal<Option<ApiError>>,
+    password_mismatch: Signal<bool>,
     // click_send_authentication: Signal<bool>,
     // click_search_address: Signal<bool>,
     // click_complete_join_membership: Signal<bool>,
@@ -43,6 +61,7 @@ impl Controller {
             click_first: use_signal(|| false),
             click_second: use_signal(|| false),
             click_third: use_signal(|| false),
+            agreed_terms: use_signal(|| vec![]),
 
             email_address: use_signal(|| "".to_string()),
             authentication_number: use_signal(|| "".to_string()),
@@ -52,6 +71,8 @@ impl Controller {
             cellphone_number: use_signal(|| "".to_string()),
             simple_address: use_signal(|| "".to_string()),
             detail_address: use_signal(|| "".to_string()),
+            signup_error: use_signal(|| None),
+            password_mismatch: use_signal(|| false),
             // click_send_authentication: use_signal(|| false),
             // click_search_address: use_signal(|| false),
             // click_complete_join_membership: use_signal(|| false),
@@ -134,6 +155,14 @@ impl Controller {
         (self.password_check)()
     }
 
+    pub fn get_signup_error(&self) -> Option<ApiError> {
+        (self.signup_error)()
+    }
+
+    pub fn get_password_mismatch(&self) -> bool {
+        (self.password_mismatch)()
+    }
+
     // pub fn get_click_send_authentication(&self) -> bool {
     //     (self.click_send_authentication)()
     // }
@@ -176,16 +205,19 @@ impl Controller {
 
     pub fn set_click_first_terms(&mut self, clicked: bool) {
         self.click_first.set(clicked);
+        self.record_agreement(Terms::Membership, clicked);
         self.check_and_update_terms_agreement();
     }
 
     pub fn set_click_second_terms(&mut self, clicked: bool) {
         self.click_second.set(clicked);
+        self.record_agreement(Terms::PrivacyPolicy, clicked);
         self.check_and_update_terms_agreement();
     }
 
     pub fn set_click_third_terms(&mut self, clicked: bool) {
         self.click_third.set(clicked);
+        self.record_agreement(Terms::EntrustPersonalInformation, clicked);
         self.check_and_update_terms_agreement();
     }
 
@@ -221,16 +253,100 @@ impl Controller {
         self.password_check.set(password_check);
     }
 
-    pub fn set_click_send_authentication(&mut self) {
+    pub async fn set_click_send_authentication(&mut self) {
         tracing::info!("send authentication button clicked");
+        let req = GetVerificationCodeRequest {
+            email: self.get_email_address(),
+        };
+
+        match get_verification_code(req).await {
+            Ok(_) => self.signup_error.set(None),
+            Err(e) => self.set_signup_error(e),
+        }
     }
 
     pub fn set_click_search_address(&mut self) {
         tracing::info!("search address button clicked");
     }
 
-    pub fn set_click_complete_join_membership(&mut self) {
+    pub async fn complete_join_membership(&mut self) -> bool {
         tracing::info!("complete join membership button clicked");
+        let password = self.get_password();
+        if password != self.get_password_check() {
+            self.password_mismatch.set(true);
+            return false;
+        }
+        self.password_mismatch.set(false);
+
+        if !is_valid_password(&password) {
+            self.signup_error.set(Some(ApiError::InvalidPassword));
+            return false;
+        }
+
+        let kind = if self.get_authorize_type() == 1 {
+            UserKind::Corporation {
+                company: self.get_company(),
+                business_number: self.get_business_number(),
+            }
+        } else {
+            UserKind::Individual
+        };
+        let agreed_terms = (self.agreed_terms)();
+
+        let req = SignupUserRequest {
+            email: self.get_email_address(),
+            password,
+            name: self.get_name(),
+            phone: self.get_cellphone_number(),
+            address: format!("{} {}", self.get_simple_address(), self.get_detail_address())
+                .trim()
+                .to_string(),
+            kind,
+            agreed_terms,
+        };
+
+        if let Err(e) = verify_email(VerifyEmailRequest {
+            email: self.get_email_address(),
+            verification_code: self.get_authentication_number(),
+        })
+        .await
+        {
+            self.set_signup_error(e);
+            return false;
+        }
+
+        match signup_user(req).await {
+            Ok(_) => {
+                self.signup_error.set(None);
+                true
+            }
+            Err(e) => {
+                self.set_signup_error(e);
+                false
+            }
+        }
+    }
+
+    fn set_signup_error(&mut self, e: ServerFnError<ApiError>) {
+        match e {
+            ServerFnError::WrappedServerError(e) => self.signup_error.set(Some(e)),
+            e => {
+                tracing::error!("signup failed: {:?}", e);
+                self.signup_error
+                    .set(Some(ApiError::InternalServerError(e.to_string())));
+            }
+        }
+    }
+
+    fn record_agreement(&mut self, terms: Terms, agreed: bool) {
+        let mut agreed_terms = self.agreed_terms.write();
+        agreed_terms.retain(|a| a.terms != terms);
+        if agreed {
+            agreed_terms.push(TermsAgreement {
+                terms,
+                agreed_at: chrono::Utc::now().timestamp() as u64,
+            });
+        }
     }
 
     fn check_and_update_terms_agreement(&mut self) {
@@ -44,6 +44,16 @@ pub struct CreateTranslate {
     pub complete_join_membership_info: String,
     pub complete: String,
     pub company_name_info: String,
+    pub duplicate_email: String,
+    pub invalid_password: String,
+    pub password_mismatch: String,
+    pub invalid_business_number: String,
+    pub terms_not_agreed: String,
+    pub invalid_verification_code: String,
+    pub verification_expired: String,
+    pub not_verified: String,
+    pub too_many_attempts: String,
+    pub signup_failed: String,
 }
 
 pub fn translate(lang: Language) -> CreateTranslate {
@@ -91,7 +101,17 @@ pub fn translate(lang: Language) -> CreateTranslate {
             complete_join_membership: "Membership registration completed".to_string(),
             complete_join_membership_info: "Congratulations on completing your membership registration.".to_string(),
             complete: "Complete".to_string(),
-            company_name_info: "Corporation Name".to_string()
+            company_name_info: "Corporation Name".to_string(),
+            duplicate_email: "This email address is already registered.".to_string(),
+            invalid_password: "The password must consist of at least 8 characters and a combination of letters, numbers, and special symbols.".to_string(),
+            password_mismatch: "The passwords do not match.".to_string(),
+            invalid_business_number: "Please check the business registration number.".to_string(),
+            terms_not_agreed: "Please agree to all essential terms.".to_string(),
+            invalid_verification_code: "The authentication number is incorrect.".to_string(),
+            verification_expired: "The authentication number has expired. Please resend it.".to_string(),
+            not_verified: "Please verify your email address first.".to_string(),
+            too_many_attempts: "Please try again in a moment.".to_string(),
+            signup_failed: "Failed to join the membership. Please try again.".to_string(),
         },
         Language::Ko => CreateTranslate {
             authorization: "본인인증".to_string(),
@@ -136,7 +156,17 @@ pub fn translate(lang: Language) -> CreateTranslate {
             complete_join_membership: "회원가입 완료".to_string(),
             complete_join_membership_info: "회원가입 완료를 축하합니다.".to_string(),
             complete: "완료".to_string(),
-            company_name_info: "법인명".to_string()
+            company_name_info: "법인명".to_string(),
+            duplicate_email: "이미 가입된 이메일 주소입니다.".to_string(),
+            invalid_password: "비밀번호는 영문, 숫자, 특수기호 조합으로 8자 이상 구성되어야 합니다.".to_string(),
+            password_mismatch: "비밀번호가 일치하지 않습니다.".to_string(),
+            invalid_business_number: "사업자 등록번호를 확인해 주세요.".to_string(),
+            terms_not_agreed: "필수 약관에 모두 동의해 주세요.".to_string(),
+            invalid_verification_code: "인증번호가 올바르지 않습니다.".to_string(),
+            verification_expired: "인증시간이 초과되었습니다. 인증번호를 재발송해 주세요.".to_string(),
+            not_verified: "이메일 인증을 먼저 진행해 주세요.".to_string(),
+            too_many_attempts: "잠시 후 다시 시도해 주세요.".to_string(),
+            signup_failed: "회원가입에 실패했습니다. 다시 시도해 주세요.".to_string(),
         },
     }
 }
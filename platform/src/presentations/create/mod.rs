@@ -1,5 +1,5 @@
 #![allow(non_snake_case)]
-use crate::prelude::*;
+use crate::{api::common::ApiError, prelude::*};
 use dioxus::prelude::*;
 use step_four::StepFourPage;
 use step_one::StepOnePage;
@@ -140,6 +140,21 @@ pub fn CreatePage(props: CreatePageProps) -> Element {
                     check_title: translates.check_title,
                     check_membership_descriptions: vec![translates.check_membership_description_1, translates.check_membership_description_2, translates.check_membership_description_3],
                     complete_join_membership: translates.complete_join_membership,
+                    error_message: if ctrl.get_password_mismatch() {
+                        Some(translates.password_mismatch)
+                    } else {
+                        ctrl.get_signup_error().map(|e| match e {
+                            ApiError::DuplicateEmail => translates.duplicate_email,
+                            ApiError::InvalidPassword => translates.invalid_password,
+                            ApiError::InvalidBusinessNumber => translates.invalid_business_number,
+                            ApiError::TermsNotAgreed => translates.terms_not_agreed,
+                            ApiError::InvalidVerificationCode => translates.invalid_verification_code,
+                            ApiError::VerificationExpired => translates.verification_expired,
+                            ApiError::NotVerified => translates.not_verified,
+                            ApiError::TooManyAttempts(_) => translates.too_many_attempts,
+                            _ => translates.signup_failed,
+                        })
+                    },
                 }
             } else {
                 StepFourPage {
@@ -29,6 +29,7 @@ pub struct StepThreeProps {
     check_title: String,
     check_membership_descriptions: Vec<String>,
     complete_join_membership: String,
+    error_message: Option<String>,
 }
 
 #[component]
@@ -61,8 +62,8 @@ pub fn StepThreePage(props: StepThreeProps) -> Element {
                             ButtonComponent {
                                 label: props.send_authentication,
                                 lang: props.lang,
-                                onclick: move |_| {
-                                    ctrl.set_click_send_authentication();
+                                onclick: move |_| async move {
+                                    ctrl.set_click_send_authentication().await;
                                 }
                             }
                         }
@@ -104,6 +105,7 @@ pub fn StepThreePage(props: StepThreeProps) -> Element {
                             div {
                                 class: "mx-[10px]",
                                 Input {
+                                    input_type: "password".to_string(),
                                     value: ctrl.get_password(),
                                     onchange: move |e| {
                                         ctrl.set_password(e);
@@ -122,6 +124,7 @@ pub fn StepThreePage(props: StepThreeProps) -> Element {
                             div {
                                 class: "mx-[10px]",
                                 Input {
+                                    input_type: "password".to_string(),
                                     value: ctrl.get_password_check(),
                                     onchange: move |e| {
                                         ctrl.set_password_check(e);
@@ -239,12 +242,19 @@ pub fn StepThreePage(props: StepThreeProps) -> Element {
                     "{props.check_membership_descriptions[2]}"
                 }
             }
+            if let Some(error_message) = props.error_message {
+                div {
+                    class: "flex flex-row w-full justify-end min-w-[710px] items-end pt-[20px] text-[16px] font-normal text-[#ff0000]",
+                    "{error_message}"
+                }
+            }
             div {
                 class: "flex flex-row w-full justify-end min-w-[710px] items-end pt-[30px]",
                 div {
-                    onclick: move |_| {
-                        ctrl.set_click_complete_join_membership();
-                        ctrl.set_step(3);
+                    onclick: move |_| async move {
+                        if ctrl.complete_join_membership().await {
+                            ctrl.set_step(3);
+                        }
                     },
                     class: "flex flex-row w-auto h-[60px] justify-end items-end bg-[#2168c3] px-[20px]",
                     div {
@@ -5,10 +5,26 @@ use chrono::{self, DateTime, Local};
 use dioxus::prelude::*;
 use dioxus_logger::tracing;
 
-use crate::api::v1::surveys::list_surveys;
+use crate::{
+    api::v1::surveys::{
+        edit::{
+            archive_survey, duplicate_survey, restore_survey, ArchiveSurveyRequest,
+            DuplicateSurveyRequest,
+        },
+        lifecycle::{transition_survey, TransitionSurveyRequest},
+        list_surveys, Status,
+    },
+    models::survey::SurveyAction,
+};
+
+pub const PAGE_SIZE: i32 = 12;
+pub const SCROLL_CONTAINER_ID: &str = "dashboard-surveys";
+// an empty element after the list, observed to load the next page when it comes near
+pub const SCROLL_END_ID: &str = "dashboard-surveys-end";
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Survey {
+    pub id: String,
     pub survey_type: String,
     pub title: String,
     pub update_date: String,
@@ -20,6 +36,12 @@ pub struct Survey {
 pub struct Controller {
     pub surveys: Signal<Vec<Survey>>,
     pub clicked_type: Signal<u64>, //0: type-1, 1: type-2
+    status: Signal<Option<Status>>,
+    bookmark: Signal<Option<String>>,
+    has_more: Signal<bool>,
+    loading: Signal<bool>,
+    // bumped when the list restarts, so that pages requested before are dropped
+    generation: Signal<u64>,
 }
 
 impl Controller {
@@ -27,36 +49,106 @@ impl Controller {
         let mut ctrl = Self {
             surveys: use_signal(|| vec![]),
             clicked_type: use_signal(|| 0),
+            status: use_signal(|| None),
+            bookmark: use_signal(|| None),
+            has_more: use_signal(|| true),
+            loading: use_signal(|| false),
+            generation: use_signal(|| 0),
         };
 
+        use_context_provider(|| ctrl);
+
         let _ = use_effect(move || {
             spawn(async move {
-                match list_surveys(None, None, None).await {
-                    Ok(res) => {
-                        let surveys = res.items;
-
-                        let total_surveys: Vec<Survey> = surveys
-                            .into_iter()
-                            .map(|survey| Survey {
-                                survey_type: survey.status.to_string(),
-                                title: survey.title,
-                                update_date: Self::format_date(survey.updated_at),
-                                response_count: survey.responses.unwrap_or_default(),
-                                total_response_count: survey.expected_responses.unwrap_or_default(),
-                            })
-                            .collect();
-                        ctrl.surveys.set(total_surveys);
-                    }
-                    Err(e) => {
-                        tracing::error!("Error: {:?}", e);
-                    }
-                }
+                ctrl.load_more().await;
+            });
+            spawn(async move {
+                ctrl.load_on_scroll().await;
             });
         });
 
         ctrl
     }
 
+    // load_more fetches the next pages of surveys, if any, until the list reaches below the view.
+    pub async fn load_more(&mut self) {
+        while self.load_page().await && self.end_is_near().await {}
+    }
+
+    // load_page appends the next page of surveys and returns whether it did.
+    async fn load_page(&mut self) -> bool {
+        if *self.loading.peek() || !*self.has_more.peek() {
+            return false;
+        }
+        self.loading.set(true);
+
+        let generation = *self.generation.peek();
+        let status = *self.status.peek();
+        let bookmark = self.bookmark.peek().clone();
+        let res = list_surveys(Some(PAGE_SIZE), bookmark, status).await;
+        if *self.generation.peek() != generation {
+            return false;
+        }
+        self.loading.set(false);
+
+        match res {
+            Ok(res) => {
+                let surveys = res.items.into_iter().map(|survey| Survey {
+                    id: survey.id,
+                    survey_type: match survey.archived_at {
+                        Some(_) => Status::Archived.name().to_string(),
+                        None => survey.status.name().to_string(),
+                    },
+                    title: survey.title,
+                    update_date: Self::format_date(survey.updated_at),
+                    response_count: survey.responses.unwrap_or_default(),
+                    total_response_count: survey.expected_responses.unwrap_or_default(),
+                });
+                self.surveys.write().extend(surveys);
+                self.has_more.set(res.bookmark.is_some());
+                self.bookmark.set(res.bookmark);
+
+                true
+            }
+            Err(e) => {
+                tracing::error!("Error: {:?}", e);
+                self.has_more.set(false);
+
+                false
+            }
+        }
+    }
+
+    // load_on_scroll loads the next pages whenever the end of the list scrolls near the view.
+    async fn load_on_scroll(&mut self) {
+        let script = format!(
+            r#"const root = document.getElementById("{SCROLL_CONTAINER_ID}");
+            const end = document.getElementById("{SCROLL_END_ID}");
+            if (root !== null && end !== null) {{
+                new IntersectionObserver((entries) => {{
+                    if (entries.some((e) => e.isIntersecting)) dioxus.send(true);
+                }}, {{ root, rootMargin: "0px 0px 200px 0px" }}).observe(end);
+            }}"#
+        );
+        let mut observer = document::eval(&script);
+        while let Ok(true) = observer.recv::<bool>().await {
+            self.load_more().await;
+        }
+    }
+
+    // end_is_near tells whether the end of the list is within 200px below the view,
+    // which the observer does not report again when it stays there.
+    async fn end_is_near(&self) -> bool {
+        let script = format!(
+            r#"const root = document.getElementById("{SCROLL_CONTAINER_ID}");
+            const end = document.getElementById("{SCROLL_END_ID}");
+            return root !== null && end !== null
+                && end.getBoundingClientRect().top <= root.getBoundingClientRect().bottom + 200;"#
+        );
+
+        matches!(document::eval(&script).join::<bool>().await, Ok(true))
+    }
+
     pub fn format_date(timestamp: u64) -> String {
         let d = UNIX_EPOCH + Duration::from_secs(timestamp);
         let datetime = DateTime::<Local>::from(d);
@@ -74,4 +166,73 @@ impl Controller {
     pub fn get_total_surveys(&mut self) -> Vec<Survey> {
         (self.surveys)()
     }
+
+    pub fn get_status(&self) -> Option<Status> {
+        (self.status)()
+    }
+
+    pub fn get_has_more(&self) -> bool {
+        (self.has_more)()
+    }
+
+    // set_status restarts the list with the new status filter.
+    pub async fn set_status(&mut self, status: Option<Status>) {
+        if *self.status.peek() == status {
+            return;
+        }
+
+        self.status.set(status);
+        self.reload().await;
+    }
+
+    pub async fn reload(&mut self) {
+        *self.generation.write() += 1;
+        self.loading.set(false);
+        self.surveys.set(vec![]);
+        self.bookmark.set(None);
+        self.has_more.set(true);
+        self.load_more().await;
+    }
+
+    pub async fn duplicate(&mut self, survey_id: String) {
+        match duplicate_survey(DuplicateSurveyRequest { survey_id }).await {
+            Ok(_) => self.reload().await,
+            Err(e) => tracing::error!("failed to duplicate survey: {:?}", e),
+        }
+    }
+
+    pub async fn transition(&mut self, survey_id: String, action: SurveyAction) {
+        let req = TransitionSurveyRequest {
+            survey_id,
+            action,
+            ended_at: None,
+        };
+
+        match transition_survey(req).await {
+            Ok(_) => self.reload().await,
+            Err(e) => tracing::error!("failed to {:?} survey: {:?}", action, e),
+        }
+    }
+
+    pub async fn archive(&mut self, survey_id: String) {
+        match archive_survey(ArchiveSurveyRequest {
+            survey_id: survey_id.clone(),
+        })
+        .await
+        {
+            Ok(_) => self.surveys.write().retain(|s| s.id != survey_id),
+            Err(e) => tracing::error!("failed to archive survey: {:?}", e),
+        }
+    }
+
+    pub async fn restore(&mut self, survey_id: String) {
+        match restore_survey(ArchiveSurveyRequest {
+            survey_id: survey_id.clone(),
+        })
+        .await
+        {
+            Ok(_) => self.surveys.write().retain(|s| s.id != survey_id),
+            Err(e) => tracing::error!("failed to restore survey: {:?}", e),
+        }
+    }
 }
@@ -1,10 +1,15 @@
 #![allow(non_snake_case)]
-use crate::presentations::dashboard::StatusButton;
+use crate::{
+    prelude::*,
+    presentations::dashboard::{StatusButton, SurveyActionLabels, SurveyActions},
+};
 use dioxus::prelude::*;
 use dioxus_logger::tracing;
 
 #[component]
 pub fn DashboardCard(
+    lang: Language,
+    survey_id: String,
     survey_type: String,
     title: String,
     update_date: String,
@@ -13,11 +18,14 @@ pub fn DashboardCard(
     draft_label: String,
     in_progress_label: String,
     complete_label: String,
+    paused_label: String,
+    archived_label: String,
     update_date_label: String,
     add_question_description: String,
     response: String,
     edit_survey: String,
     analysis_result: String,
+    actions: SurveyActionLabels,
 ) -> Element {
     tracing::info!("status: {:?}", survey_type.clone());
     rsx! {
@@ -28,16 +36,24 @@ pub fn DashboardCard(
                     survey_type: survey_type.clone(),
                     draft_label,
                     in_progress_label,
-                    complete_label
+                    complete_label,
+                    paused_label,
+                    archived_label
                 }
                 div {
                     class: "w-full text-[#4c4c4c] font-semibold text-[30px] mb-[14px] overflow-hidden truncate",
                     {title}
                 }
                 div {
-                    class: "text-[20px] font-normal text-[#4c4c4c]",
+                    class: "text-[20px] font-normal text-[#4c4c4c] mb-[10px]",
                     "{update_date_label} {update_date}"
                 }
+                SurveyActions {
+                    lang,
+                    survey_id: survey_id.clone(),
+                    survey_type: survey_type.clone(),
+                    labels: actions,
+                }
             }
             div {
                 class: "flex flex-col w-full",
@@ -61,16 +77,18 @@ pub fn DashboardCard(
                     }
                 }
                 if survey_type == "draft" {
-                    div {
+                    Link {
                         class: "flex flex-row w-full h-[55px] rounded-[8px] border-solid border-[3px] border-[#1e5eaf] bg-white items-center justify-center",
+                        to: Route::WriteQuestionPage { lang, survey_id },
                         div {
                             class: "text-[20px] font-medium text-[#1e5eaf]",
                             "{edit_survey}"
                         }
                     }
                 } else {
-                    div {
+                    Link {
                         class: "flex flex-row w-full h-[55px] rounded-[8px] border-solid border-[3px] border-[#1e5eaf] bg-white items-center justify-center",
+                        to: Route::ResultPage { lang, survey_id },
                         div {
                             class: "text-[20px] font-medium text-[#1e5eaf]",
                             "{analysis_result}"
@@ -1,9 +1,14 @@
 #![allow(non_snake_case)]
-use crate::presentations::dashboard::StatusButton;
+use crate::{
+    prelude::*,
+    presentations::dashboard::{StatusButton, SurveyActionLabels, SurveyActions},
+};
 use dioxus::prelude::*;
 
 #[component]
 pub fn DashboardRow(
+    lang: Language,
+    survey_id: String,
     survey_type: String,
     title: String,
     update_date: String,
@@ -12,9 +17,12 @@ pub fn DashboardRow(
     draft_label: String,
     in_progress_label: String,
     complete_label: String,
+    paused_label: String,
+    archived_label: String,
     add_question_description: String,
     edit_survey: String,
     analysis_result: String,
+    actions: SurveyActionLabels,
 ) -> Element {
     rsx! {
         div {
@@ -22,8 +30,17 @@ pub fn DashboardRow(
             div {
                 class: "flex flex-row min-w-[500px]",
                 div {
-                    class: "text-[#4c4c4c] font-semibold text-[30px] mr-[50px]",
-                    {title}
+                    class: "flex flex-col mr-[50px]",
+                    div {
+                        class: "text-[#4c4c4c] font-semibold text-[30px]",
+                        {title}
+                    }
+                    SurveyActions {
+                        lang,
+                        survey_id: survey_id.clone(),
+                        survey_type: survey_type.clone(),
+                        labels: actions,
+                    }
                 }
                 if survey_type == "draft" {
                     div {
@@ -64,22 +81,26 @@ pub fn DashboardRow(
                             draft_label,
                             in_progress_label,
                             complete_label,
+                            paused_label,
+                            archived_label,
                         }
                     }
                 }
                 div {
                     class: "flex flex-row w-[265px] items-center justify-center",
                     if survey_type == "draft" {
-                        div {
+                        Link {
                             class: "flex flex-row w-[200px] h-[55px] rounded-[8px] border-solid border border-[#b0b0b0] bg-white items-center justify-center",
+                            to: Route::WriteQuestionPage { lang, survey_id },
                             div {
                                 class: "text-[20px] font-medium text-[#1e5eaf]",
                                 "{edit_survey}"
                             }
                         }
                     } else {
-                        div {
+                        Link {
                             class: "flex flex-row w-[200px] h-[55px] rounded-[8px] border-solid border border-[#b0b0b0] bg-white items-center justify-center",
+                            to: Route::ResultPage { lang, survey_id },
                             div {
                                 class: "text-[20px] font-medium text-[#1e5eaf]",
                                 "{analysis_result}"
@@ -7,14 +7,27 @@ pub struct DashboardTranslate {
     pub response_count: String,
     pub final_update_date: String,
     pub status: String,
+    pub all: String,
     pub draft: String,
     pub in_progress: String,
     pub complete: String,
+    pub paused: String,
+    pub archived: String,
     pub update_date: String,
     pub add_question_description: String,
     pub response: String,
     pub edit_survey: String,
     pub analysis_result: String,
+    pub load_more: String,
+    pub launch: String,
+    pub pause: String,
+    pub resume: String,
+    pub close: String,
+    pub reopen: String,
+    pub rename: String,
+    pub duplicate: String,
+    pub archive: String,
+    pub restore: String,
 }
 
 pub fn translate(lang: Language) -> DashboardTranslate {
@@ -26,14 +39,27 @@ pub fn translate(lang: Language) -> DashboardTranslate {
             response_count: "Response Count".to_string(),
             final_update_date: "Final Update Date".to_string(),
             status: "Status".to_string(),
+            all: "All".to_string(),
             draft: "Draft".to_string(),
             in_progress: "In Progress".to_string(),
             complete: "Complete".to_string(),
+            paused: "Paused".to_string(),
+            archived: "Archived".to_string(),
             update_date: "Update Date".to_string(),
             add_question_description: "Add a Question".to_string(),
             response: " response".to_string(),
             edit_survey: "Edit Survey".to_string(),
             analysis_result: "Analysis Result".to_string(),
+            load_more: "Load More".to_string(),
+            launch: "Launch".to_string(),
+            pause: "Pause".to_string(),
+            resume: "Resume".to_string(),
+            close: "Close".to_string(),
+            reopen: "Reopen".to_string(),
+            rename: "Rename".to_string(),
+            duplicate: "Duplicate".to_string(),
+            archive: "Archive".to_string(),
+            restore: "Restore".to_string(),
         },
         Language::Ko => DashboardTranslate {
             search: "Search".to_string(),
@@ -42,14 +68,27 @@ pub fn translate(lang: Language) -> DashboardTranslate {
             response_count: "응답 수".to_string(),
             final_update_date: "최종 수정 날짜".to_string(),
             status: "상태".to_string(),
+            all: "전체".to_string(),
             draft: "초안".to_string(),
             in_progress: "진행중".to_string(),
             complete: "완료".to_string(),
+            paused: "일시정지".to_string(),
+            archived: "보관됨".to_string(),
             update_date: "수정 날짜".to_string(),
             add_question_description: "질문을 추가하세요".to_string(),
             response: "개 응답".to_string(),
             edit_survey: "설문 편집하기".to_string(),
             analysis_result: "결과 분석".to_string(),
+            load_more: "더 보기".to_string(),
+            launch: "시작".to_string(),
+            pause: "일시정지".to_string(),
+            resume: "재개".to_string(),
+            close: "종료".to_string(),
+            reopen: "다시 열기".to_string(),
+            rename: "이름 변경".to_string(),
+            duplicate: "복제".to_string(),
+            archive: "보관".to_string(),
+            restore: "복원".to_string(),
         },
     }
 }
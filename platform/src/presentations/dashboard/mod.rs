@@ -1,5 +1,9 @@
 #![allow(non_snake_case)]
-use crate::prelude::*;
+use crate::{
+    api::v1::surveys::Status,
+    models::{role::Permission, survey::SurveyAction},
+    prelude::*,
+};
 use controller::Survey;
 use dashboard_card::DashboardCard;
 use dashboard_row::DashboardRow;
@@ -15,9 +19,26 @@ pub struct DashboardPageProps {
     lang: Language,
 }
 
+#[derive(PartialEq, Props, Clone)]
+pub struct SurveyActionLabels {
+    launch: String,
+    pause: String,
+    resume: String,
+    close: String,
+    reopen: String,
+    rename: String,
+    duplicate: String,
+    archive: String,
+    restore: String,
+}
+
 #[derive(PartialEq, Props, Clone)]
 pub struct DashboardCardTypeProps {
+    lang: Language,
     surveys: Vec<Survey>,
+    paused: String,
+    archived: String,
+    actions: SurveyActionLabels,
     draft: String,
     in_progress: String,
     complete: String,
@@ -30,7 +51,11 @@ pub struct DashboardCardTypeProps {
 
 #[derive(PartialEq, Props, Clone)]
 pub struct DashboardListTypeProps {
+    lang: Language,
     surveys: Vec<Survey>,
+    paused: String,
+    archived: String,
+    actions: SurveyActionLabels,
     survey_name: String,
     response_count: String,
     final_update_date: String,
@@ -46,11 +71,33 @@ pub struct DashboardListTypeProps {
 #[component]
 pub fn DashboardPage(props: DashboardPageProps) -> Element {
     let mut ctrl = controller::Controller::init();
+    let auth = use_auth_context();
     let translates = i18n::translate(props.lang.clone());
+    let filters = vec![
+        (None, translates.all.clone()),
+        (Some(Status::Draft), translates.draft.clone()),
+        (Some(Status::InProgress), translates.in_progress.clone()),
+        (Some(Status::Paused), translates.paused.clone()),
+        (Some(Status::Finished), translates.complete.clone()),
+        (Some(Status::Archived), translates.archived.clone()),
+    ];
+    let actions = SurveyActionLabels {
+        launch: translates.launch.clone(),
+        pause: translates.pause.clone(),
+        resume: translates.resume.clone(),
+        close: translates.close.clone(),
+        reopen: translates.reopen.clone(),
+        rename: translates.rename.clone(),
+        duplicate: translates.duplicate.clone(),
+        archive: translates.archive.clone(),
+        restore: translates.restore.clone(),
+    };
 
     rsx! {
         div {
-            class: "flex flex-col w-full h-full pt-[45px] pr-[45px] pl-[35px] items-start justify-start",
+            id: controller::SCROLL_CONTAINER_ID,
+            class: "flex flex-col w-full pt-[45px] pr-[45px] pl-[35px] items-start justify-start overflow-y-auto",
+            style: "height: calc(100vh - 70px)",
             div {
                 class: "flex flex-row w-full items-start justify-between",
                 div {
@@ -90,22 +137,44 @@ pub fn DashboardPage(props: DashboardPageProps) -> Element {
                         }
                     }
                 }
-                Link {
-                    to: Route::WriteTitlePage {
-                        lang: props.lang.clone(),
-                    },
-                    div {
-                        class: "flex flex-row w-[200px] h-[50px] justify-end items-end bg-[#2168c3] rounded-[8px]",
+                if auth().can(Permission::Edit) {
+                    Link {
+                        to: Route::WriteTitlePage {
+                            lang: props.lang.clone(),
+                        },
                         div {
-                            class: "flex flex-row w-full h-full justify-center items-center text-[21px] font-semibold text-white",
-                            "{translates.create_survey}"
+                            class: "flex flex-row w-[200px] h-[50px] justify-end items-end bg-[#2168c3] rounded-[8px]",
+                            div {
+                                class: "flex flex-row w-full h-full justify-center items-center text-[21px] font-semibold text-white",
+                                "{translates.create_survey}"
+                            }
                         }
                     }
                 }
             }
+            div {
+                class: "flex flex-row w-full justify-start items-center pt-[25px]",
+                for (status, label) in filters {
+                    div {
+                        class: if ctrl.get_status() == status {
+                            "flex flex-row px-[20px] h-[40px] rounded-[20px] bg-[#2168c3] text-white text-[18px] font-medium justify-center items-center mr-[10px] cursor-pointer"
+                        } else {
+                            "flex flex-row px-[20px] h-[40px] rounded-[20px] bg-white text-[#4c4c4c] text-[18px] font-medium justify-center items-center mr-[10px] cursor-pointer"
+                        },
+                        onclick: move |_| async move {
+                            ctrl.set_status(status).await;
+                        },
+                        "{label}"
+                    }
+                }
+            }
             if ctrl.get_clicked_type() == 0 {
                 DashboardCardTypes {
+                    lang: props.lang,
                     surveys: ctrl.get_total_surveys(),
+                    paused: translates.paused.clone(),
+                    archived: translates.archived.clone(),
+                    actions: actions.clone(),
                     draft: translates.draft,
                     in_progress: translates.in_progress,
                     complete: translates.complete,
@@ -117,7 +186,11 @@ pub fn DashboardPage(props: DashboardPageProps) -> Element {
                 }
             } else {
                 DashboardListTypes {
+                    lang: props.lang,
                     surveys: ctrl.get_total_surveys(),
+                    paused: translates.paused.clone(),
+                    archived: translates.archived.clone(),
+                    actions: actions.clone(),
                     survey_name: translates.survey_name,
                     response_count: translates.response_count,
                     final_update_date: translates.final_update_date,
@@ -130,6 +203,19 @@ pub fn DashboardPage(props: DashboardPageProps) -> Element {
                     analysis_result: translates.analysis_result,
                 }
             }
+            if ctrl.get_has_more() {
+                div {
+                    class: "flex flex-row w-full justify-center items-center py-[30px]",
+                    div {
+                        class: "flex flex-row w-[200px] h-[50px] rounded-[8px] bg-white border-solid border border-[#e0e0e0] justify-center items-center text-[20px] font-medium text-[#4c4c4c] cursor-pointer",
+                        onclick: move |_| async move {
+                            ctrl.load_more().await;
+                        },
+                        "{translates.load_more}"
+                    }
+                }
+            }
+            div { id: controller::SCROLL_END_ID }
         }
     }
 }
@@ -140,6 +226,8 @@ pub fn StatusButton(
     draft_label: String,
     in_progress_label: String,
     complete_label: String,
+    paused_label: String,
+    archived_label: String,
 ) -> Element {
     let survey_type_label: String;
     let label_bg_color: String;
@@ -148,6 +236,14 @@ pub fn StatusButton(
         survey_type_label = draft_label;
         label_bg_color = "bg-[#e5e5e5]".to_string();
         label_text_color = "text-black".to_string();
+    } else if survey_type == "paused" {
+        survey_type_label = paused_label;
+        label_bg_color = "bg-[#ffb547]".to_string();
+        label_text_color = "text-white".to_string();
+    } else if survey_type == "archived" {
+        survey_type_label = archived_label;
+        label_bg_color = "bg-[#b0b0b0]".to_string();
+        label_text_color = "text-white".to_string();
     } else if survey_type == "finished" {
         survey_type_label = complete_label;
         label_bg_color = "bg-[#2168c3]".to_string();
@@ -173,6 +269,8 @@ pub fn DashboardCardTypes(props: DashboardCardTypeProps) -> Element {
             class: "flex flex-wrap w-full h-full justify-center items-start pt-[35px]",
             for survey in surveys.iter() {
                 DashboardCard {
+                    lang: props.lang,
+                    survey_id: survey.id.clone(),
                     survey_type: survey.survey_type.clone(),
                     title: survey.title.clone(),
                     update_date: survey.update_date.clone(),
@@ -181,11 +279,14 @@ pub fn DashboardCardTypes(props: DashboardCardTypeProps) -> Element {
                     draft_label: props.draft.clone(),
                     in_progress_label: props.in_progress.clone(),
                     complete_label: props.complete.clone(),
+                    paused_label: props.paused.clone(),
+                    archived_label: props.archived.clone(),
                     update_date_label: props.update_date.clone(),
                     add_question_description: props.add_question_description.clone(),
                     response: props.response.clone(),
                     edit_survey: props.edit_survey.clone(),
                     analysis_result: props.analysis_result.clone(),
+                    actions: props.actions.clone(),
                 }
             }
         }
@@ -226,6 +327,8 @@ pub fn DashboardListTypes(props: DashboardListTypeProps) -> Element {
                 class: "flex flex-col w-full h-full justify-start items-start",
                 for survey in surveys.iter() {
                     DashboardRow {
+                        lang: props.lang,
+                        survey_id: survey.id.clone(),
                         survey_type: survey.survey_type.clone(),
                         title: survey.title.clone(),
                         update_date: survey.update_date.clone(),
@@ -234,12 +337,95 @@ pub fn DashboardListTypes(props: DashboardListTypeProps) -> Element {
                         draft_label: props.draft.clone(),
                         in_progress_label: props.in_progress.clone(),
                         complete_label: props.complete.clone(),
+                        paused_label: props.paused.clone(),
+                        archived_label: props.archived.clone(),
                         add_question_description: props.add_question_description.clone(),
                         edit_survey: props.edit_survey.clone(),
                         analysis_result: props.analysis_result.clone(),
+                        actions: props.actions.clone(),
                     }
                 }
             }
         }
     }
 }
+
+#[component]
+pub fn SurveyActions(
+    lang: Language,
+    survey_id: String,
+    survey_type: String,
+    labels: SurveyActionLabels,
+) -> Element {
+    let mut ctrl: controller::Controller = use_context();
+    let auth = use_auth_context();
+    let can_edit = auth().can(Permission::Edit);
+    let archived = survey_type == "archived";
+    let duplicate_id = survey_id.clone();
+    let archive_id = survey_id.clone();
+    let transitions = match survey_type.as_str() {
+        _ if !auth().can(Permission::Launch) => vec![],
+        "draft" => vec![(SurveyAction::Launch, labels.launch.clone())],
+        "in_progress" => vec![
+            (SurveyAction::Pause, labels.pause.clone()),
+            (SurveyAction::Close, labels.close.clone()),
+        ],
+        "paused" => vec![
+            (SurveyAction::Resume, labels.resume.clone()),
+            (SurveyAction::Close, labels.close.clone()),
+        ],
+        "finished" => vec![(SurveyAction::Reopen, labels.reopen.clone())],
+        _ => vec![],
+    };
+
+    rsx! {
+        div {
+            class: "flex flex-row justify-start items-center text-[16px] font-normal text-[#696969]",
+            for (action, label) in transitions {
+                div {
+                    class: "mr-[15px] cursor-pointer text-[#2168c3]",
+                    onclick: {
+                        let survey_id = survey_id.clone();
+                        move |_| {
+                            let survey_id = survey_id.clone();
+                            async move { ctrl.transition(survey_id, action).await; }
+                        }
+                    },
+                    "{label}"
+                }
+            }
+            if can_edit && !archived {
+                Link {
+                    class: "mr-[15px]",
+                    to: Route::EditTitlePage { lang, survey_id },
+                    "{labels.rename}"
+                }
+                div {
+                    class: "mr-[15px] cursor-pointer",
+                    onclick: move |_| {
+                        let survey_id = duplicate_id.clone();
+                        async move { ctrl.duplicate(survey_id).await; }
+                    },
+                    "{labels.duplicate}"
+                }
+                div {
+                    class: "cursor-pointer",
+                    onclick: move |_| {
+                        let survey_id = archive_id.clone();
+                        async move { ctrl.archive(survey_id).await; }
+                    },
+                    "{labels.archive}"
+                }
+            } else if can_edit {
+                div {
+                    class: "cursor-pointer",
+                    onclick: move |_| {
+                        let survey_id = archive_id.clone();
+                        async move { ctrl.restore(survey_id).await; }
+                    },
+                    "{labels.restore}"
+                }
+            }
+        }
+    }
+}
@@ -2,6 +2,14 @@
 use dioxus::prelude::*;
 use dioxus_logger::tracing;
 
+use crate::api::{
+    common::ApiError,
+    v1::users::find::{
+        find_email, send_phone_verification_code, FindEmailRequest,
+        SendPhoneVerificationCodeRequest,
+    },
+};
+
 #[derive(Debug, Clone, PartialEq, Copy)]
 pub struct Controller {
     name: Signal<String>,
@@ -9,6 +17,7 @@ pub struct Controller {
     authentication_number: Signal<String>,
 
     email_address: Signal<String>,
+    error: Signal<Option<ApiError>>,
 }
 
 impl Controller {
@@ -18,6 +27,7 @@ impl Controller {
             phone_number: use_signal(|| "".to_string()),
             authentication_number: use_signal(|| "".to_string()),
             email_address: use_signal(|| "".to_string()),
+            error: use_signal(|| None),
         };
 
         use_context_provider(|| ctrl);
@@ -41,6 +51,10 @@ impl Controller {
         (self.email_address)()
     }
 
+    pub fn get_error(&self) -> Option<ApiError> {
+        (self.error)()
+    }
+
     pub fn set_authentication_number(&mut self, authentication_number: String) {
         self.authentication_number.set(authentication_number);
     }
@@ -53,14 +67,42 @@ impl Controller {
         self.phone_number.set(phone_number);
     }
 
-    pub fn set_click_send_authentication(&mut self) {
+    pub async fn set_click_send_authentication(&mut self) {
         tracing::info!("send authentication button clicked");
+        let req = SendPhoneVerificationCodeRequest {
+            phone: self.get_phone_number(),
+        };
+
+        match send_phone_verification_code(req).await {
+            Ok(_) => self.error.set(None),
+            Err(e) => self.set_error(e),
+        }
+    }
+
+    pub async fn find_email_address(&mut self) {
+        let req = FindEmailRequest {
+            name: self.get_name(),
+            phone: self.get_phone_number(),
+            verification_code: self.get_authentication_number(),
+        };
+
+        match find_email(req).await {
+            Ok(res) => {
+                self.error.set(None);
+                self.email_address.set(res.emails.join(", "));
+            }
+            Err(e) => self.set_error(e),
+        }
     }
 
-    //TODO: connect api
-    pub fn find_email_address(&mut self) {
-        tracing::info!("clicked: {}", self.get_email_address());
-        self.email_address.set("miner@biyard.co".to_string());
-        tracing::info!("email address: {}", self.get_email_address());
+    fn set_error(&mut self, e: ServerFnError<ApiError>) {
+        match e {
+            ServerFnError::WrappedServerError(e) => self.error.set(Some(e)),
+            e => {
+                tracing::error!("failed to find email: {:?}", e);
+                self.error
+                    .set(Some(ApiError::InternalServerError(e.to_string())));
+            }
+        }
     }
 }
@@ -15,6 +15,12 @@ pub struct FindEmailTranslate {
     pub get_email_description: String,
     pub email_address: String,
     pub go_to_login: String,
+
+    pub not_found: String,
+    pub invalid_verification_code: String,
+    pub verification_expired: String,
+    pub too_many_attempts: String,
+    pub failed: String,
 }
 
 pub fn translate(lang: Language) -> FindEmailTranslate {
@@ -33,6 +39,11 @@ pub fn translate(lang: Language) -> FindEmailTranslate {
             get_email_description: "We found your registered email with the following information".to_string(),
             email_address: "Email Address".to_string(),
             go_to_login: "To the login screen".to_string(),
+            not_found: "No account was found with the information.".to_string(),
+            invalid_verification_code: "The authentication number is incorrect.".to_string(),
+            verification_expired: "The authentication number has expired. Please resend it.".to_string(),
+            too_many_attempts: "Please try again in a moment.".to_string(),
+            failed: "Failed to find the email. Please try again.".to_string(),
         },
         Language::Ko => FindEmailTranslate {
             find_email: "이메일 찾기".to_string(),
@@ -48,6 +59,11 @@ pub fn translate(lang: Language) -> FindEmailTranslate {
             get_email_description: "다음 정보로 가입된 이메일을 찾았습니다.".to_string(),
             email_address: "이메일 주소".to_string(),
             go_to_login: "로그인 화면으로".to_string(),
+            not_found: "입력하신 정보로 가입된 계정이 없습니다.".to_string(),
+            invalid_verification_code: "인증번호가 올바르지 않습니다.".to_string(),
+            verification_expired: "인증시간이 초과되었습니다. 인증번호를 재발송해 주세요.".to_string(),
+            too_many_attempts: "잠시 후 다시 시도해 주세요.".to_string(),
+            failed: "이메일을 찾지 못했습니다. 다시 시도해 주세요.".to_string(),
         },
     }
 }
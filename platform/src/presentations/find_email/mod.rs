@@ -1,5 +1,6 @@
 #![allow(non_snake_case)]
 use crate::{
+    api::common::ApiError,
     components::{input::Input, table_row::Row},
     prelude::*,
 };
@@ -24,6 +25,7 @@ pub struct FindEmailProps {
     check_title: String,
     check_description: Vec<String>,
     find_email: String,
+    error_message: Option<String>,
 }
 
 #[derive(PartialEq, Props, Clone)]
@@ -72,6 +74,13 @@ pub fn FindEmailPage(props: FindEmailPageProps) -> Element {
                     check_title: translates.check_title.clone(),
                     check_description: translates.check_description.clone(),
                     find_email: translates.find_email.clone(),
+                    error_message: ctrl.get_error().map(|e| match e {
+                        ApiError::NotFound => translates.not_found.clone(),
+                        ApiError::InvalidVerificationCode => translates.invalid_verification_code.clone(),
+                        ApiError::VerificationExpired => translates.verification_expired.clone(),
+                        ApiError::TooManyAttempts(_) => translates.too_many_attempts.clone(),
+                        _ => translates.failed.clone(),
+                    }),
                 }
             } else {
                 GetEmail {
@@ -175,8 +184,8 @@ pub fn FindEmail(props: FindEmailProps) -> Element {
                             SendAuthenticationButton {
                                 label: props.send_authentication_number,
                                 lang: props.lang,
-                                onclick: move |_| {
-                                    ctrl.set_click_send_authentication();
+                                onclick: move |_| async move {
+                                    ctrl.set_click_send_authentication().await;
                                 }
                             }
                         }
@@ -225,12 +234,18 @@ pub fn FindEmail(props: FindEmailProps) -> Element {
                     "{props.check_description[1]}"
                 }
             }
+            if let Some(error_message) = props.error_message {
+                div {
+                    class: "flex flex-row w-full justify-end items-end pb-[20px] text-[16px] font-normal text-[#ff0000]",
+                    "{error_message}"
+                }
+            }
             div {
                 class: "flex flex-row w-full justify-end items-end",
                 div {
                     class: "flex flex-row w-[300px] h-[60px] bg-[#2168c3] justify-center items-center text-white font-bold text-[24px]",
-                    onclick: move |_| {
-                        ctrl.find_email_address();
+                    onclick: move |_| async move {
+                        ctrl.find_email_address().await;
                     },
                     "{props.find_email}"
                 }
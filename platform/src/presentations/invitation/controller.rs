@@ -0,0 +1,58 @@
+#![allow(non_snake_case)]
+use dioxus::prelude::*;
+use dioxus_logger::tracing;
+
+use crate::{
+    api::{
+        common::ApiError,
+        v1::organizations::{accept_invitation, AcceptInvitationRequest},
+    },
+    models::organization::OrganizationMembership,
+    utils::context::use_auth_context,
+};
+
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub struct Controller {
+    membership: Signal<Option<OrganizationMembership>>,
+    error: Signal<Option<ApiError>>,
+}
+
+impl Controller {
+    pub fn init(token: String) -> Self {
+        let mut auth = use_auth_context();
+        let mut ctrl = Self {
+            membership: use_signal(|| None),
+            error: use_signal(|| None),
+        };
+
+        let _ = use_effect(move || {
+            let token = token.clone();
+            spawn(async move {
+                match accept_invitation(AcceptInvitationRequest { token }).await {
+                    Ok(membership) => {
+                        if let Some(user) = auth.write().user.as_mut() {
+                            user.organization_id = membership.organization.id.clone();
+                        }
+                        ctrl.membership.set(Some(membership));
+                    }
+                    Err(ServerFnError::WrappedServerError(e)) => ctrl.error.set(Some(e)),
+                    Err(e) => {
+                        tracing::error!("failed to accept invitation: {:?}", e);
+                        ctrl.error
+                            .set(Some(ApiError::InternalServerError(e.to_string())));
+                    }
+                }
+            });
+        });
+
+        ctrl
+    }
+
+    pub fn get_membership(&self) -> Option<OrganizationMembership> {
+        (self.membership)()
+    }
+
+    pub fn get_error(&self) -> Option<ApiError> {
+        (self.error)()
+    }
+}
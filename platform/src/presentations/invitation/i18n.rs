@@ -0,0 +1,29 @@
+use crate::utils::context::Language;
+
+pub struct InvitationTranslate {
+    pub accepting: String,
+    pub joined: String,
+    pub invalid_invitation: String,
+    pub failed: String,
+    pub go_to_dashboard: String,
+}
+
+pub fn translate(lang: Language) -> InvitationTranslate {
+    match lang {
+        Language::En => InvitationTranslate {
+            accepting: "Accepting the invitation...".to_string(),
+            joined: "You have joined".to_string(),
+            invalid_invitation: "The invitation has expired or was sent to another email."
+                .to_string(),
+            failed: "Failed to accept the invitation. Please try again.".to_string(),
+            go_to_dashboard: "To the dashboard".to_string(),
+        },
+        Language::Ko => InvitationTranslate {
+            accepting: "초대를 수락하는 중입니다...".to_string(),
+            joined: "다음 조직에 참여했습니다".to_string(),
+            invalid_invitation: "초대가 만료되었거나 다른 이메일로 보낸 초대입니다.".to_string(),
+            failed: "초대를 수락하지 못했습니다. 다시 시도해 주세요.".to_string(),
+            go_to_dashboard: "대시보드로".to_string(),
+        },
+    }
+}
@@ -0,0 +1,54 @@
+#![allow(non_snake_case)]
+use crate::{api::common::ApiError, prelude::*};
+use dioxus::prelude::*;
+
+mod controller;
+mod i18n;
+
+#[derive(PartialEq, Props, Clone)]
+pub struct AcceptInvitationPageProps {
+    lang: Language,
+    token: String,
+}
+
+#[component]
+pub fn AcceptInvitationPage(props: AcceptInvitationPageProps) -> Element {
+    let ctrl = controller::Controller::init(props.token.clone());
+    let translates = i18n::translate(props.lang.clone());
+
+    rsx! {
+        div {
+            class: "flex flex-col w-full h-full justify-center items-center pt-[160px]",
+            if let Some(membership) = ctrl.get_membership() {
+                div {
+                    class: "text-[20px] font-normal text-black pb-[10px]",
+                    "{translates.joined}"
+                }
+                div {
+                    class: "text-[26px] font-bold text-black pb-[30px]",
+                    "{membership.organization.name}"
+                }
+                Link {
+                    to: Route::DashboardPage { lang: props.lang },
+                    div {
+                        class: "flex flex-row w-[300px] h-[60px] bg-[#2168c3] justify-center items-center text-white font-bold text-[24px]",
+                        "{translates.go_to_dashboard}"
+                    }
+                }
+            } else if let Some(error) = ctrl.get_error() {
+                div {
+                    class: "text-[20px] font-normal text-[#ff0000]",
+                    match error {
+                        ApiError::InvalidInvitation => translates.invalid_invitation,
+                        _ => translates.failed,
+                    }
+                }
+            } else {
+                div {
+                    class: "text-[20px] font-normal text-black",
+                    "{translates.accepting}"
+                }
+            }
+        }
+    }
+}
@@ -1,10 +1,20 @@
 #![allow(non_snake_case)]
 use dioxus::prelude::*;
+use dioxus_logger::tracing;
+
+use crate::{
+    api::{
+        common::ApiError,
+        v1::users::{login_user, LoginUserRequest},
+    },
+    models::user::UserProfile,
+};
 
 #[derive(Debug, Clone, PartialEq, Copy)]
 pub struct Controller {
     email: Signal<String>,
     password: Signal<String>,
+    login_error: Signal<Option<ApiError>>,
 }
 
 impl Controller {
@@ -12,6 +22,7 @@ impl Controller {
         let ctrl = Self {
             email: use_signal(|| "".to_string()),
             password: use_signal(|| "".to_string()),
+            login_error: use_signal(|| None),
         };
 
         use_context_provider(|| ctrl);
@@ -27,6 +38,10 @@ impl Controller {
         (self.password)()
     }
 
+    pub fn get_login_error(&self) -> Option<ApiError> {
+        (self.login_error)()
+    }
+
     pub fn set_email(&mut self, email: String) {
         self.email.set(email);
     }
@@ -34,4 +49,28 @@ impl Controller {
     pub fn set_password(&mut self, password: String) {
         self.password.set(password);
     }
+
+    pub async fn login(&mut self) -> Option<UserProfile> {
+        let req = LoginUserRequest {
+            email: self.get_email(),
+            password: self.get_password(),
+        };
+
+        match login_user(req).await {
+            Ok(profile) => {
+                self.login_error.set(None);
+                Some(profile)
+            }
+            Err(ServerFnError::WrappedServerError(e)) => {
+                self.login_error.set(Some(e));
+                None
+            }
+            Err(e) => {
+                tracing::error!("login failed: {:?}", e);
+                self.login_error
+                    .set(Some(ApiError::InternalServerError(e.to_string())));
+                None
+            }
+        }
+    }
 }
@@ -9,6 +9,9 @@ pub struct LoginTranslate {
     pub check_title: String,
     pub check_description_1: String,
     pub check_description_2: String,
+    pub invalid_credentials: String,
+    pub too_many_attempts: String,
+    pub login_failed: String,
 }
 
 pub fn translate(lang: Language) -> LoginTranslate {
@@ -22,7 +25,10 @@ pub fn translate(lang: Language) -> LoginTranslate {
             create_account: "Create Account".to_string(),
             check_title: "Check it out!".to_string(),
             check_description_1: "- The password must consist of at least 8 characters and a combination of letters, numbers, and special symbols.".to_string(),
-            check_description_2: "- If you lose your ID or password or exceed 5 errors, please reset your website password or contact customer service (XXXX-XXXX).".to_string()
+            check_description_2: "- If you lose your ID or password or exceed 5 errors, please reset your website password or contact customer service (XXXX-XXXX).".to_string(),
+            invalid_credentials: "The email or password is incorrect.".to_string(),
+            too_many_attempts: "Too many failed attempts. Please try again later.".to_string(),
+            login_failed: "Failed to log in. Please try again.".to_string(),
         },
         Language::Ko => LoginTranslate {
             login: "로그인".to_string(),
@@ -33,7 +39,10 @@ pub fn translate(lang: Language) -> LoginTranslate {
             create_account: "회원가입".to_string(),
             check_title: "확인하세요!".to_string(),
             check_description_1: "- 비밀번호는 영문, 숫자, 특수기호 조합으로 8자 이상 구성되어야 합니다.".to_string(),
-            check_description_2: "- ID, 비밀번호를 분실, 5회 오류횟수 초과시에는 홈페이지 비밀번호 재설정 또는 고객센터(XXXX-XXXX)로 문의해 주세요.".to_string()
+            check_description_2: "- ID, 비밀번호를 분실, 5회 오류횟수 초과시에는 홈페이지 비밀번호 재설정 또는 고객센터(XXXX-XXXX)로 문의해 주세요.".to_string(),
+            invalid_credentials: "이메일 또는 비밀번호가 올바르지 않습니다.".to_string(),
+            too_many_attempts: "로그인 시도 횟수를 초과했습니다. 잠시 후 다시 시도해 주세요.".to_string(),
+            login_failed: "로그인에 실패했습니다. 다시 시도해 주세요.".to_string(),
         },
     }
 }
@@ -1,5 +1,5 @@
 #![allow(non_snake_case)]
-use crate::{components::bottom::Bottom, prelude::*};
+use crate::{api::common::ApiError, components::bottom::Bottom, prelude::*};
 use dioxus::prelude::*;
 
 mod controller;
@@ -25,13 +25,17 @@ pub struct InputPasswordProps {
 #[derive(Props, Clone, PartialEq)]
 pub struct LoginProps {
     ctrl: controller::Controller,
+    lang: Language,
     login_message: String,
     email_message: String,
     password_message: String,
+    error_message: Option<String>,
 }
 
 #[derive(Props, Clone, PartialEq)]
 pub struct LoginButtonProps {
+    ctrl: controller::Controller,
+    lang: Language,
     login_message: String,
 }
 
@@ -61,6 +65,11 @@ pub fn LoginPage(props: LoginPageProps) -> Element {
     let check_title_message = translates.check_title;
     let check_description_1_message = translates.check_description_1;
     let check_description_2_message = translates.check_description_2;
+    let error_message = ctrl.get_login_error().map(|e| match e {
+        ApiError::InvalidCredentials => translates.invalid_credentials,
+        ApiError::TooManyAttempts(_) => translates.too_many_attempts,
+        _ => translates.login_failed,
+    });
 
     rsx! {
         div { class: "bg-white dark:bg-black w-screen min-h-screen flex flex-col",
@@ -82,9 +91,11 @@ pub fn LoginPage(props: LoginPageProps) -> Element {
                     class: "flex flex-col w-full h-full justify-center items-center",
                     LoginComponent {
                         ctrl,
+                        lang: props.lang.clone(),
                         email_message,
                         password_message,
-                        login_message
+                        login_message,
+                        error_message,
                     }
                     MemberInfoComponent {
                         lang: props.lang.clone(),
@@ -193,19 +204,43 @@ pub fn LoginComponent(props: LoginProps) -> Element {
                     }
                 }
                 LoginButton {
+                    ctrl: props.ctrl,
+                    lang: props.lang,
                     login_message: props.login_message,
                 }
             }
+            if let Some(error_message) = props.error_message {
+                div {
+                    class: "flex flex-row w-[610px] justify-start items-center text-[16px] font-normal text-[#ff0000] pt-[10px]",
+                    "{error_message}"
+                }
+            }
         }
     }
 }
 
 #[component]
 pub fn LoginButton(props: LoginButtonProps) -> Element {
+    let mut ctrl = props.ctrl;
+    let lang = props.lang;
+    let navigator = use_navigator();
+    let mut auth = use_auth_context();
+    let mut login_state = use_login_context();
+
     rsx! {
         div {
-            class: "flex flex-row w-[300px] h-[100px] bg-[#2168c3]",
+            class: "flex flex-row w-[300px] h-[100px] bg-[#2168c3] cursor-pointer",
             style: "width: 300px",
+            onclick: move |_| async move {
+                if let Some(user) = ctrl.login().await {
+                    auth.set(AuthContext {
+                        checked: true,
+                        user: Some(user),
+                    });
+                    let next = login_state.write().1.take();
+                    navigator.push(next.unwrap_or(Route::DashboardPage { lang }));
+                }
+            },
             div {
                 class: "flex flex-row w-full h-full justify-center items-center text-[24px] font-bold text-white",
                 "{props.login_message}"
@@ -0,0 +1,192 @@
+#![allow(non_snake_case)]
+use dioxus::prelude::*;
+use dioxus_logger::tracing;
+
+use crate::{
+    api::{
+        common::ApiError,
+        v1::{
+            organizations::{
+                invite_member, list_members, list_organizations, remove_member, update_member_role,
+                InviteMemberRequest, RemoveMemberRequest, UpdateMemberRoleRequest,
+            },
+            users::get_current_user,
+        },
+    },
+    models::{
+        organization::Member,
+        role::{Permission, Role},
+    },
+    utils::context::{use_auth_context, AuthContext, Language},
+};
+
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub struct Controller {
+    lang: Language,
+    auth: Signal<AuthContext>,
+    members: Signal<Vec<Member>>,
+    // the role of the signed-in user in the current organization
+    role: Signal<Option<Role>>,
+    invite_email: Signal<String>,
+    invite_role: Signal<Role>,
+    // the email invited last
+    invited: Signal<Option<String>>,
+    error: Signal<Option<ApiError>>,
+}
+
+impl Controller {
+    pub fn init(lang: Language) -> Self {
+        let mut ctrl = Self {
+            lang,
+            auth: use_auth_context(),
+            members: use_signal(|| vec![]),
+            role: use_signal(|| None),
+            invite_email: use_signal(|| "".to_string()),
+            invite_role: use_signal(|| Role::Editor),
+            invited: use_signal(|| None),
+            error: use_signal(|| None),
+        };
+
+        let _ = use_effect(move || {
+            spawn(async move {
+                ctrl.load().await;
+            });
+        });
+
+        ctrl
+    }
+
+    async fn load(&mut self) {
+        let organization_id = self
+            .auth
+            .peek()
+            .user
+            .as_ref()
+            .map(|u| u.organization_id.clone())
+            .unwrap_or_default();
+
+        match list_organizations().await {
+            Ok(organizations) => self.role.set(
+                organizations
+                    .into_iter()
+                    .find(|m| m.organization.id == organization_id)
+                    .map(|m| m.role),
+            ),
+            Err(e) => return self.set_error(e),
+        }
+        match list_members().await {
+            Ok(members) => self.members.set(members),
+            Err(e) => self.set_error(e),
+        }
+    }
+
+    pub fn get_members(&self) -> Vec<Member> {
+        (self.members)()
+    }
+
+    pub fn can_manage(&self) -> bool {
+        (self.role)().map_or(false, |r| r.allows(Permission::ManageMembers))
+    }
+
+    pub fn is_me(&self, user_id: &str) -> bool {
+        self.auth
+            .peek()
+            .user
+            .as_ref()
+            .map_or(false, |u| u.id == user_id)
+    }
+
+    pub fn get_invite_email(&self) -> String {
+        (self.invite_email)()
+    }
+
+    pub fn set_invite_email(&mut self, email: String) {
+        self.invite_email.set(email);
+    }
+
+    pub fn get_invite_role(&self) -> Role {
+        (self.invite_role)()
+    }
+
+    pub fn set_invite_role(&mut self, role: Role) {
+        self.invite_role.set(role);
+    }
+
+    pub fn get_invited(&self) -> Option<String> {
+        (self.invited)()
+    }
+
+    pub fn get_error(&self) -> Option<ApiError> {
+        (self.error)()
+    }
+
+    pub async fn invite(&mut self) {
+        let email = self.get_invite_email().trim().to_string();
+        if !email.contains('@') {
+            self.error
+                .set(Some(ApiError::InvalidInput("email".to_string())));
+            return;
+        }
+
+        let req = InviteMemberRequest {
+            email: email.clone(),
+            role: self.get_invite_role(),
+            lang: self.lang,
+        };
+        match invite_member(req).await {
+            Ok(_) => {
+                self.error.set(None);
+                self.invited.set(Some(email));
+                self.invite_email.set("".to_string());
+            }
+            Err(e) => self.set_error(e),
+        }
+    }
+
+    pub async fn change_role(&mut self, user_id: String, role: Role) {
+        match update_member_role(UpdateMemberRoleRequest { user_id, role }).await {
+            Ok(_) => {
+                self.error.set(None);
+                self.load().await;
+            }
+            Err(e) => self.set_error(e),
+        }
+    }
+
+    // remove removes a member; removing oneself leaves the organization,
+    // in which case it returns true.
+    pub async fn remove(&mut self, user_id: String) -> bool {
+        let leaving = self.is_me(&user_id);
+        if let Err(e) = remove_member(RemoveMemberRequest { user_id }).await {
+            self.set_error(e);
+            return false;
+        }
+        self.error.set(None);
+
+        if !leaving {
+            self.load().await;
+            return false;
+        }
+        // the server moved the user to another organization
+        match get_current_user().await {
+            Ok(user) => self.auth.set(AuthContext {
+                checked: true,
+                user: Some(user),
+            }),
+            Err(e) => tracing::error!("failed to reload the user: {:?}", e),
+        }
+
+        true
+    }
+
+    fn set_error(&mut self, e: ServerFnError<ApiError>) {
+        match e {
+            ServerFnError::WrappedServerError(e) => self.error.set(Some(e)),
+            e => {
+                tracing::error!("members request failed: {:?}", e);
+                self.error
+                    .set(Some(ApiError::InternalServerError(e.to_string())));
+            }
+        }
+    }
+}
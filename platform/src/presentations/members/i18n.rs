@@ -0,0 +1,75 @@
+use crate::{models::role::Role, utils::context::Language};
+
+pub struct MembersTranslate {
+    pub members: String,
+    pub invite: String,
+    pub email_hint: String,
+    pub invited: String,
+    pub name: String,
+    pub email: String,
+    pub role: String,
+    pub remove: String,
+    pub leave: String,
+    pub owner: String,
+    pub editor: String,
+    pub analyst: String,
+    pub viewer: String,
+    pub invalid_email: String,
+    pub forbidden: String,
+    pub last_owner: String,
+    pub failed: String,
+}
+
+impl MembersTranslate {
+    pub fn role_name(&self, role: Role) -> String {
+        match role {
+            Role::Owner => self.owner.clone(),
+            Role::Editor => self.editor.clone(),
+            Role::Analyst => self.analyst.clone(),
+            Role::Viewer => self.viewer.clone(),
+        }
+    }
+}
+
+pub fn translate(lang: Language) -> MembersTranslate {
+    match lang {
+        Language::En => MembersTranslate {
+            members: "Members".to_string(),
+            invite: "Invite".to_string(),
+            email_hint: "Email to invite".to_string(),
+            invited: "An invitation was sent to".to_string(),
+            name: "Name".to_string(),
+            email: "Email".to_string(),
+            role: "Role".to_string(),
+            remove: "Remove".to_string(),
+            leave: "Leave".to_string(),
+            owner: "Owner".to_string(),
+            editor: "Editor".to_string(),
+            analyst: "Analyst".to_string(),
+            viewer: "Viewer".to_string(),
+            invalid_email: "Please enter a valid email.".to_string(),
+            forbidden: "Only owners can manage members.".to_string(),
+            last_owner: "An organization must keep at least one owner.".to_string(),
+            failed: "The request failed. Please try again.".to_string(),
+        },
+        Language::Ko => MembersTranslate {
+            members: "멤버".to_string(),
+            invite: "초대".to_string(),
+            email_hint: "초대할 이메일".to_string(),
+            invited: "초대를 보냈습니다:".to_string(),
+            name: "이름".to_string(),
+            email: "이메일".to_string(),
+            role: "역할".to_string(),
+            remove: "내보내기".to_string(),
+            leave: "나가기".to_string(),
+            owner: "소유자".to_string(),
+            editor: "편집자".to_string(),
+            analyst: "분석가".to_string(),
+            viewer: "뷰어".to_string(),
+            invalid_email: "올바른 이메일을 입력해 주세요.".to_string(),
+            forbidden: "소유자만 멤버를 관리할 수 있습니다.".to_string(),
+            last_owner: "조직에는 최소 한 명의 소유자가 있어야 합니다.".to_string(),
+            failed: "요청에 실패했습니다. 다시 시도해 주세요.".to_string(),
+        },
+    }
+}
@@ -0,0 +1,151 @@
+#![allow(non_snake_case)]
+use crate::{api::common::ApiError, models::role::Role, prelude::*};
+use dioxus::prelude::*;
+
+mod controller;
+mod i18n;
+
+#[derive(PartialEq, Props, Clone)]
+pub struct MembersPageProps {
+    lang: Language,
+}
+
+#[component]
+pub fn MembersPage(props: MembersPageProps) -> Element {
+    let mut ctrl = controller::Controller::init(props.lang);
+    let translates = i18n::translate(props.lang);
+    let navigator = use_navigator();
+    let lang = props.lang;
+    let can_manage = ctrl.can_manage();
+
+    rsx! {
+        div {
+            class: "flex flex-col w-full pt-[45px] pr-[45px] pl-[35px] items-start justify-start",
+            div {
+                class: "text-[26px] font-bold text-black pb-[20px]",
+                "{translates.members}"
+            }
+            if can_manage {
+                div {
+                    class: "flex flex-row w-full items-center pb-[10px]",
+                    input {
+                        class: "w-[400px] h-[45px] text-[18px] bg-white border border-[#e0e0e0] rounded-[8px] px-[10px] mr-[10px]",
+                        "type": "email",
+                        placeholder: translates.email_hint.clone(),
+                        value: ctrl.get_invite_email(),
+                        oninput: move |e| ctrl.set_invite_email(e.value()),
+                    }
+                    select {
+                        class: "w-[150px] h-[45px] bg-white border border-[#e0e0e0] rounded-[8px] px-[5px] mr-[10px]",
+                        onchange: move |e| {
+                            if let Some(role) = e.value().parse::<usize>().ok().and_then(|i| Role::ALL.get(i)) {
+                                ctrl.set_invite_role(*role);
+                            }
+                        },
+                        for (i, role) in Role::ALL.into_iter().enumerate() {
+                            option {
+                                value: i.to_string(),
+                                selected: role == ctrl.get_invite_role(),
+                                {translates.role_name(role)}
+                            }
+                        }
+                    }
+                    div {
+                        class: "flex flex-row w-[120px] h-[45px] bg-[#2168c3] rounded-[8px] justify-center items-center text-white font-bold text-[18px] cursor-pointer",
+                        onclick: move |_| async move {
+                            ctrl.invite().await;
+                        },
+                        "{translates.invite}"
+                    }
+                }
+            }
+            if let Some(email) = ctrl.get_invited() {
+                div {
+                    class: "text-[16px] font-normal text-[#2168c3] pb-[10px]",
+                    "{translates.invited} {email}"
+                }
+            }
+            if let Some(error) = ctrl.get_error() {
+                div {
+                    class: "text-[16px] font-normal text-[#ff0000] pb-[10px]",
+                    match error {
+                        ApiError::InvalidInput(_) => translates.invalid_email.clone(),
+                        ApiError::Forbidden => translates.forbidden.clone(),
+                        ApiError::LastOwner => translates.last_owner.clone(),
+                        _ => translates.failed.clone(),
+                    }
+                }
+            }
+            div {
+                class: "flex flex-col w-full bg-white border border-[#e0e0e0] rounded-[8px]",
+                div {
+                    class: "flex flex-row w-full h-[50px] items-center px-[20px] border-b border-[#e0e0e0] text-[16px] font-bold text-[#4c4c4c]",
+                    div { class: "w-[200px]", "{translates.name}" }
+                    div { class: "flex-1", "{translates.email}" }
+                    div { class: "w-[170px]", "{translates.role}" }
+                    div { class: "w-[100px]" }
+                }
+                for member in ctrl.get_members() {
+                    div {
+                        key: "{member.user_id}",
+                        class: "flex flex-row w-full h-[55px] items-center px-[20px] border-b border-[#e0e0e0] text-[16px] text-black",
+                        div { class: "w-[200px]", "{member.name}" }
+                        div { class: "flex-1", "{member.email}" }
+                        div {
+                            class: "w-[170px]",
+                            if can_manage {
+                                select {
+                                    class: "w-[150px] h-[35px] border border-[#e0e0e0] rounded-[5px]",
+                                    onchange: {
+                                        let user_id = member.user_id.clone();
+                                        move |e: Event<FormData>| {
+                                            let user_id = user_id.clone();
+                                            async move {
+                                                if let Some(role) = e.value().parse::<usize>().ok().and_then(|i| Role::ALL.get(i)) {
+                                                    ctrl.change_role(user_id, *role).await;
+                                                }
+                                            }
+                                        }
+                                    },
+                                    for (i, role) in Role::ALL.into_iter().enumerate() {
+                                        option {
+                                            value: i.to_string(),
+                                            selected: role == member.role,
+                                            {translates.role_name(role)}
+                                        }
+                                    }
+                                }
+                            } else {
+                                {translates.role_name(member.role)}
+                            }
+                        }
+                        div {
+                            class: "w-[100px]",
+                            if can_manage || ctrl.is_me(&member.user_id) {
+                                div {
+                                    class: "text-[14px] text-[#ff0000] cursor-pointer",
+                                    onclick: {
+                                        let user_id = member.user_id.clone();
+                                        move |_| {
+                                            let user_id = user_id.clone();
+                                            async move {
+                                                if ctrl.remove(user_id).await {
+                                                    navigator.replace(Route::DashboardPage { lang });
+                                                }
+                                            }
+                                        }
+                                    },
+                                    if ctrl.is_me(&member.user_id) {
+                                        "{translates.leave}"
+                                    } else {
+                                        "{translates.remove}"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
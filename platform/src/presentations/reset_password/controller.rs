@@ -2,6 +2,17 @@
 use dioxus::prelude::*;
 use dioxus_logger::tracing;
 
+use crate::{
+    api::{
+        common::ApiError,
+        v1::users::reset::{
+            request_password_reset, reset_password, verify_password_reset,
+            RequestPasswordResetRequest, ResetPasswordRequest, VerifyPasswordResetRequest,
+        },
+    },
+    models::user::is_valid_password,
+};
+
 #[derive(Debug, Clone, PartialEq, Copy)]
 pub struct Controller {
     email: Signal<String>,
@@ -11,6 +22,9 @@ pub struct Controller {
     step: Signal<u64>,
     new_password: Signal<String>,
     new_password_check: Signal<String>,
+    reset_token: Signal<String>,
+    error: Signal<Option<ApiError>>,
+    password_mismatch: Signal<bool>,
 }
 
 impl Controller {
@@ -23,6 +37,9 @@ impl Controller {
             step: use_signal(|| 0),
             new_password: use_signal(|| "".to_string()),
             new_password_check: use_signal(|| "".to_string()),
+            reset_token: use_signal(|| "".to_string()),
+            error: use_signal(|| None),
+            password_mismatch: use_signal(|| false),
         };
 
         use_context_provider(|| ctrl);
@@ -58,6 +75,14 @@ impl Controller {
         (self.new_password_check)()
     }
 
+    pub fn get_error(&self) -> Option<ApiError> {
+        (self.error)()
+    }
+
+    pub fn get_password_mismatch(&self) -> bool {
+        (self.password_mismatch)()
+    }
+
     pub fn set_step(&mut self, step: u64) {
         self.step.set(step);
     }
@@ -86,18 +111,73 @@ impl Controller {
         self.new_password_check.set(new_password_check);
     }
 
-    pub fn set_click_send_authentication(&mut self) {
+    pub async fn set_click_send_authentication(&mut self) {
         tracing::info!("send authentication button clicked");
+        let req = RequestPasswordResetRequest {
+            email: self.get_email(),
+            name: self.get_name(),
+            phone: self.get_phone_number(),
+        };
+
+        match request_password_reset(req).await {
+            Ok(_) => self.error.set(None),
+            Err(e) => self.set_error(e),
+        }
     }
 
-    //TODO: connect api
-    pub fn clicked_email_authentication(&mut self) {
+    pub async fn clicked_email_authentication(&mut self) {
         tracing::info!("email authentication button clicked");
-        self.step.set(1);
+        let req = VerifyPasswordResetRequest {
+            email: self.get_email(),
+            verification_code: self.get_authentication_number(),
+        };
+
+        match verify_password_reset(req).await {
+            Ok(res) => {
+                self.error.set(None);
+                self.reset_token.set(res.token);
+                self.step.set(1);
+            }
+            Err(e) => self.set_error(e),
+        }
     }
 
-    pub fn clicked_reset_new_password(&mut self) {
+    pub async fn clicked_reset_new_password(&mut self) {
         tracing::info!("reset new password button clicked");
-        self.step.set(2);
+        let new_password = self.get_new_password();
+        if new_password != self.get_new_password_check() {
+            self.password_mismatch.set(true);
+            return;
+        }
+        self.password_mismatch.set(false);
+
+        if !is_valid_password(&new_password) {
+            self.error.set(Some(ApiError::InvalidPassword));
+            return;
+        }
+
+        let req = ResetPasswordRequest {
+            token: (self.reset_token)(),
+            new_password,
+        };
+
+        match reset_password(req).await {
+            Ok(_) => {
+                self.error.set(None);
+                self.step.set(2);
+            }
+            Err(e) => self.set_error(e),
+        }
+    }
+
+    fn set_error(&mut self, e: ServerFnError<ApiError>) {
+        match e {
+            ServerFnError::WrappedServerError(e) => self.error.set(Some(e)),
+            e => {
+                tracing::error!("failed to reset password: {:?}", e);
+                self.error
+                    .set(Some(ApiError::InternalServerError(e.to_string())));
+            }
+        }
     }
 }
@@ -19,6 +19,14 @@ pub struct ResetPasswordTranslate {
     pub complete_change_password_title: String,
     pub complete_change_password_description: Vec<String>,
     pub go_to_login: String,
+
+    pub invalid_password: String,
+    pub password_mismatch: String,
+    pub invalid_verification_code: String,
+    pub verification_expired: String,
+    pub invalid_reset_token: String,
+    pub too_many_attempts: String,
+    pub failed: String,
 }
 
 pub fn translate(lang: Language) -> ResetPasswordTranslate {
@@ -40,7 +48,14 @@ pub fn translate(lang: Language) -> ResetPasswordTranslate {
             check_new_password_description: vec!["- The password must consist of at least 8 characters and a combination of letters, numbers, and special symbols.".to_string()],
             complete_change_password_title: "Password change completed".to_string(),
             complete_change_password_description: vec!["Your password change has been completed!".to_string(), "Please log in with a new password.".to_string()],
-            go_to_login: "To the login screen".to_string()
+            go_to_login: "To the login screen".to_string(),
+            invalid_password: "The password must consist of at least 8 characters and a combination of letters, numbers, and special symbols.".to_string(),
+            password_mismatch: "The passwords do not match.".to_string(),
+            invalid_verification_code: "The authentication number is incorrect.".to_string(),
+            verification_expired: "The authentication number has expired. Please resend it.".to_string(),
+            invalid_reset_token: "The password reset has expired. Please authenticate again.".to_string(),
+            too_many_attempts: "Please try again in a moment.".to_string(),
+            failed: "Failed to reset the password. Please try again.".to_string(),
         },
         Language::Ko => ResetPasswordTranslate {
             reset_password: "비밀번호 재설정".to_string(),
@@ -59,7 +74,14 @@ pub fn translate(lang: Language) -> ResetPasswordTranslate {
             check_new_password_description: vec!["- 비밀번호는 영문, 숫자, 특수기호 조합으로 8자 이상 구성되어야 합니다.".to_string()],
             complete_change_password_title: "비밀번호 변경 완료".to_string(),
             complete_change_password_description: vec!["비밀번호 변경이 완료 되었습니다!".to_string(), "새로운 비밀번호로 로그인 해 주세요.".to_string()],
-            go_to_login: "로그인 화면으로".to_string()
+            go_to_login: "로그인 화면으로".to_string(),
+            invalid_password: "비밀번호는 영문, 숫자, 특수기호 조합으로 8자 이상 구성되어야 합니다.".to_string(),
+            password_mismatch: "비밀번호가 일치하지 않습니다.".to_string(),
+            invalid_verification_code: "인증번호가 올바르지 않습니다.".to_string(),
+            verification_expired: "인증시간이 초과되었습니다. 인증번호를 재발송해 주세요.".to_string(),
+            invalid_reset_token: "비밀번호 재설정 시간이 만료되었습니다. 다시 인증해 주세요.".to_string(),
+            too_many_attempts: "잠시 후 다시 시도해 주세요.".to_string(),
+            failed: "비밀번호를 재설정하지 못했습니다. 다시 시도해 주세요.".to_string(),
         },
     }
 }
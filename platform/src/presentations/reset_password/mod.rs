@@ -2,6 +2,7 @@
 use dioxus::prelude::*;
 
 use crate::{
+    api::common::ApiError,
     components::{input::Input, table_row::Row},
     prelude::*,
 };
@@ -32,6 +33,7 @@ pub struct EmailAuthenticationi18nProps {
     authentication_number_description: Vec<String>,
     check_title: String,
     check_description: Vec<String>,
+    error_message: Option<String>,
 }
 
 #[derive(PartialEq, Props, Clone)]
@@ -41,6 +43,7 @@ pub struct ResetNewPasswordi18nProps {
     input_new_password_check_label: String,
     check_title: String,
     check_new_password_description: Vec<String>,
+    error_message: Option<String>,
 }
 
 #[derive(PartialEq, Props, Clone)]
@@ -78,6 +81,18 @@ pub mod i18n;
 pub fn ResetPasswordPage(props: ResetPasswordPageProps) -> Element {
     let mut ctrl = controller::Controller::init();
     let translates = i18n::translate(props.lang.clone());
+    let error_message = if ctrl.get_password_mismatch() {
+        Some(translates.password_mismatch.clone())
+    } else {
+        ctrl.get_error().map(|e| match e {
+            ApiError::InvalidPassword => translates.invalid_password.clone(),
+            ApiError::InvalidVerificationCode => translates.invalid_verification_code.clone(),
+            ApiError::VerificationExpired => translates.verification_expired.clone(),
+            ApiError::InvalidResetToken => translates.invalid_reset_token.clone(),
+            ApiError::TooManyAttempts(_) => translates.too_many_attempts.clone(),
+            _ => translates.failed.clone(),
+        })
+    };
 
     rsx! {
         div {
@@ -98,6 +113,7 @@ pub fn ResetPasswordPage(props: ResetPasswordPageProps) -> Element {
                         authentication_number_description: translates.authentication_number_description,
                         check_title: translates.check_title,
                         check_description: translates.check_description,
+                        error_message,
                     }
                 }
             }
@@ -110,7 +126,8 @@ pub fn ResetPasswordPage(props: ResetPasswordPageProps) -> Element {
                         input_new_password_label: translates.input_new_password_label,
                         input_new_password_check_label: translates.input_new_password_check_label,
                         check_title: translates.check_title,
-                        check_new_password_description: translates.check_new_password_description
+                        check_new_password_description: translates.check_new_password_description,
+                        error_message,
                     }
                 }
             } else {
@@ -229,12 +246,18 @@ pub fn ResetNewPassword(props: ResetNewPasswordProps) -> Element {
                     "{props.i18n.check_new_password_description[0]}"
                 }
             }
+            if let Some(error_message) = props.i18n.error_message {
+                div {
+                    class: "flex flex-row w-full justify-end items-end pb-[20px] text-[16px] font-normal text-[#ff0000]",
+                    "{error_message}"
+                }
+            }
             div {
                 class: "flex flex-row w-full justify-end items-end",
                 div {
                     class: "flex flex-row w-[300px] h-[60px] bg-[#2168c3] justify-center items-center text-white font-bold text-[24px]",
-                    onclick: move |_| {
-                        ctrl.clicked_reset_new_password();
+                    onclick: move |_| async move {
+                        ctrl.clicked_reset_new_password().await;
                     },
                     "{props.i18n.reset_password}"
                 }
@@ -313,8 +336,8 @@ pub fn EmailAuthentication(props: EmailAuthenticationProps) -> Element {
                                 SendAuthenticationButton {
                                     label: props.i18n.send_authentication,
                                     lang: props.lang,
-                                    onclick: move |_| {
-                                        // ctrl.set_click_send_authentication();
+                                    onclick: move |_| async move {
+                                        ctrl.set_click_send_authentication().await;
                                     }
                                 }
                             }
@@ -368,12 +391,18 @@ pub fn EmailAuthentication(props: EmailAuthenticationProps) -> Element {
                     "{props.i18n.check_description[2]}"
                 }
             }
+            if let Some(error_message) = props.i18n.error_message {
+                div {
+                    class: "flex flex-row w-full justify-end items-end pb-[20px] text-[16px] font-normal text-[#ff0000]",
+                    "{error_message}"
+                }
+            }
             div {
                 class: "flex flex-row w-full justify-end items-end",
                 div {
                     class: "flex flex-row w-[300px] h-[60px] bg-[#2168c3] justify-center items-center text-white font-bold text-[24px]",
-                    onclick: move |_| {
-                        ctrl.clicked_email_authentication();
+                    onclick: move |_| async move {
+                        ctrl.clicked_email_authentication().await;
                     },
                     "{props.i18n.reset_password}"
                 }
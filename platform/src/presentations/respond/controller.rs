@@ -0,0 +1,242 @@
+#![allow(non_snake_case)]
+use dioxus::prelude::*;
+use dioxus_logger::tracing;
+
+use crate::{
+    api::{
+        common::ApiError,
+        v1::surveys::{
+            answer_survey,
+            respond::{get_survey_form, SurveyForm},
+            AnswerSurveyRequest,
+        },
+    },
+    models::question::{
+        carried_options, resolve, route, validate_answer, validate_answers, Question,
+        QuestionAnswer,
+    },
+};
+
+// respondents do not sign in, so the browser keeps the respondent token issued with each form
+// to come back to a survey as the same respondent, who answers only once
+fn token_key(survey_id: &str) -> String {
+    format!("voice-korea-respondent#{survey_id}")
+}
+
+async fn stored_token(survey_id: &str) -> Option<String> {
+    let key = serde_json::to_string(&token_key(survey_id)).ok()?;
+    let script = format!(r#"return localStorage.getItem({key}) ?? "";"#);
+
+    document::eval(&script)
+        .join::<String>()
+        .await
+        .ok()
+        .filter(|token| !token.is_empty())
+}
+
+fn store_token(survey_id: &str, token: &str) {
+    if let (Ok(key), Ok(token)) = (
+        serde_json::to_string(&token_key(survey_id)),
+        serde_json::to_string(token),
+    ) {
+        let _ = document::eval(&format!("localStorage.setItem({key}, {token});"));
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub struct Controller {
+    form: Signal<Option<SurveyForm>>,
+    // in the order of the questions; `None` is not answered yet
+    answers: Signal<Vec<Option<QuestionAnswer>>>,
+    step: Signal<usize>,
+    invalid: Signal<bool>,
+    submitting: Signal<bool>,
+    submitted: Signal<bool>,
+    error: Signal<Option<ApiError>>,
+}
+
+impl Controller {
+    pub fn init(survey_id: String) -> Self {
+        let mut ctrl = Self {
+            form: use_signal(|| None),
+            answers: use_signal(|| vec![]),
+            step: use_signal(|| 0),
+            invalid: use_signal(|| false),
+            submitting: use_signal(|| false),
+            submitted: use_signal(|| false),
+            error: use_signal(|| None),
+        };
+
+        let _ = use_effect(move || {
+            let survey_id = survey_id.clone();
+            spawn(async move {
+                let token = stored_token(&survey_id).await;
+                match get_survey_form(survey_id.clone(), token, None).await {
+                    Ok(form) => {
+                        store_token(&survey_id, &form.token);
+                        ctrl.answers.set(vec![None; form.questions.len()]);
+                        ctrl.form.set(Some(form));
+                    }
+                    Err(e) => ctrl.set_error(e),
+                }
+            });
+        });
+
+        ctrl
+    }
+
+    pub fn get_form(&self) -> Option<SurveyForm> {
+        (self.form)()
+    }
+
+    pub fn get_step(&self) -> usize {
+        (self.step)()
+    }
+
+    // shown is whether each question is shown with the current answers, see `route`.
+    fn shown(&self) -> Vec<bool> {
+        match self.form.read().as_ref() {
+            Some(form) => route(&form.questions, &self.answers.read()),
+            None => vec![],
+        }
+    }
+
+    // get_position counts the questions shown so far, including the current one.
+    pub fn get_position(&self) -> usize {
+        let step = self.get_step();
+        self.shown()
+            .into_iter()
+            .take(step + 1)
+            .filter(|s| *s)
+            .count()
+    }
+
+    // get_total counts the questions shown, which changes with the answers.
+    pub fn get_total(&self) -> usize {
+        self.shown().into_iter().filter(|s| *s).count()
+    }
+
+    pub fn is_last(&self) -> bool {
+        let step = self.get_step();
+        !self.shown().into_iter().skip(step + 1).any(|s| s)
+    }
+
+    // get_question returns the current question with the earlier answers filled in, see `resolve`.
+    pub fn get_question(&self) -> Option<Question> {
+        let form = self.form.read();
+        resolve(
+            &form.as_ref()?.questions,
+            &self.answers.read(),
+            self.get_step(),
+        )
+    }
+
+    // get_allowed returns the options the current question carries forward, if it does.
+    pub fn get_allowed(&self) -> Option<Vec<usize>> {
+        let form = self.form.read();
+        let answers = self.answers.read();
+        carried_options(&form.as_ref()?.questions, self.get_step(), |j| {
+            answers.get(j).and_then(|a| a.as_ref())
+        })
+    }
+
+    pub fn get_answer(&self) -> Option<QuestionAnswer> {
+        self.answers.read().get(self.get_step()).cloned().flatten()
+    }
+
+    pub fn set_answer(&mut self, answer: Option<QuestionAnswer>) {
+        let step = self.get_step();
+        if let Some(a) = self.answers.write().get_mut(step) {
+            *a = answer;
+        }
+        self.invalid.set(false);
+    }
+
+    pub fn get_invalid(&self) -> bool {
+        (self.invalid)()
+    }
+
+    pub fn get_submitting(&self) -> bool {
+        (self.submitting)()
+    }
+
+    pub fn get_submitted(&self) -> bool {
+        (self.submitted)()
+    }
+
+    pub fn get_error(&self) -> Option<ApiError> {
+        (self.error)()
+    }
+
+    pub fn prev(&mut self) {
+        let step = self.get_step();
+        let shown = self.shown();
+        if let Some(prev) = (0..step).rev().find(|i| shown[*i]) {
+            self.step.set(prev);
+            self.invalid.set(false);
+        }
+    }
+
+    // next moves to the next question once the current one is answered properly.
+    pub fn next(&mut self) {
+        let valid = match self.get_question() {
+            Some(question) => {
+                let answer = self.get_answer();
+                validate_answer(&question, answer.as_ref())
+                    && answer.map_or(true, |a| a.selects_within(self.get_allowed().as_deref()))
+            }
+            None => false,
+        };
+        if !valid {
+            self.invalid.set(true);
+            return;
+        }
+        let step = self.get_step();
+        let shown = self.shown();
+        if let Some(next) = (step + 1..shown.len()).find(|i| shown[*i]) {
+            self.step.set(next);
+        }
+    }
+
+    pub async fn submit(&mut self) {
+        let Some(form) = self.get_form() else {
+            return;
+        };
+        // answers to questions hidden by a later change are dropped
+        let shown = route(&form.questions, &self.answers.read());
+        let answers: Vec<Option<QuestionAnswer>> = (self.answers)()
+            .into_iter()
+            .zip(shown)
+            .map(|(answer, shown)| answer.filter(|_| shown))
+            .collect();
+        if let Err(i) = validate_answers(&form.questions, &answers) {
+            self.step.set(i.min(answers.len().saturating_sub(1)));
+            self.invalid.set(true);
+            return;
+        }
+
+        self.submitting.set(true);
+        match answer_survey(AnswerSurveyRequest {
+            survey_id: form.id,
+            token: form.token,
+            answers,
+        })
+        .await
+        {
+            Ok(_) => self.submitted.set(true),
+            Err(e) => self.set_error(e),
+        }
+        self.submitting.set(false);
+    }
+
+    fn set_error(&mut self, e: ServerFnError<ApiError>) {
+        match e {
+            ServerFnError::WrappedServerError(e) => self.error.set(Some(e)),
+            e => {
+                tracing::error!("failed to answer survey: {:?}", e);
+                self.error
+                    .set(Some(ApiError::InternalServerError(e.to_string())));
+            }
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use crate::utils::context::Language;
+
+pub struct RespondTranslate {
+    pub loading: String,
+    pub required: String,
+    pub invalid_answer: String,
+    pub text_hint: String,
+    pub select_at_least: String,
+    pub select_at_most: String,
+    pub nps_min_label: String,
+    pub ranking_hint: String,
+    pub keep_order: String,
+    pub nps_max_label: String,
+    pub prev: String,
+    pub next: String,
+    pub submit: String,
+    pub thank_you: String,
+    pub thank_you_description: String,
+    pub already_answered: String,
+    pub closed: String,
+    pub quota_full: String,
+    pub not_found: String,
+    pub failed: String,
+}
+
+pub fn translate(lang: Language) -> RespondTranslate {
+    match lang {
+        Language::En => RespondTranslate {
+            loading: "Loading the survey...".to_string(),
+            required: "Required".to_string(),
+            invalid_answer: "Please answer this question.".to_string(),
+            text_hint: "Please enter your answer.".to_string(),
+            select_at_least: "Select at least {n}".to_string(),
+            select_at_most: "Select at most {n}".to_string(),
+            nps_min_label: "Not at all likely".to_string(),
+            ranking_hint: "Order the options from the most to the least preferred.".to_string(),
+            keep_order: "Keep this order".to_string(),
+            nps_max_label: "Extremely likely".to_string(),
+            prev: "Previous".to_string(),
+            next: "Next".to_string(),
+            submit: "Submit".to_string(),
+            thank_you: "Thank you for your response.".to_string(),
+            thank_you_description: "Your answers have been submitted.".to_string(),
+            already_answered: "You have already answered this survey.".to_string(),
+            closed: "This survey is closed.".to_string(),
+            quota_full: "This survey has collected all the responses it needs.".to_string(),
+            not_found: "The survey does not exist.".to_string(),
+            failed: "Failed to submit your answers. Please try again.".to_string(),
+        },
+        Language::Ko => RespondTranslate {
+            loading: "설문을 불러오는 중입니다...".to_string(),
+            required: "필수".to_string(),
+            invalid_answer: "이 질문에 답해주세요.".to_string(),
+            text_hint: "답변을 입력해주세요.".to_string(),
+            select_at_least: "최소 {n}개 선택".to_string(),
+            select_at_most: "최대 {n}개 선택".to_string(),
+            nps_min_label: "전혀 추천하지 않음".to_string(),
+            ranking_hint: "가장 선호하는 순서대로 선택지를 정렬해주세요.".to_string(),
+            keep_order: "이 순서로 응답".to_string(),
+            nps_max_label: "매우 추천함".to_string(),
+            prev: "이전".to_string(),
+            next: "다음".to_string(),
+            submit: "제출".to_string(),
+            thank_you: "응답해 주셔서 감사합니다.".to_string(),
+            thank_you_description: "답변이 제출되었습니다.".to_string(),
+            already_answered: "이미 응답한 설문입니다.".to_string(),
+            closed: "종료된 설문입니다.".to_string(),
+            quota_full: "목표 응답 수가 모두 채워진 설문입니다.".to_string(),
+            not_found: "존재하지 않는 설문입니다.".to_string(),
+            failed: "답변을 제출하지 못했습니다. 다시 시도해 주세요.".to_string(),
+        },
+    }
+}
+
+impl RespondTranslate {
+    // selection_hint describes the number of options to select of a multiple choice.
+    pub fn selection_hint(&self, min: Option<usize>, max: Option<usize>) -> Option<String> {
+        let hints: Vec<String> = [(&self.select_at_least, min), (&self.select_at_most, max)]
+            .into_iter()
+            .filter_map(|(text, n)| n.map(|n| text.replace("{n}", &n.to_string())))
+            .collect();
+
+        match hints.is_empty() {
+            true => None,
+            false => Some(hints.join(", ")),
+        }
+    }
+}
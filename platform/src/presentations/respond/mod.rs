@@ -0,0 +1,176 @@
+#![allow(non_snake_case)]
+use crate::{
+    api::{common::ApiError, v1::surveys::respond::FormStatus},
+    models::question::QuestionType,
+    prelude::*,
+};
+use dioxus::prelude::*;
+
+mod controller;
+mod i18n;
+mod question_input;
+
+use question_input::QuestionInput;
+
+#[derive(PartialEq, Props, Clone)]
+pub struct RespondPageProps {
+    lang: Language,
+    survey_id: String,
+}
+
+#[derive(PartialEq, Props, Clone)]
+pub struct MessageProps {
+    title: String,
+    #[props(default)]
+    description: String,
+}
+
+// RespondPage is the public page where respondents answer a survey, one question at a time.
+#[component]
+pub fn RespondPage(props: RespondPageProps) -> Element {
+    let mut ctrl = controller::Controller::init(props.survey_id.clone());
+    let translates = i18n::translate(props.lang.clone());
+    let logo_path = "/images/logo.png";
+
+    let body = if ctrl.get_submitted() {
+        rsx! {
+            Message { title: translates.thank_you, description: translates.thank_you_description }
+        }
+    } else if let Some(error) = ctrl.get_error() {
+        let title = match error {
+            ApiError::NotFound => translates.not_found,
+            ApiError::SurveyClosed => translates.closed,
+            ApiError::AlreadyAnswered => translates.already_answered,
+            _ => translates.failed,
+        };
+        rsx! {
+            Message { title }
+        }
+    } else if let Some(form) = ctrl.get_form() {
+        match (form.status, ctrl.get_question()) {
+            (FormStatus::QuotaFull, _) => rsx! {
+                Message { title: translates.quota_full }
+            },
+            (FormStatus::Closed, _) | (_, None) => rsx! {
+                Message { title: translates.closed }
+            },
+            (FormStatus::Open, Some(question)) => {
+                let step = ctrl.get_position();
+                let total = ctrl.get_total();
+                let progress = step * 100 / total.max(1);
+                let selection_hint = match question.question {
+                    QuestionType::MultipleChoice { min, max, .. } => {
+                        translates.selection_hint(min, max)
+                    }
+                    QuestionType::Ranking { .. } => Some(translates.ranking_hint.clone()),
+                    _ => None,
+                };
+
+                rsx! {
+                    div {
+                        class: "flex flex-col w-full max-w-[800px] justify-start items-start px-[30px] py-[40px]",
+                        div { class: "text-[#2168c3] font-semibold text-[30px] mb-[10px]", "{form.title}" }
+                        div { class: "text-[18px] font-normal text-[#4c4c4c] mb-[30px]", "{form.description}" }
+                        div {
+                            class: "flex flex-row w-full justify-between items-center mb-[10px] text-[16px] text-[#8a8a8a]",
+                            div { "{step} / {total}" }
+                            if question.required {
+                                div { class: "text-[#2168c3]", {translates.required.clone()} }
+                            }
+                        }
+                        div {
+                            class: "w-full h-[6px] rounded-[3px] bg-[#e0e0e0] mb-[30px]",
+                            div {
+                                class: "h-full rounded-[3px] bg-[#2168c3]",
+                                style: "width: {progress}%",
+                            }
+                        }
+                        div { class: "text-[24px] font-semibold text-black mb-[20px]", "{question.title}" }
+                        if let Some(selection_hint) = selection_hint {
+                            div { class: "text-[16px] font-normal text-[#8a8a8a] mb-[10px]", "{selection_hint}" }
+                        }
+                        QuestionInput {
+                            question: question.clone(),
+                            answer: ctrl.get_answer(),
+                            text_hint: translates.text_hint.clone(),
+                            nps_min_label: translates.nps_min_label.clone(),
+                            nps_max_label: translates.nps_max_label.clone(),
+                            keep_order: translates.keep_order.clone(),
+                            allowed: ctrl.get_allowed(),
+                            onchange: move |answer| ctrl.set_answer(answer),
+                        }
+                        if ctrl.get_invalid() {
+                            div {
+                                class: "text-[16px] font-normal text-[#ff0000] mt-[10px]",
+                                {translates.invalid_answer.clone()}
+                            }
+                        }
+                        div {
+                            class: "flex flex-row w-full justify-between items-center mt-[40px]",
+                            if step > 1 {
+                                div {
+                                    class: "flex flex-row w-[120px] h-[50px] justify-center items-center rounded-[5px] bg-[#424242] text-[20px] font-normal text-white cursor-pointer",
+                                    onclick: move |_| ctrl.prev(),
+                                    {translates.prev.clone()}
+                                }
+                            } else {
+                                div {}
+                            }
+                            if ctrl.is_last() {
+                                button {
+                                    class: "flex flex-row w-[120px] h-[50px] justify-center items-center rounded-[5px] bg-[#2168c3] text-[20px] font-normal text-white",
+                                    disabled: ctrl.get_submitting(),
+                                    onclick: move |_| async move {
+                                        ctrl.submit().await;
+                                    },
+                                    {translates.submit.clone()}
+                                }
+                            } else {
+                                div {
+                                    class: "flex flex-row w-[120px] h-[50px] justify-center items-center rounded-[5px] bg-[#2168c3] text-[20px] font-normal text-white cursor-pointer",
+                                    onclick: move |_| ctrl.next(),
+                                    {translates.next.clone()}
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    } else {
+        rsx! {
+            Message { title: translates.loading }
+        }
+    };
+
+    rsx! {
+        div { class: "bg-white w-screen min-h-screen flex flex-col",
+            div {
+                class: "flex flex-row w-full justify-start items-center px-[30px] py-[3px]",
+                div { class: "mr-[7px]",
+                    img {
+                        src: "{logo_path}",
+                        width: 42,
+                        height: 42
+                    }
+                }
+                div { class: "text-[24px] font-bold text-[#2168C3]", "VOICE KOREA" }
+            }
+            div {
+                class: "flex flex-col w-full justify-start items-center",
+                {body}
+            }
+        }
+    }
+}
+
+#[component]
+pub fn Message(props: MessageProps) -> Element {
+    rsx! {
+        div {
+            class: "flex flex-col w-full justify-center items-center pt-[160px]",
+            div { class: "text-[26px] font-bold text-black pb-[10px]", "{props.title}" }
+            div { class: "text-[20px] font-normal text-[#636363]", "{props.description}" }
+        }
+    }
+}
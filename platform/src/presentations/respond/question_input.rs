@@ -0,0 +1,440 @@
+#![allow(non_snake_case)]
+use dioxus::prelude::*;
+
+use crate::models::question::{
+    format_number, Question, QuestionAnswer, QuestionType, LONG_TEXT_MAX_LENGTH, TEXT_MAX_LENGTH,
+};
+
+// QuestionInput renders the input of a question type.
+// Blank text is reported as `None`, which skips an optional question;
+// other typed values are reported as typed and checked by `validate_answer`.
+// `allowed` limits the options of a choice question carrying forward an earlier one.
+#[component]
+pub fn QuestionInput(
+    question: Question,
+    answer: Option<QuestionAnswer>,
+    text_hint: String,
+    nps_min_label: String,
+    nps_max_label: String,
+    keep_order: String,
+    allowed: Option<Vec<usize>>,
+    onchange: EventHandler<Option<QuestionAnswer>>,
+) -> Element {
+    let text = |value: String, long: bool| match value.trim().is_empty() {
+        true => None,
+        false if long => Some(QuestionAnswer::LongText(value)),
+        false => Some(QuestionAnswer::Text(value)),
+    };
+
+    let typed = |value: String, answer: fn(String) -> QuestionAnswer| match value.trim().is_empty()
+    {
+        true => None,
+        false => Some(answer(value)),
+    };
+
+    let shows = |i: &usize| allowed.as_ref().map_or(true, |allowed| allowed.contains(i));
+    let question_id = question.id;
+
+    match question.question {
+        QuestionType::Text(hint) => {
+            let value = match answer {
+                Some(QuestionAnswer::Text(value)) => value,
+                _ => "".to_string(),
+            };
+            rsx! {
+                input {
+                    class: "w-full text-[20px] text-black font-normal p-[10px] border-b border-[#9f9f9f] outline-none",
+                    "type": "text",
+                    maxlength: TEXT_MAX_LENGTH as i64,
+                    placeholder: hint.unwrap_or(text_hint),
+                    value,
+                    oninput: move |e| onchange.call(text(e.value(), false)),
+                }
+            }
+        }
+        QuestionType::LongText(hint) => {
+            let value = match answer {
+                Some(QuestionAnswer::LongText(value)) => value,
+                _ => "".to_string(),
+            };
+            rsx! {
+                textarea {
+                    class: "w-full h-[200px] text-[18px] text-black font-normal p-[10px] border border-[#9f9f9f] rounded-[5px] outline-none",
+                    maxlength: LONG_TEXT_MAX_LENGTH as i64,
+                    placeholder: hint.unwrap_or(text_hint),
+                    value,
+                    oninput: move |e| onchange.call(text(e.value(), true)),
+                }
+            }
+        }
+        QuestionType::SingleChoice { question, options } => {
+            let selected = match answer {
+                Some(QuestionAnswer::SingleChoice(index)) => Some(index),
+                _ => None,
+            };
+            rsx! {
+                if let Some(question) = question {
+                    div { class: "text-[18px] font-normal text-[#4c4c4c] mb-[15px]", "{question}" }
+                }
+                div {
+                    class: "flex flex-col w-full",
+                    for (i, option) in options.into_iter().enumerate().filter(|(i, _)| shows(i)) {
+                        label {
+                            class: "flex flex-row w-full items-center py-[10px] text-[20px] font-normal text-black cursor-pointer",
+                            input {
+                                class: "mr-[12px] w-[20px] h-[20px]",
+                                "type": "radio",
+                                name: "{question_id}",
+                                checked: selected == Some(i),
+                                onchange: move |_| onchange.call(Some(QuestionAnswer::SingleChoice(i))),
+                            }
+                            "{option}"
+                        }
+                    }
+                }
+            }
+        }
+        QuestionType::MultipleChoice {
+            question,
+            options,
+            max,
+            ..
+        } => {
+            let selected = match answer {
+                Some(QuestionAnswer::MultipleChoice(selected)) => selected,
+                _ => vec![],
+            };
+            let full = max.map_or(false, |max| selected.len() >= max);
+            rsx! {
+                if let Some(question) = question {
+                    div { class: "text-[18px] font-normal text-[#4c4c4c] mb-[15px]", "{question}" }
+                }
+                div {
+                    class: "flex flex-col w-full",
+                    for (i, option) in options.into_iter().enumerate().filter(|(i, _)| shows(i)) {
+                        label {
+                            class: "flex flex-row w-full items-center py-[10px] text-[20px] font-normal text-black cursor-pointer",
+                            input {
+                                class: "mr-[12px] w-[20px] h-[20px]",
+                                "type": "checkbox",
+                                checked: selected.contains(&i),
+                                disabled: full && !selected.contains(&i),
+                                onchange: {
+                                    let selected = selected.clone();
+                                    move |_| {
+                                        let mut selected = selected.clone();
+                                        match selected.iter().position(|s| *s == i) {
+                                            Some(p) => {
+                                                selected.remove(p);
+                                            }
+                                            None => {
+                                                selected.push(i);
+                                                selected.sort();
+                                            }
+                                        }
+                                        onchange.call(match selected.is_empty() {
+                                            true => None,
+                                            false => Some(QuestionAnswer::MultipleChoice(selected)),
+                                        })
+                                    }
+                                },
+                            }
+                            "{option}"
+                        }
+                    }
+                }
+            }
+        }
+        QuestionType::Likert { .. } | QuestionType::Rating { .. } | QuestionType::Nps { .. } => {
+            let (min, max) = question.question.scale().unwrap_or_default();
+            let (min_label, max_label) = match &question.question {
+                QuestionType::Likert {
+                    min_label,
+                    max_label,
+                    ..
+                } => (min_label.clone(), max_label.clone()),
+                QuestionType::Nps { .. } => (nps_min_label, nps_max_label),
+                _ => ("".to_string(), "".to_string()),
+            };
+            let selected = answer.and_then(|a| a.scale_value());
+            let kind = question.question.clone();
+            rsx! {
+                if let Some(hint) = question.question.hint() {
+                    div { class: "text-[18px] font-normal text-[#4c4c4c] mb-[15px]", "{hint}" }
+                }
+                div {
+                    class: "flex flex-col w-full",
+                    div {
+                        class: "flex flex-row w-full justify-between",
+                        for value in min..=max {
+                            div {
+                                class: if selected == Some(value) {
+                                    "flex flex-1 h-[50px] mx-[2px] justify-center items-center rounded-[5px] bg-[#2168c3] text-[18px] font-medium text-white cursor-pointer"
+                                } else {
+                                    "flex flex-1 h-[50px] mx-[2px] justify-center items-center rounded-[5px] border border-[#9f9f9f] text-[18px] font-normal text-black cursor-pointer"
+                                },
+                                onclick: {
+                                    let kind = kind.clone();
+                                    move |_| onchange.call(kind.scale_answer(value))
+                                },
+                                "{value}"
+                            }
+                        }
+                    }
+                    div {
+                        class: "flex flex-row w-full justify-between mt-[8px] text-[15px] font-normal text-[#8a8a8a]",
+                        div { "{min_label}" }
+                        div { "{max_label}" }
+                    }
+                }
+            }
+        }
+        QuestionType::Matrix {
+            question,
+            rows,
+            columns,
+            multi,
+        } => {
+            let selected = match answer {
+                Some(QuestionAnswer::Matrix(selected)) if selected.len() == rows.len() => selected,
+                _ => vec![vec![]; rows.len()],
+            };
+            rsx! {
+                if let Some(question) = question {
+                    div { class: "text-[18px] font-normal text-[#4c4c4c] mb-[15px]", "{question}" }
+                }
+                table {
+                    class: "w-full text-[18px] font-normal text-black",
+                    thead {
+                        tr {
+                            th {}
+                            for column in columns.iter() {
+                                th { class: "px-[10px] py-[10px] text-[16px] font-medium text-[#4c4c4c]", "{column}" }
+                            }
+                        }
+                    }
+                    tbody {
+                        for (row, label) in rows.into_iter().enumerate() {
+                            tr {
+                                class: "border-t border-[#e0e0e0]",
+                                td { class: "py-[10px] pr-[10px]", "{label}" }
+                                for column in 0..columns.len() {
+                                    td {
+                                        class: "text-center",
+                                        input {
+                                            class: "w-[20px] h-[20px] cursor-pointer",
+                                            "type": if multi { "checkbox" } else { "radio" },
+                                            name: "{question_id}-{row}",
+                                            checked: selected[row].contains(&column),
+                                            onchange: {
+                                                let selected = selected.clone();
+                                                move |_| {
+                                                    let mut selected = selected.clone();
+                                                    let cells = &mut selected[row];
+                                                    match cells.iter().position(|c| *c == column) {
+                                                        Some(p) if multi => {
+                                                            cells.remove(p);
+                                                        }
+                                                        Some(_) => {}
+                                                        None if multi => {
+                                                            cells.push(column);
+                                                            cells.sort();
+                                                        }
+                                                        None => *cells = vec![column],
+                                                    }
+                                                    onchange.call(match selected.iter().all(|r| r.is_empty()) {
+                                                        true => None,
+                                                        false => Some(QuestionAnswer::Matrix(selected)),
+                                                    })
+                                                }
+                                            },
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        // the options start in their own order, which is not an answer until moved or kept
+        QuestionType::Ranking { question, options } => {
+            let answered = matches!(answer, Some(QuestionAnswer::Ranking(ref order)) if order.len() == options.len());
+            let order = match answer {
+                Some(QuestionAnswer::Ranking(order)) if answered => order,
+                _ => (0..options.len()).collect(),
+            };
+            let total = order.len();
+            let swap = move |order: &Vec<usize>, rank: usize, target: usize| {
+                let mut order = order.clone();
+                order.swap(rank, target);
+                Some(QuestionAnswer::Ranking(order))
+            };
+            rsx! {
+                if let Some(question) = question {
+                    div { class: "text-[18px] font-normal text-[#4c4c4c] mb-[15px]", "{question}" }
+                }
+                div {
+                    class: "flex flex-col w-full",
+                    for (rank, option) in order.clone().into_iter().enumerate() {
+                        div {
+                            class: "flex flex-row w-full items-center mb-[8px] px-[15px] py-[10px] rounded-[5px] border border-[#9f9f9f] text-[20px] font-normal text-black",
+                            div { class: "w-[40px] text-[#2168c3] font-semibold", "{rank + 1}" }
+                            div { class: "flex flex-1", "{options[option]}" }
+                            if rank > 0 {
+                                div {
+                                    class: "ml-[10px] px-[8px] cursor-pointer",
+                                    onclick: {
+                                        let order = order.clone();
+                                        move |_| onchange.call(swap(&order, rank, rank - 1))
+                                    },
+                                    "▲"
+                                }
+                            }
+                            if rank + 1 < total {
+                                div {
+                                    class: "ml-[10px] px-[8px] cursor-pointer",
+                                    onclick: {
+                                        let order = order.clone();
+                                        move |_| onchange.call(swap(&order, rank, rank + 1))
+                                    },
+                                    "▼"
+                                }
+                            }
+                        }
+                    }
+                    if !answered {
+                        div {
+                            class: "mt-[5px] text-[16px] font-medium text-[#2168c3] cursor-pointer",
+                            onclick: move |_| onchange.call(Some(QuestionAnswer::Ranking(order.clone()))),
+                            "{keep_order}"
+                        }
+                    }
+                }
+            }
+        }
+        QuestionType::Number {
+            question,
+            decimals,
+            min,
+            max,
+            step,
+        } => {
+            let value = match answer {
+                Some(QuestionAnswer::Number(value)) => value,
+                _ => "".to_string(),
+            };
+            rsx! {
+                if let Some(question) = question {
+                    div { class: "text-[18px] font-normal text-[#4c4c4c] mb-[15px]", "{question}" }
+                }
+                input {
+                    class: "w-[300px] text-[20px] text-black font-normal p-[10px] border-b border-[#9f9f9f] outline-none",
+                    "type": "number",
+                    min: min.unwrap_or_default(),
+                    max: max.unwrap_or_default(),
+                    step: step.unwrap_or(format_number(1, decimals)),
+                    value,
+                    oninput: move |e| onchange.call(typed(e.value(), QuestionAnswer::Number)),
+                }
+            }
+        }
+        QuestionType::Date { question, min, max } => {
+            let value = match answer {
+                Some(QuestionAnswer::Date(value)) => value,
+                _ => "".to_string(),
+            };
+            rsx! {
+                if let Some(question) = question {
+                    div { class: "text-[18px] font-normal text-[#4c4c4c] mb-[15px]", "{question}" }
+                }
+                input {
+                    class: "w-[300px] text-[20px] text-black font-normal p-[10px] border-b border-[#9f9f9f] outline-none",
+                    "type": "date",
+                    min: min.unwrap_or_default(),
+                    max: max.unwrap_or_default(),
+                    value,
+                    oninput: move |e| onchange.call(typed(e.value(), QuestionAnswer::Date)),
+                }
+            }
+        }
+        QuestionType::DateRange { question, min, max } => {
+            let (start, end) = match answer {
+                Some(QuestionAnswer::DateRange(start, end)) => (start, end),
+                _ => ("".to_string(), "".to_string()),
+            };
+            let range = move |start: String, end: String| match start.trim().is_empty()
+                && end.trim().is_empty()
+            {
+                true => None,
+                false => Some(QuestionAnswer::DateRange(start, end)),
+            };
+            let (min, max) = (min.unwrap_or_default(), max.unwrap_or_default());
+            rsx! {
+                if let Some(question) = question {
+                    div { class: "text-[18px] font-normal text-[#4c4c4c] mb-[15px]", "{question}" }
+                }
+                div {
+                    class: "flex flex-row items-center text-[20px] text-black font-normal",
+                    input {
+                        class: "w-[220px] p-[10px] border-b border-[#9f9f9f] outline-none",
+                        "type": "date",
+                        min: min.clone(),
+                        max: max.clone(),
+                        value: start.clone(),
+                        oninput: {
+                            let end = end.clone();
+                            move |e: FormEvent| onchange.call(range(e.value(), end.clone()))
+                        },
+                    }
+                    div { class: "mx-[15px]", "~" }
+                    input {
+                        class: "w-[220px] p-[10px] border-b border-[#9f9f9f] outline-none",
+                        "type": "date",
+                        min: if start.is_empty() { min } else { start.clone() },
+                        max,
+                        value: end,
+                        oninput: move |e| onchange.call(range(start.clone(), e.value())),
+                    }
+                }
+            }
+        }
+        QuestionType::Phone(ref hint) | QuestionType::Email(ref hint) => {
+            let hint = hint.clone();
+            let (input_type, kind): (&str, fn(String) -> QuestionAnswer) = match question.question {
+                QuestionType::Phone(_) => ("tel", QuestionAnswer::Phone),
+                _ => ("email", QuestionAnswer::Email),
+            };
+            let value = match answer {
+                Some(QuestionAnswer::Phone(value)) | Some(QuestionAnswer::Email(value)) => value,
+                _ => "".to_string(),
+            };
+            rsx! {
+                input {
+                    class: "w-full text-[20px] text-black font-normal p-[10px] border-b border-[#9f9f9f] outline-none",
+                    "type": input_type,
+                    maxlength: TEXT_MAX_LENGTH as i64,
+                    placeholder: hint.unwrap_or(text_hint),
+                    value,
+                    oninput: move |e| onchange.call(typed(e.value(), kind)),
+                }
+            }
+        }
+        QuestionType::Pattern { question, .. } => {
+            let value = match answer {
+                Some(QuestionAnswer::Text(value)) => value,
+                _ => "".to_string(),
+            };
+            rsx! {
+                input {
+                    class: "w-full text-[20px] text-black font-normal p-[10px] border-b border-[#9f9f9f] outline-none",
+                    "type": "text",
+                    maxlength: TEXT_MAX_LENGTH as i64,
+                    placeholder: question.unwrap_or(text_hint),
+                    value,
+                    oninput: move |e| onchange.call(text(e.value(), false)),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,53 @@
+#![allow(non_snake_case)]
+use dioxus::prelude::*;
+use dioxus_logger::tracing;
+
+use crate::{
+    api::{common::ApiError, v1::surveys::results::get_survey_results},
+    models::result::SurveyResult,
+};
+
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub struct Controller {
+    result: Signal<Option<SurveyResult>>,
+    error: Signal<Option<ApiError>>,
+}
+
+impl Controller {
+    pub fn init(survey_id: String) -> Self {
+        let mut ctrl = Self {
+            result: use_signal(|| None),
+            error: use_signal(|| None),
+        };
+
+        let _ = use_effect(move || {
+            let survey_id = survey_id.clone();
+            spawn(async move {
+                match get_survey_results(survey_id).await {
+                    Ok(res) => {
+                        ctrl.error.set(None);
+                        ctrl.result.set(Some(res));
+                    }
+                    Err(ServerFnError::WrappedServerError(e)) => {
+                        ctrl.error.set(Some(e));
+                    }
+                    Err(e) => {
+                        tracing::error!("Error: {:?}", e);
+                        ctrl.error
+                            .set(Some(ApiError::InternalServerError(e.to_string())));
+                    }
+                }
+            });
+        });
+
+        ctrl
+    }
+
+    pub fn get_result(&self) -> Option<SurveyResult> {
+        (self.result)()
+    }
+
+    pub fn get_error(&self) -> Option<ApiError> {
+        (self.error)()
+    }
+}
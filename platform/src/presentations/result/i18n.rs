@@ -0,0 +1,70 @@
+use crate::utils::context::Language;
+
+pub struct ResultTranslate {
+    pub loading: String,
+    pub responses: String,
+    pub answered: String,
+    pub no_answers: String,
+    pub mean: String,
+    pub median: String,
+    pub min: String,
+    pub max: String,
+    pub nps_score: String,
+    pub promoters: String,
+    pub passives: String,
+    pub detractors: String,
+    pub option: String,
+    pub borda_count: String,
+    pub average_rank: String,
+    pub back_label: String,
+    pub not_found: String,
+    pub forbidden: String,
+    pub failed: String,
+}
+
+pub fn translate(lang: Language) -> ResultTranslate {
+    match lang {
+        Language::En => ResultTranslate {
+            loading: "Loading the results...".to_string(),
+            responses: "Responses".to_string(),
+            answered: " answered".to_string(),
+            no_answers: "No answers yet.".to_string(),
+            mean: "Mean".to_string(),
+            median: "Median".to_string(),
+            min: "Min".to_string(),
+            max: "Max".to_string(),
+            nps_score: "NPS".to_string(),
+            promoters: "Promoters".to_string(),
+            passives: "Passives".to_string(),
+            detractors: "Detractors".to_string(),
+            option: "Option".to_string(),
+            borda_count: "Borda count".to_string(),
+            average_rank: "Average rank".to_string(),
+            back_label: "Go Back".to_string(),
+            not_found: "The questionnaire does not exist.".to_string(),
+            forbidden: "You do not have permission to view the results.".to_string(),
+            failed: "Failed to load the results. Please try again.".to_string(),
+        },
+        Language::Ko => ResultTranslate {
+            loading: "결과를 불러오는 중입니다...".to_string(),
+            responses: "응답 수".to_string(),
+            answered: "명 응답".to_string(),
+            no_answers: "아직 응답이 없습니다.".to_string(),
+            mean: "평균".to_string(),
+            median: "중앙값".to_string(),
+            min: "최솟값".to_string(),
+            max: "최댓값".to_string(),
+            nps_score: "순추천지수".to_string(),
+            promoters: "추천 고객".to_string(),
+            passives: "중립 고객".to_string(),
+            detractors: "비추천 고객".to_string(),
+            option: "선택지".to_string(),
+            borda_count: "보다 점수".to_string(),
+            average_rank: "평균 순위".to_string(),
+            back_label: "돌아가기".to_string(),
+            not_found: "존재하지 않는 설문지입니다.".to_string(),
+            forbidden: "결과를 볼 권한이 없습니다.".to_string(),
+            failed: "결과를 불러오지 못했습니다. 다시 시도해 주세요.".to_string(),
+        },
+    }
+}
@@ -0,0 +1,73 @@
+#![allow(non_snake_case)]
+use crate::{api::common::ApiError, prelude::*};
+use dioxus::prelude::*;
+
+mod controller;
+mod i18n;
+mod question_result;
+
+use question_result::QuestionResultCard;
+
+#[derive(PartialEq, Props, Clone)]
+pub struct ResultPageProps {
+    lang: Language,
+    survey_id: String,
+}
+
+#[component]
+pub fn ResultPage(props: ResultPageProps) -> Element {
+    let ctrl = controller::Controller::init(props.survey_id.clone());
+    let translates = i18n::translate(props.lang.clone());
+
+    if let Some(error) = ctrl.get_error() {
+        return rsx! {
+            div {
+                class: "flex flex-col w-full h-full justify-center items-center pt-[160px] text-[20px] font-normal text-[#ff0000]",
+                match error {
+                    ApiError::NotFound => translates.not_found,
+                    ApiError::Forbidden => translates.forbidden,
+                    _ => translates.failed,
+                }
+            }
+        };
+    }
+
+    let Some(result) = ctrl.get_result() else {
+        return rsx! {
+            div {
+                class: "flex flex-col w-full h-full justify-center items-center pt-[160px] text-[20px] font-normal text-black",
+                "{translates.loading}"
+            }
+        };
+    };
+
+    rsx! {
+        div {
+            class: "flex flex-col w-full h-full justify-start items-center",
+            div {
+                class: "flex flex-col max-w-[1200px] min-w-[600px] w-full justify-start items-start mt-[15px] px-[50px]",
+                div {
+                    class: "flex flex-row w-full h-[110px] rounded-[10px] bg-white mb-[10px] items-center justify-between px-[30px]",
+                    div { class: "text-[#2168c3] font-semibold text-[30px]", "{result.survey.title}" }
+                    div {
+                        class: "text-[20px] font-normal text-[#4c4c4c]",
+                        "{translates.responses} {result.responses}"
+                    }
+                }
+                for (index, question) in result.questions.into_iter().enumerate() {
+                    QuestionResultCard { lang: props.lang, index, result: question }
+                }
+                div {
+                    class: "flex flex-row w-full justify-end items-end mb-[30px]",
+                    Link {
+                        to: Route::DashboardPage { lang: props.lang },
+                        div {
+                            class: "flex flex-row justify-center items-center w-[115px] h-[50px] rounded-[10px] bg-[#434343] text-white font-medium text-[20px]",
+                            "{translates.back_label}"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,180 @@
+#![allow(non_snake_case)]
+use dioxus::prelude::*;
+
+use super::i18n;
+use crate::{
+    models::result::{AnswerSummary, QuestionResult},
+    prelude::*,
+};
+
+// QuestionResultCard shows the summary of the answers to a question.
+#[component]
+pub fn QuestionResultCard(lang: Language, index: usize, result: QuestionResult) -> Element {
+    let translates = i18n::translate(lang);
+    let answered = result.answered;
+
+    rsx! {
+        div {
+            class: "flex flex-col w-full rounded-[10px] bg-white px-[30px] py-[25px] mb-[20px]",
+            div {
+                class: "flex flex-row w-full justify-between items-center mb-[15px]",
+                div { class: "text-[22px] font-semibold text-black", "Q{index + 1}. {result.title}" }
+                div { class: "text-[16px] font-normal text-[#8a8a8a]", "{answered}{translates.answered}" }
+            }
+            if answered == 0 {
+                div { class: "text-[16px] font-normal text-[#8a8a8a]", "{translates.no_answers}" }
+            } else {
+                match result.summary {
+                    AnswerSummary::Text(answers) => rsx! {
+                        div {
+                            class: "flex flex-col w-full max-h-[300px] overflow-y-auto",
+                            for answer in answers {
+                                div {
+                                    class: "w-full py-[8px] border-b border-[#e0e0e0] text-[16px] font-normal text-[#4c4c4c]",
+                                    "{answer}"
+                                }
+                            }
+                        }
+                    },
+                    AnswerSummary::Choice { options, counts } => rsx! {
+                        for (option, count) in options.into_iter().zip(counts) {
+                            Bar { label: option, count, total: answered }
+                        }
+                    },
+                    AnswerSummary::Scale { min, max, min_label, max_label, counts, mean } => rsx! {
+                        Statistic { label: translates.mean.clone(), value: format!("{mean:.2}") }
+                        for (i, count) in counts.into_iter().enumerate() {
+                            Bar { label: scale_label(min + i as u8, min, max, &min_label, &max_label), count, total: answered }
+                        }
+                    },
+                    AnswerSummary::Nps { counts, mean, promoters, passives, detractors, score } => rsx! {
+                        div {
+                            class: "flex flex-row w-full",
+                            Statistic { label: translates.nps_score.clone(), value: format!("{score:.1}") }
+                            Statistic { label: translates.mean.clone(), value: format!("{mean:.2}") }
+                            Statistic { label: translates.promoters.clone(), value: promoters.to_string() }
+                            Statistic { label: translates.passives.clone(), value: passives.to_string() }
+                            Statistic { label: translates.detractors.clone(), value: detractors.to_string() }
+                        }
+                        for (value, count) in counts.into_iter().enumerate() {
+                            Bar { label: value.to_string(), count, total: answered }
+                        }
+                    },
+                    AnswerSummary::Matrix { rows, columns, counts } => rsx! {
+                        table {
+                            class: "w-full text-[16px] font-normal text-[#4c4c4c]",
+                            thead {
+                                tr {
+                                    th {}
+                                    for column in columns {
+                                        th { class: "px-[10px] py-[8px] font-medium", "{column}" }
+                                    }
+                                }
+                            }
+                            tbody {
+                                for (row, counts) in rows.into_iter().zip(counts) {
+                                    tr {
+                                        class: "border-t border-[#e0e0e0]",
+                                        td { class: "py-[8px] pr-[10px]", "{row}" }
+                                        for count in counts {
+                                            td {
+                                                class: "px-[10px] py-[8px] text-center",
+                                                "{count} ({percent(count, answered)}%)"
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    },
+                    AnswerSummary::Number { mean, median, min, max, histogram } => rsx! {
+                        div {
+                            class: "flex flex-row w-full",
+                            Statistic { label: translates.mean.clone(), value: format!("{mean:.2}") }
+                            Statistic { label: translates.median.clone(), value: format!("{median:.2}") }
+                            Statistic { label: translates.min.clone(), value: min }
+                            Statistic { label: translates.max.clone(), value: max }
+                        }
+                        for bin in histogram {
+                            Bar {
+                                label: if bin.start == bin.end { bin.start.clone() } else { format!("{} ~ {}", bin.start, bin.end) },
+                                count: bin.count,
+                                total: answered,
+                            }
+                        }
+                    },
+                    AnswerSummary::Ranking { options, borda, average_rank } => rsx! {
+                        table {
+                            class: "w-full text-[16px] font-normal text-[#4c4c4c]",
+                            thead {
+                                tr {
+                                    th { class: "py-[8px] text-left font-medium", "{translates.option}" }
+                                    th { class: "px-[10px] py-[8px] font-medium", "{translates.borda_count}" }
+                                    th { class: "px-[10px] py-[8px] font-medium", "{translates.average_rank}" }
+                                }
+                            }
+                            tbody {
+                                for (i, ((option, borda), rank)) in options.into_iter().zip(borda).zip(average_rank).enumerate() {
+                                    tr {
+                                        class: "border-t border-[#e0e0e0]",
+                                        td { class: "py-[8px] pr-[10px]", "{i + 1}. {option}" }
+                                        td { class: "px-[10px] py-[8px] text-center", "{borda}" }
+                                        td { class: "px-[10px] py-[8px] text-center", {format!("{rank:.2}")} }
+                                    }
+                                }
+                            }
+                        }
+                    },
+                }
+            }
+        }
+    }
+}
+
+// scale_label names a point of a scale, with the labels of its ends.
+fn scale_label(value: u8, min: u8, max: u8, min_label: &str, max_label: &str) -> String {
+    match value {
+        v if v == min && !min_label.is_empty() => format!("{v} ({min_label})"),
+        v if v == max && !max_label.is_empty() => format!("{v} ({max_label})"),
+        v => v.to_string(),
+    }
+}
+
+fn percent(count: u64, total: u64) -> u64 {
+    match total {
+        0 => 0,
+        total => count * 100 / total,
+    }
+}
+
+#[component]
+pub fn Statistic(label: String, value: String) -> Element {
+    rsx! {
+        div {
+            class: "flex flex-col items-start mr-[40px] mb-[15px]",
+            div { class: "text-[15px] font-normal text-[#8a8a8a]", "{label}" }
+            div { class: "text-[24px] font-semibold text-[#2168c3]", "{value}" }
+        }
+    }
+}
+
+// Bar shows `count` out of `total` as a horizontal bar with its percentage.
+#[component]
+pub fn Bar(label: String, count: u64, total: u64) -> Element {
+    let percent = percent(count, total);
+
+    rsx! {
+        div {
+            class: "flex flex-row w-full items-center mb-[8px] text-[16px] font-normal text-[#4c4c4c]",
+            div { class: "w-[200px] mr-[15px] truncate", "{label}" }
+            div {
+                class: "flex flex-1 h-[20px] rounded-[3px] bg-[#e0e0e0] mr-[15px]",
+                div {
+                    class: "h-full rounded-[3px] bg-[#2168c3]",
+                    style: "width: {percent}%",
+                }
+            }
+            div { class: "w-[100px] text-right", "{count} ({percent}%)" }
+        }
+    }
+}
@@ -2,36 +2,532 @@
 use dioxus::prelude::*;
 use dioxus_logger::tracing;
 
-use crate::api::v1::surveys::{get_survey, GetSurveyResponse};
+use crate::{
+    api::{
+        common::ApiError,
+        v1::surveys::{
+            get_survey,
+            questions::{save_questions, QuestionDraft, SaveQuestionsRequest},
+            GetSurveyResponse,
+        },
+    },
+    models::question::{
+        carry_options, renumber_question, Condition, Jump, JumpTarget, QuestionType, Rule,
+    },
+};
 
 #[derive(Debug, Clone, PartialEq, Copy)]
 pub struct Controller {
     survey: Signal<GetSurveyResponse>,
+    questions: Signal<Vec<QuestionDraft>>,
+    // the questions as last loaded or saved, to detect unsaved changes
+    saved: Signal<Vec<QuestionDraft>>,
+    confirm_leave: Signal<bool>,
+    error: Signal<Option<ApiError>>,
+    save_error: Signal<Option<ApiError>>,
 }
 
 impl Controller {
-    pub fn init(_title: String) -> Self {
+    pub fn init(survey_id: String) -> Self {
         let mut ctrl = Self {
             survey: use_signal(|| GetSurveyResponse::default()),
+            questions: use_signal(|| vec![]),
+            saved: use_signal(|| vec![]),
+            confirm_leave: use_signal(|| false),
+            error: use_signal(|| None),
+            save_error: use_signal(|| None),
         };
 
+        use_context_provider(|| ctrl);
+
         let _ = use_effect(move || {
+            let survey_id = survey_id.clone();
             spawn(async move {
-                match get_survey().await {
+                match get_survey(survey_id).await {
                     Ok(res) => {
+                        let questions: Vec<QuestionDraft> = res
+                            .questions
+                            .iter()
+                            .cloned()
+                            .map(QuestionDraft::from)
+                            .collect();
+                        ctrl.error.set(None);
+                        ctrl.questions.set(questions.clone());
+                        ctrl.saved.set(questions);
                         ctrl.survey.set(res);
                     }
-                    Err(e) => {
-                        tracing::error!("Error: {:?}", e);
-                    }
+                    Err(e) => ctrl.error.set(Some(Self::api_error(e))),
                 }
             });
         });
 
+        // ask the browser to confirm leaving the page with unsaved changes
+        let _ = use_effect(move || {
+            let script = match ctrl.is_dirty() {
+                true => {
+                    "window.onbeforeunload = (e) => { e.preventDefault(); e.returnValue = ''; };"
+                }
+                false => "window.onbeforeunload = null;",
+            };
+            let _ = document::eval(script);
+        });
+        use_drop(|| {
+            let _ = document::eval("window.onbeforeunload = null;");
+        });
+
         ctrl
     }
 
     pub fn get_survey(&mut self) -> GetSurveyResponse {
         (self.survey)()
     }
+
+    pub fn get_questions(&self) -> Vec<QuestionDraft> {
+        (self.questions)()
+    }
+
+    pub fn get_error(&self) -> Option<ApiError> {
+        (self.error)()
+    }
+
+    pub fn get_save_error(&self) -> Option<ApiError> {
+        (self.save_error)()
+    }
+
+    pub fn is_dirty(&self) -> bool {
+        *self.questions.read() != *self.saved.read()
+    }
+
+    pub fn is_locked(&self) -> bool {
+        self.survey.read().survey.questions_locked()
+    }
+
+    pub fn get_confirm_leave(&self) -> bool {
+        (self.confirm_leave)()
+    }
+
+    pub fn set_confirm_leave(&mut self, confirm_leave: bool) {
+        self.confirm_leave.set(confirm_leave);
+    }
+
+    pub fn add_question(&mut self) {
+        self.questions.write().push(QuestionDraft {
+            id: None,
+            title: "".to_string(),
+            question: QuestionType::Text(None),
+            required: false,
+            logic: Default::default(),
+            carry_forward: None,
+        });
+    }
+
+    // renumber keeps the `{Qn}` references and carried forward options on the same questions,
+    // where `map` gives the new number of a question; see `renumber_question`.
+    fn renumber(questions: &mut [QuestionDraft], map: impl Fn(usize) -> Option<usize>) {
+        for q in questions.iter_mut() {
+            renumber_question(&mut q.title, &mut q.question, &mut q.carry_forward, &map);
+        }
+    }
+
+    // carry copies the carried forward options as the server stores them, see `carry_options`.
+    fn carry(questions: &mut [QuestionDraft]) {
+        carry_options(questions, |q| q.carry_forward, |q| &mut q.question);
+    }
+
+    pub fn remove_question(&mut self, index: usize) {
+        let mut questions = self.questions.write();
+        if index < questions.len() {
+            questions.remove(index);
+            let removed = index + 1;
+            Self::renumber(&mut questions, |n| match n.cmp(&removed) {
+                std::cmp::Ordering::Less => Some(n),
+                std::cmp::Ordering::Equal => None,
+                std::cmp::Ordering::Greater => Some(n - 1),
+            });
+        }
+    }
+
+    // move_question swaps the question at `index` with the one `offset` away.
+    pub fn move_question(&mut self, index: usize, offset: isize) {
+        let mut questions = self.questions.write();
+        let target = index as isize + offset;
+        if index < questions.len() && target >= 0 && (target as usize) < questions.len() {
+            let target = target as usize;
+            questions.swap(index, target);
+            let (a, b) = (index + 1, target + 1);
+            Self::renumber(&mut questions, |n| match n {
+                n if n == a => Some(b),
+                n if n == b => Some(a),
+                n => Some(n),
+            });
+            Self::carry(&mut questions);
+        }
+    }
+
+    pub fn set_title(&mut self, index: usize, title: String) {
+        if let Some(q) = self.questions.write().get_mut(index) {
+            q.title = title;
+        }
+    }
+
+    pub fn set_required(&mut self, index: usize, required: bool) {
+        if let Some(q) = self.questions.write().get_mut(index) {
+            q.required = required;
+        }
+    }
+
+    pub fn set_kind(&mut self, index: usize, kind: String) {
+        if let Some(q) = self.questions.write().get_mut(index) {
+            if let Some(question) = q.question.convert(&kind) {
+                q.question = question;
+            }
+            // only choice questions carry forward options
+            if !matches!(
+                q.question,
+                QuestionType::SingleChoice { .. } | QuestionType::MultipleChoice { .. }
+            ) {
+                q.carry_forward = None;
+            }
+        }
+        Self::carry(&mut self.questions.write());
+    }
+
+    // set_carry_forward limits the options to the ones selected in the question numbered `source`,
+    // copying its options; None lets the question keep its own options.
+    pub fn set_carry_forward(&mut self, index: usize, source: Option<u32>) {
+        let mut questions = self.questions.write();
+        if let Some(q) = questions.get_mut(index) {
+            q.carry_forward = source;
+        }
+        Self::carry(&mut questions);
+    }
+
+    pub fn set_hint(&mut self, index: usize, value: String) {
+        let value = match value.trim().is_empty() {
+            true => None,
+            false => Some(value),
+        };
+        if let Some(q) = self.questions.write().get_mut(index) {
+            *q.question.hint_mut() = value;
+        }
+    }
+
+    pub fn add_option(&mut self, index: usize) {
+        if let Some(options) = self
+            .questions
+            .write()
+            .get_mut(index)
+            .and_then(|q| q.question.options_mut())
+        {
+            options.push("".to_string());
+        }
+        Self::carry(&mut self.questions.write());
+    }
+
+    pub fn set_option(&mut self, index: usize, option: usize, value: String) {
+        if let Some(o) = self
+            .questions
+            .write()
+            .get_mut(index)
+            .and_then(|q| q.question.options_mut())
+            .and_then(|options| options.get_mut(option))
+        {
+            *o = value;
+        }
+        Self::carry(&mut self.questions.write());
+    }
+
+    pub fn remove_option(&mut self, index: usize, option: usize) {
+        if let Some(options) = self
+            .questions
+            .write()
+            .get_mut(index)
+            .and_then(|q| q.question.options_mut())
+        {
+            if option < options.len() {
+                options.remove(option);
+            }
+        }
+        Self::carry(&mut self.questions.write());
+    }
+
+    // set_selection_limits sets the number of options to select; blank inputs clear a limit.
+    pub fn set_selection_limits(&mut self, index: usize, min: Option<usize>, max: Option<usize>) {
+        if let Some(QuestionType::MultipleChoice {
+            min: current_min,
+            max: current_max,
+            ..
+        }) = self
+            .questions
+            .write()
+            .get_mut(index)
+            .map(|q| &mut q.question)
+        {
+            *current_min = min;
+            *current_max = max;
+        }
+    }
+
+    // set_points sets the number of points of a likert scale or the maximum of a rating.
+    pub fn set_points(&mut self, index: usize, value: u8) {
+        if let Some(q) = self.questions.write().get_mut(index) {
+            match &mut q.question {
+                QuestionType::Likert { points, .. } => *points = value,
+                QuestionType::Rating { max, .. } => *max = value,
+                _ => {}
+            }
+        }
+    }
+
+    // set_scale_label sets the label of the lowest or the highest point of a likert scale.
+    pub fn set_scale_label(&mut self, index: usize, highest: bool, label: String) {
+        if let Some(QuestionType::Likert {
+            min_label,
+            max_label,
+            ..
+        }) = self
+            .questions
+            .write()
+            .get_mut(index)
+            .map(|q| &mut q.question)
+        {
+            match highest {
+                true => *max_label = label,
+                false => *min_label = label,
+            }
+        }
+    }
+
+    // matrix_items returns the rows, or the columns when `column`, of a matrix question.
+    fn matrix_items(question: &mut QuestionType, column: bool) -> Option<&mut Vec<String>> {
+        match question {
+            QuestionType::Matrix { rows, .. } if !column => Some(rows),
+            QuestionType::Matrix { columns, .. } => Some(columns),
+            _ => None,
+        }
+    }
+
+    pub fn add_matrix_item(&mut self, index: usize, column: bool) {
+        if let Some(items) = self
+            .questions
+            .write()
+            .get_mut(index)
+            .and_then(|q| Self::matrix_items(&mut q.question, column))
+        {
+            items.push("".to_string());
+        }
+    }
+
+    pub fn set_matrix_item(&mut self, index: usize, column: bool, item: usize, value: String) {
+        if let Some(i) = self
+            .questions
+            .write()
+            .get_mut(index)
+            .and_then(|q| Self::matrix_items(&mut q.question, column))
+            .and_then(|items| items.get_mut(item))
+        {
+            *i = value;
+        }
+    }
+
+    pub fn remove_matrix_item(&mut self, index: usize, column: bool, item: usize) {
+        if let Some(items) = self
+            .questions
+            .write()
+            .get_mut(index)
+            .and_then(|q| Self::matrix_items(&mut q.question, column))
+        {
+            if item < items.len() {
+                items.remove(item);
+            }
+        }
+    }
+
+    pub fn set_multi(&mut self, index: usize, value: bool) {
+        if let Some(QuestionType::Matrix { multi, .. }) = self
+            .questions
+            .write()
+            .get_mut(index)
+            .map(|q| &mut q.question)
+        {
+            *multi = value;
+        }
+    }
+
+    // set_range sets the bounds of a number or a date; blank inputs clear a bound.
+    pub fn set_range(&mut self, index: usize, min: Option<String>, max: Option<String>) {
+        let blank = |v: Option<String>| v.filter(|v| !v.trim().is_empty());
+        if let Some(q) = self.questions.write().get_mut(index) {
+            match &mut q.question {
+                QuestionType::Number {
+                    min: current_min,
+                    max: current_max,
+                    ..
+                }
+                | QuestionType::Date {
+                    min: current_min,
+                    max: current_max,
+                    ..
+                }
+                | QuestionType::DateRange {
+                    min: current_min,
+                    max: current_max,
+                    ..
+                } => {
+                    *current_min = blank(min);
+                    *current_max = blank(max);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    pub fn set_step(&mut self, index: usize, value: String) {
+        if let Some(QuestionType::Number { step, .. }) = self
+            .questions
+            .write()
+            .get_mut(index)
+            .map(|q| &mut q.question)
+        {
+            *step = Some(value).filter(|v| !v.trim().is_empty());
+        }
+    }
+
+    pub fn set_decimals(&mut self, index: usize, value: u8) {
+        if let Some(QuestionType::Number { decimals, .. }) = self
+            .questions
+            .write()
+            .get_mut(index)
+            .map(|q| &mut q.question)
+        {
+            *decimals = value;
+        }
+    }
+
+    pub fn set_pattern(&mut self, index: usize, value: String) {
+        if let Some(QuestionType::Pattern { pattern, .. }) = self
+            .questions
+            .write()
+            .get_mut(index)
+            .map(|q| &mut q.question)
+        {
+            *pattern = value;
+        }
+    }
+
+    // add_condition shows the question only if `question_id` is answered.
+    pub fn add_condition(&mut self, index: usize, question_id: String) {
+        if let Some(q) = self.questions.write().get_mut(index) {
+            q.logic.display.push(Condition {
+                question_id,
+                rule: Rule::Answered,
+            });
+        }
+    }
+
+    // set_condition_question changes the question a condition tests, resetting its rule.
+    pub fn set_condition_question(&mut self, index: usize, condition: usize, question_id: String) {
+        if let Some(c) = self
+            .questions
+            .write()
+            .get_mut(index)
+            .and_then(|q| q.logic.display.get_mut(condition))
+        {
+            c.question_id = question_id;
+            c.rule = Rule::Answered;
+        }
+    }
+
+    pub fn set_condition_rule(&mut self, index: usize, condition: usize, rule: Rule) {
+        if let Some(c) = self
+            .questions
+            .write()
+            .get_mut(index)
+            .and_then(|q| q.logic.display.get_mut(condition))
+        {
+            c.rule = rule;
+        }
+    }
+
+    pub fn remove_condition(&mut self, index: usize, condition: usize) {
+        if let Some(q) = self.questions.write().get_mut(index) {
+            if condition < q.logic.display.len() {
+                q.logic.display.remove(condition);
+            }
+        }
+    }
+
+    pub fn add_jump(&mut self, index: usize) {
+        if let Some(q) = self.questions.write().get_mut(index) {
+            q.logic.jumps.push(Jump {
+                rule: Rule::Answered,
+                to: JumpTarget::End,
+            });
+        }
+    }
+
+    pub fn set_jump_rule(&mut self, index: usize, jump: usize, rule: Rule) {
+        if let Some(j) = self
+            .questions
+            .write()
+            .get_mut(index)
+            .and_then(|q| q.logic.jumps.get_mut(jump))
+        {
+            j.rule = rule;
+        }
+    }
+
+    pub fn set_jump_target(&mut self, index: usize, jump: usize, to: JumpTarget) {
+        if let Some(j) = self
+            .questions
+            .write()
+            .get_mut(index)
+            .and_then(|q| q.logic.jumps.get_mut(jump))
+        {
+            j.to = to;
+        }
+    }
+
+    pub fn remove_jump(&mut self, index: usize, jump: usize) {
+        if let Some(q) = self.questions.write().get_mut(index) {
+            if jump < q.logic.jumps.len() {
+                q.logic.jumps.remove(jump);
+            }
+        }
+    }
+
+    pub async fn save(&mut self) -> bool {
+        let survey_id = self.survey.peek().survey.id.clone();
+        let req = SaveQuestionsRequest {
+            survey_id,
+            questions: self.get_questions(),
+        };
+
+        match save_questions(req).await {
+            Ok(questions) => {
+                let drafts: Vec<QuestionDraft> =
+                    questions.iter().cloned().map(QuestionDraft::from).collect();
+                self.save_error.set(None);
+                self.questions.set(drafts.clone());
+                self.saved.set(drafts);
+                self.survey.write().survey.questions = questions.len() as u64;
+                self.survey.write().questions = questions;
+                true
+            }
+            Err(e) => {
+                self.save_error.set(Some(Self::api_error(e)));
+                false
+            }
+        }
+    }
+
+    fn api_error(e: ServerFnError<ApiError>) -> ApiError {
+        match e {
+            ServerFnError::WrappedServerError(e) => e,
+            e => {
+                tracing::error!("Error: {:?}", e);
+                ApiError::InternalServerError(e.to_string())
+            }
+        }
+    }
 }
@@ -4,6 +4,78 @@ pub struct WriteQuestionTranslate {
     pub add_question: String,
     pub back_label: String,
     pub save_label: String,
+    pub not_found: String,
+    pub forbidden: String,
+    pub failed: String,
+    pub question_title_hint: String,
+    pub hint_placeholder: String,
+    pub text: String,
+    pub long_text: String,
+    pub single_choice: String,
+    pub multiple_choice: String,
+    pub min_selections: String,
+    pub max_selections: String,
+    pub likert: String,
+    pub rating: String,
+    pub nps: String,
+    pub matrix: String,
+    pub ranking: String,
+    pub integer: String,
+    pub decimal: String,
+    pub date: String,
+    pub date_range: String,
+    pub phone: String,
+    pub email: String,
+    pub pattern: String,
+    pub min_value: String,
+    pub max_value: String,
+    pub step: String,
+    pub decimals: String,
+    pub pattern_hint: String,
+    pub logic: String,
+    pub show_if: String,
+    pub add_condition: String,
+    pub jump_if: String,
+    pub go_to: String,
+    pub end_of_survey: String,
+    pub add_jump: String,
+    pub logic_needs_saved: String,
+    pub answered: String,
+    pub skipped: String,
+    pub selected: String,
+    pub not_selected: String,
+    pub at_least: String,
+    pub at_most: String,
+    pub invalid_logic: String,
+    pub carry_forward: String,
+    pub no_carry_forward: String,
+    pub carried_options: String,
+    pub piping_hint: String,
+    pub invalid_template: String,
+    pub rows: String,
+    pub columns: String,
+    pub row_hint: String,
+    pub column_hint: String,
+    pub add_row: String,
+    pub add_column: String,
+    pub multi_select: String,
+    pub points: String,
+    pub min_label_hint: String,
+    pub max_label_hint: String,
+    pub option_hint: String,
+    pub add_option: String,
+    pub required: String,
+    pub remove: String,
+    pub move_up: String,
+    pub move_down: String,
+    pub saved: String,
+    pub locked: String,
+    pub invalid_question: String,
+    pub save_failed: String,
+    pub conflict: String,
+    pub unsaved_changes: String,
+    pub leave: String,
+    pub stay: String,
 }
 
 pub fn translate(lang: Language) -> WriteQuestionTranslate {
@@ -12,11 +84,205 @@ pub fn translate(lang: Language) -> WriteQuestionTranslate {
             add_question: "Add Question".to_string(),
             back_label: "Go Back".to_string(),
             save_label: "Save".to_string(),
+            not_found: "The questionnaire does not exist.".to_string(),
+            forbidden: "You do not have permission to view this questionnaire.".to_string(),
+            failed: "Failed to load the questionnaire. Please try again.".to_string(),
+            question_title_hint: "Please enter the question.".to_string(),
+            hint_placeholder: "Description or placeholder (optional)".to_string(),
+            text: "Short answer".to_string(),
+            long_text: "Long answer".to_string(),
+            single_choice: "Single choice".to_string(),
+            multiple_choice: "Multiple choice".to_string(),
+            min_selections: "Select at least".to_string(),
+            max_selections: "Select at most".to_string(),
+            likert: "Likert scale".to_string(),
+            rating: "Rating".to_string(),
+            nps: "NPS (0-10)".to_string(),
+            matrix: "Matrix".to_string(),
+            ranking: "Ranking".to_string(),
+            integer: "Integer".to_string(),
+            decimal: "Decimal".to_string(),
+            date: "Date".to_string(),
+            date_range: "Date range".to_string(),
+            phone: "Phone number".to_string(),
+            email: "Email".to_string(),
+            pattern: "Formatted text".to_string(),
+            min_value: "Min".to_string(),
+            max_value: "Max".to_string(),
+            step: "Step".to_string(),
+            decimals: "Decimal places".to_string(),
+            pattern_hint: "Regular expression the answer must match, e.g. [A-Z]{2}[0-9]{4}"
+                .to_string(),
+            logic: "Logic".to_string(),
+            show_if: "Show only if all of these hold".to_string(),
+            add_condition: "Add Condition".to_string(),
+            jump_if: "If the answer".to_string(),
+            go_to: "go to".to_string(),
+            end_of_survey: "End of survey".to_string(),
+            add_jump: "Add Jump".to_string(),
+            logic_needs_saved: "Save new questions to use them in logic.".to_string(),
+            answered: "is answered".to_string(),
+            skipped: "is skipped".to_string(),
+            selected: "selects".to_string(),
+            not_selected: "does not select".to_string(),
+            at_least: "is at least".to_string(),
+            at_most: "is at most".to_string(),
+            invalid_logic:
+                "A logic rule refers to a missing or later question, or its jumps loop back."
+                    .to_string(),
+            carry_forward: "Options".to_string(),
+            no_carry_forward: "Own options".to_string(),
+            carried_options: "Only the options selected in".to_string(),
+            piping_hint: "Write {Q1} in the title, description or options to show the answer to Q1."
+                .to_string(),
+            invalid_template:
+                "An answer reference {Qn} or carried forward options must come from an earlier question."
+                    .to_string(),
+            rows: "Rows".to_string(),
+            columns: "Columns".to_string(),
+            row_hint: "Row".to_string(),
+            column_hint: "Column".to_string(),
+            add_row: "Add Row".to_string(),
+            add_column: "Add Column".to_string(),
+            multi_select: "Allow several answers per row".to_string(),
+            points: "Points".to_string(),
+            min_label_hint: "Lowest, e.g. Strongly disagree".to_string(),
+            max_label_hint: "Highest, e.g. Strongly agree".to_string(),
+            option_hint: "Option".to_string(),
+            add_option: "Add Option".to_string(),
+            required: "Required".to_string(),
+            remove: "Delete".to_string(),
+            move_up: "Up".to_string(),
+            move_down: "Down".to_string(),
+            saved: "All changes are saved.".to_string(),
+            locked: "The questions can not be changed after responses were collected.".to_string(),
+            invalid_question:
+                "Every question needs a title, and a choice needs two or more options.".to_string(),
+            save_failed: "Failed to save the questions. Please try again.".to_string(),
+            conflict: "The questions were changed elsewhere. Reload the page to see the changes."
+                .to_string(),
+            unsaved_changes: "You have unsaved changes. Leave without saving?".to_string(),
+            leave: "Leave".to_string(),
+            stay: "Stay".to_string(),
         },
         Language::Ko => WriteQuestionTranslate {
             add_question: "질문 추가하기".to_string(),
             back_label: "돌아가기".to_string(),
             save_label: "저장".to_string(),
+            not_found: "존재하지 않는 설문지입니다.".to_string(),
+            forbidden: "설문지를 볼 권한이 없습니다.".to_string(),
+            failed: "설문지를 불러오지 못했습니다. 다시 시도해 주세요.".to_string(),
+            question_title_hint: "질문을 입력해주세요.".to_string(),
+            hint_placeholder: "설명 또는 안내 문구 (선택)".to_string(),
+            text: "단답형".to_string(),
+            long_text: "서술형".to_string(),
+            single_choice: "객관식".to_string(),
+            multiple_choice: "객관식 (복수 선택)".to_string(),
+            min_selections: "최소 선택".to_string(),
+            max_selections: "최대 선택".to_string(),
+            likert: "리커트 척도".to_string(),
+            rating: "평점".to_string(),
+            nps: "순추천지수 (0-10)".to_string(),
+            matrix: "행렬형".to_string(),
+            ranking: "순위형".to_string(),
+            integer: "정수".to_string(),
+            decimal: "소수".to_string(),
+            date: "날짜".to_string(),
+            date_range: "기간".to_string(),
+            phone: "전화번호".to_string(),
+            email: "이메일".to_string(),
+            pattern: "형식 지정 텍스트".to_string(),
+            min_value: "최솟값".to_string(),
+            max_value: "최댓값".to_string(),
+            step: "간격".to_string(),
+            decimals: "소수점 자릿수".to_string(),
+            pattern_hint: "답변이 일치해야 하는 정규식, 예: [A-Z]{2}[0-9]{4}".to_string(),
+            logic: "로직".to_string(),
+            show_if: "다음 조건을 모두 만족할 때만 표시".to_string(),
+            add_condition: "조건 추가".to_string(),
+            jump_if: "답변이".to_string(),
+            go_to: "이면 이동".to_string(),
+            end_of_survey: "설문 종료".to_string(),
+            add_jump: "이동 규칙 추가".to_string(),
+            logic_needs_saved: "새 질문은 저장한 후 로직에 사용할 수 있습니다.".to_string(),
+            answered: "응답함".to_string(),
+            skipped: "건너뜀".to_string(),
+            selected: "다음을 선택함".to_string(),
+            not_selected: "다음을 선택하지 않음".to_string(),
+            at_least: "다음 이상".to_string(),
+            at_most: "다음 이하".to_string(),
+            invalid_logic: "로직이 없거나 뒤에 있는 질문을 참조하거나, 이동 규칙이 순환합니다."
+                .to_string(),
+            carry_forward: "보기".to_string(),
+            no_carry_forward: "직접 입력한 보기".to_string(),
+            carried_options: "다음 질문에서 선택한 보기만".to_string(),
+            piping_hint: "제목, 설명 또는 보기에 {Q1}을 쓰면 Q1의 응답이 표시됩니다.".to_string(),
+            invalid_template: "응답 참조 {Qn}와 이어받는 보기는 앞에 있는 질문이어야 합니다."
+                .to_string(),
+            rows: "행".to_string(),
+            columns: "열".to_string(),
+            row_hint: "행".to_string(),
+            column_hint: "열".to_string(),
+            add_row: "행 추가".to_string(),
+            add_column: "열 추가".to_string(),
+            multi_select: "행마다 여러 개 선택 허용".to_string(),
+            points: "점수 범위".to_string(),
+            min_label_hint: "가장 낮은 점수, 예: 전혀 그렇지 않다".to_string(),
+            max_label_hint: "가장 높은 점수, 예: 매우 그렇다".to_string(),
+            option_hint: "선택지".to_string(),
+            add_option: "선택지 추가".to_string(),
+            required: "필수".to_string(),
+            remove: "삭제".to_string(),
+            move_up: "위로".to_string(),
+            move_down: "아래로".to_string(),
+            saved: "모든 변경 사항이 저장되었습니다.".to_string(),
+            locked: "응답이 수집된 후에는 질문을 변경할 수 없습니다.".to_string(),
+            invalid_question:
+                "모든 질문에 제목이 필요하며, 객관식은 두 개 이상의 선택지가 필요합니다."
+                    .to_string(),
+            save_failed: "질문을 저장하지 못했습니다. 다시 시도해 주세요.".to_string(),
+            conflict: "다른 곳에서 질문이 변경되었습니다. 페이지를 새로 고쳐 변경 사항을 확인해 주세요."
+                .to_string(),
+            unsaved_changes: "저장하지 않은 변경 사항이 있습니다. 저장하지 않고 나가시겠습니까?"
+                .to_string(),
+            leave: "나가기".to_string(),
+            stay: "머무르기".to_string(),
         },
     }
 }
+
+impl WriteQuestionTranslate {
+    // rule_label names a `Rule::kind`.
+    pub fn rule_label(&self, kind: &str) -> String {
+        match kind {
+            "skipped" => self.skipped.clone(),
+            "selected" => self.selected.clone(),
+            "not_selected" => self.not_selected.clone(),
+            "at_least" => self.at_least.clone(),
+            "at_most" => self.at_most.clone(),
+            _ => self.answered.clone(),
+        }
+    }
+
+    // kind_label names a `QuestionType::kind`.
+    pub fn kind_label(&self, kind: &str) -> String {
+        match kind {
+            "long_text" => self.long_text.clone(),
+            "single_choice" => self.single_choice.clone(),
+            "multiple_choice" => self.multiple_choice.clone(),
+            "likert" => self.likert.clone(),
+            "rating" => self.rating.clone(),
+            "nps" => self.nps.clone(),
+            "matrix" => self.matrix.clone(),
+            "ranking" => self.ranking.clone(),
+            "integer" => self.integer.clone(),
+            "decimal" => self.decimal.clone(),
+            "date" => self.date.clone(),
+            "date_range" => self.date_range.clone(),
+            "phone" => self.phone.clone(),
+            "email" => self.email.clone(),
+            "pattern" => self.pattern.clone(),
+            _ => self.text.clone(),
+        }
+    }
+}
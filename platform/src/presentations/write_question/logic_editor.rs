@@ -0,0 +1,237 @@
+#![allow(non_snake_case)]
+use dioxus::prelude::*;
+
+use super::{controller::Controller, i18n};
+use crate::{
+    api::v1::surveys::questions::QuestionDraft,
+    models::question::{JumpTarget, QuestionType, Rule},
+    prelude::*,
+};
+
+// LogicEditor edits when a question is shown, where to go after it and the options it carries forward.
+// Rules refer to questions by id, so only saved questions can be chosen;
+// carried forward options refer to the question number like `{Qn}` references.
+#[component]
+pub fn LogicEditor(lang: Language, index: usize, question: QuestionDraft, locked: bool) -> Element {
+    let mut ctrl: Controller = use_context();
+    let translates = i18n::translate(lang);
+    let questions = ctrl.get_questions();
+    let saved = |range: std::ops::Range<usize>| -> Vec<(usize, String, QuestionDraft)> {
+        questions
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| range.contains(i))
+            .filter_map(|(i, q)| q.id.clone().map(|id| (i, id, q.clone())))
+            .collect()
+    };
+    let earlier = saved(0..index);
+    let later = saved(index + 1..questions.len());
+    let unsaved = questions.iter().any(|q| q.id.is_none());
+    let first_earlier = earlier.first().map(|(_, id, _)| id.clone());
+    let choice = |q: &QuestionType| {
+        matches!(
+            q,
+            QuestionType::SingleChoice { .. } | QuestionType::MultipleChoice { .. }
+        )
+    };
+    // the earlier choice questions whose selected options can be carried forward
+    let sources: Vec<(usize, String)> = match choice(&question.question) {
+        true => questions
+            .iter()
+            .take(index)
+            .enumerate()
+            .filter(|(_, q)| choice(&q.question))
+            .map(|(i, q)| (i, q.title.clone()))
+            .collect(),
+        false => vec![],
+    };
+
+    rsx! {
+        div {
+            class: "flex flex-col w-full mt-[20px] pt-[15px] border-t border-[#e0e0e0] text-[16px] font-normal text-[#4c4c4c]",
+            div { class: "font-medium mb-[10px]", {translates.logic.clone()} }
+            if !sources.is_empty() || question.carry_forward.is_some() {
+                div {
+                    class: "flex flex-row w-full items-center mb-[5px]",
+                    div { class: "mr-[10px]", {translates.carry_forward.clone()} }
+                    select {
+                        class: "w-[400px] p-[5px] border border-[#9f9f9f] rounded-[5px]",
+                        disabled: locked,
+                        onchange: move |e| ctrl.set_carry_forward(index, e.value().parse::<u32>().ok()),
+                        option { value: "", selected: question.carry_forward.is_none(), {translates.no_carry_forward.clone()} }
+                        for (i, title) in sources {
+                            option {
+                                value: (i + 1).to_string(),
+                                selected: question.carry_forward == Some(i as u32 + 1),
+                                "{translates.carried_options} Q{i + 1}. {title}"
+                            }
+                        }
+                    }
+                }
+            }
+            if !question.logic.display.is_empty() {
+                div { class: "mb-[5px]", {translates.show_if.clone()} }
+            }
+            for (c, condition) in question.logic.display.iter().cloned().enumerate() {
+                div {
+                    class: "flex flex-row w-full items-center mb-[5px]",
+                    select {
+                        class: "w-[260px] mr-[10px] p-[5px] border border-[#9f9f9f] rounded-[5px]",
+                        disabled: locked,
+                        value: condition.question_id.clone(),
+                        onchange: move |e| ctrl.set_condition_question(index, c, e.value()),
+                        for (i, id, q) in earlier.iter().cloned() {
+                            option {
+                                value: id.clone(),
+                                selected: id == condition.question_id,
+                                "Q{i + 1}. {q.title}"
+                            }
+                        }
+                    }
+                    RuleInput {
+                        lang,
+                        question: earlier
+                            .iter()
+                            .find(|(_, id, _)| *id == condition.question_id)
+                            .map(|(_, _, q)| q.question.clone())
+                            .unwrap_or(QuestionType::Text(None)),
+                        rule: condition.rule,
+                        locked,
+                        onchange: move |rule| ctrl.set_condition_rule(index, c, rule),
+                    }
+                    if !locked {
+                        div {
+                            class: "ml-[10px] text-[#8a8a8a] cursor-pointer",
+                            onclick: move |_| ctrl.remove_condition(index, c),
+                            "✕"
+                        }
+                    }
+                }
+            }
+            for (j, jump) in question.logic.jumps.iter().cloned().enumerate() {
+                div {
+                    class: "flex flex-row w-full items-center mb-[5px]",
+                    div { class: "mr-[10px]", {translates.jump_if.clone()} }
+                    RuleInput {
+                        lang,
+                        question: question.question.clone(),
+                        rule: jump.rule,
+                        locked,
+                        onchange: move |rule| ctrl.set_jump_rule(index, j, rule),
+                    }
+                    div { class: "mx-[10px]", {translates.go_to.clone()} }
+                    select {
+                        class: "w-[260px] p-[5px] border border-[#9f9f9f] rounded-[5px]",
+                        disabled: locked,
+                        onchange: move |e| {
+                            let to = match e.value().as_str() {
+                                "" => JumpTarget::End,
+                                id => JumpTarget::Question(id.to_string()),
+                            };
+                            ctrl.set_jump_target(index, j, to);
+                        },
+                        option { value: "", selected: jump.to == JumpTarget::End, {translates.end_of_survey.clone()} }
+                        for (i, id, q) in later.iter().cloned() {
+                            option {
+                                value: id.clone(),
+                                selected: jump.to == JumpTarget::Question(id),
+                                "Q{i + 1}. {q.title}"
+                            }
+                        }
+                    }
+                    if !locked {
+                        div {
+                            class: "ml-[10px] text-[#8a8a8a] cursor-pointer",
+                            onclick: move |_| ctrl.remove_jump(index, j),
+                            "✕"
+                        }
+                    }
+                }
+            }
+            if !locked {
+                div {
+                    class: "flex flex-row items-center mt-[5px] font-medium text-[#2168c3]",
+                    if let Some(question_id) = first_earlier {
+                        div {
+                            class: "mr-[20px] cursor-pointer",
+                            onclick: move |_| ctrl.add_condition(index, question_id.clone()),
+                            {translates.add_condition.clone()}
+                        }
+                    }
+                    div {
+                        class: "cursor-pointer",
+                        onclick: move |_| ctrl.add_jump(index),
+                        {translates.add_jump.clone()}
+                    }
+                }
+                if unsaved {
+                    div { class: "mt-[5px] text-[14px] text-[#8a8a8a]", {translates.logic_needs_saved.clone()} }
+                }
+                div { class: "mt-[5px] text-[14px] text-[#8a8a8a]", {translates.piping_hint.clone()} }
+            }
+        }
+    }
+}
+
+// RuleInput edits a rule on the answers to `question`, with its option or bound.
+#[component]
+fn RuleInput(
+    lang: Language,
+    question: QuestionType,
+    rule: Rule,
+    locked: bool,
+    onchange: EventHandler<Rule>,
+) -> Element {
+    let translates = i18n::translate(lang);
+    let kind = rule.kind();
+    let options = question.options().cloned().unwrap_or_default();
+
+    rsx! {
+        select {
+            class: "w-[180px] mr-[10px] p-[5px] border border-[#9f9f9f] rounded-[5px]",
+            disabled: locked,
+            value: kind,
+            onchange: move |e| {
+                if let Some(rule) = Rule::new(&e.value()) {
+                    onchange.call(rule);
+                }
+            },
+            for k in Rule::kinds(&question) {
+                option { value: k, selected: k == kind, {translates.rule_label(k)} }
+            }
+        }
+        match rule {
+            Rule::Selected(selected) | Rule::NotSelected(selected) => rsx! {
+                select {
+                    class: "w-[200px] p-[5px] border border-[#9f9f9f] rounded-[5px]",
+                    disabled: locked,
+                    onchange: move |e| {
+                        let option = e.value().parse::<usize>().unwrap_or_default();
+                        onchange.call(match kind {
+                            "selected" => Rule::Selected(option),
+                            _ => Rule::NotSelected(option),
+                        });
+                    },
+                    for (i, option) in options.into_iter().enumerate() {
+                        option { value: i.to_string(), selected: i == selected, "{option}" }
+                    }
+                }
+            },
+            Rule::AtLeast(bound) | Rule::AtMost(bound) => rsx! {
+                input {
+                    class: "w-[100px] p-[5px] border border-[#9f9f9f] rounded-[5px]",
+                    "type": "text",
+                    disabled: locked,
+                    value: bound,
+                    oninput: move |e| {
+                        onchange.call(match kind {
+                            "at_least" => Rule::AtLeast(e.value()),
+                            _ => Rule::AtMost(e.value()),
+                        });
+                    },
+                }
+            },
+            Rule::Answered | Rule::Skipped => rsx! {},
+        }
+    }
+}
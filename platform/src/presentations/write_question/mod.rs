@@ -1,23 +1,59 @@
 #![allow(non_snake_case)]
-use crate::prelude::*;
+use crate::{api::common::ApiError, prelude::*};
 use dioxus::prelude::*;
 
 mod controller;
 mod i18n;
+mod logic_editor;
+mod question_card;
+
+use question_card::QuestionCard;
 
 #[derive(PartialEq, Props, Clone)]
 pub struct WriteQuestionProps {
     lang: Language,
-    title: String,
+    survey_id: String,
 }
 
 #[component]
 pub fn WriteQuestionPage(props: WriteQuestionProps) -> Element {
-    let mut ctrl = controller::Controller::init(props.title.clone());
+    let mut ctrl = controller::Controller::init(props.survey_id.clone());
     let translates = i18n::translate(props.lang.clone());
+    let navigator = use_navigator();
+    let lang = props.lang;
     let survey = ctrl.get_survey();
+    let questions = ctrl.get_questions();
+    let total = questions.len();
+    let locked = ctrl.is_locked();
+    let status_message = match ctrl.get_save_error() {
+        Some(ApiError::InvalidInput(field)) if field.ends_with(".logic.templates") => {
+            Some(translates.invalid_template.clone())
+        }
+        Some(ApiError::InvalidInput(field)) if field.contains(".logic") => {
+            Some(translates.invalid_logic.clone())
+        }
+        Some(ApiError::InvalidInput(_)) => Some(translates.invalid_question.clone()),
+        Some(ApiError::InvalidTransition) => Some(translates.locked.clone()),
+        Some(ApiError::Forbidden) => Some(translates.forbidden.clone()),
+        Some(ApiError::Conflict) => Some(translates.conflict.clone()),
+        Some(_) => Some(translates.save_failed.clone()),
+        None if locked => Some(translates.locked.clone()),
+        None => None,
+    };
+
+    if let Some(error) = ctrl.get_error() {
+        return rsx! {
+            div {
+                class: "flex flex-col w-full h-full justify-center items-center pt-[160px] text-[20px] font-normal text-[#ff0000]",
+                match error {
+                    ApiError::NotFound => translates.not_found,
+                    ApiError::Forbidden => translates.forbidden,
+                    _ => translates.failed,
+                }
+            }
+        };
+    }
 
-    //TODO: write question page
     rsx! {
         div {
             class: "flex flex-col w-full h-full justify-start items-center",
@@ -30,30 +66,85 @@ pub fn WriteQuestionPage(props: WriteQuestionProps) -> Element {
                         "{survey.survey.title}"
                     }
                 }
-                div {
-                    class: "flex flex-row w-full h-[90px] rounded-[10px] bg-white justify-center items-center mb-[30px]",
+                for (index, question) in questions.into_iter().enumerate() {
+                    QuestionCard {
+                        key: "{index}",
+                        lang,
+                        index,
+                        total,
+                        question,
+                        locked,
+                    }
+                }
+                if !locked {
                     div {
-                        class: "flex flex-row w-[200px] h-[50px] rounded-[20px] bg-[#d6d6d6] justify-center items-center",
-                        img {
-                            class: "flex flex-col pr-[10px]",
-                            src: "/images/add.png",
-                            alt: "add question",
+                        class: "flex flex-row w-full h-[90px] rounded-[10px] bg-white justify-center items-center mb-[30px]",
+                        div {
+                            class: "flex flex-row w-[200px] h-[50px] rounded-[20px] bg-[#d6d6d6] justify-center items-center cursor-pointer",
+                            onclick: move |_| ctrl.add_question(),
+                            img {
+                                class: "flex flex-col pr-[10px]",
+                                src: "/images/add.png",
+                                alt: "add question",
+                            }
+                            div {
+                                class: "text-[20px] font-medium text-black",
+                                "{translates.add_question}"
+                            }
+                        }
+                    }
+                }
+                if let Some(status_message) = status_message {
+                    div {
+                        class: "flex flex-row w-full justify-end items-start mb-[10px] text-[16px] font-normal text-[#ff0000]",
+                        "{status_message}"
+                    }
+                } else if !ctrl.is_dirty() && total > 0 {
+                    div {
+                        class: "flex flex-row w-full justify-end items-start mb-[10px] text-[16px] font-normal text-[#8a8a8a]",
+                        "{translates.saved}"
+                    }
+                }
+                if ctrl.get_confirm_leave() {
+                    div {
+                        class: "flex flex-row w-full justify-end items-center mb-[10px] text-[16px] font-normal text-black",
+                        "{translates.unsaved_changes}"
+                        div {
+                            class: "ml-[15px] text-[#ff0000] cursor-pointer",
+                            onclick: move |_| {
+                                ctrl.set_confirm_leave(false);
+                                navigator.push(Route::DashboardPage { lang });
+                            },
+                            "{translates.leave}"
                         }
                         div {
-                            class: "text-[20px] font-medium text-black",
-                            "{translates.add_question}"
+                            class: "ml-[15px] text-[#2168c3] cursor-pointer",
+                            onclick: move |_| ctrl.set_confirm_leave(false),
+                            "{translates.stay}"
                         }
                     }
                 }
                 div {
-                    class: "flex flex-row w-full justify-end items-end",
+                    class: "flex flex-row w-full justify-end items-end mb-[30px]",
                     div {
-                        class: "flex flex-row justify-center items-center w-[115px] h-[50px] rounded-[10px] bg-[#434343] text-white font-medium text-[20px] mr-[20px]",
+                        class: "flex flex-row justify-center items-center w-[115px] h-[50px] rounded-[10px] bg-[#434343] text-white font-medium text-[20px] mr-[20px] cursor-pointer",
+                        onclick: move |_| {
+                            if ctrl.is_dirty() {
+                                ctrl.set_confirm_leave(true);
+                            } else {
+                                navigator.push(Route::DashboardPage { lang });
+                            }
+                        },
                         "{translates.back_label}"
                     }
-                    div {
-                        class: "flex flex-row justify-center items-center w-[115px] h-[50px] rounded-[10px] bg-[#2168c3] text-white font-medium text-[20px] mr-[20px]",
-                        "{translates.save_label}"
+                    if !locked {
+                        div {
+                            class: "flex flex-row justify-center items-center w-[115px] h-[50px] rounded-[10px] bg-[#2168c3] text-white font-medium text-[20px] mr-[20px] cursor-pointer",
+                            onclick: move |_| async move {
+                                ctrl.save().await;
+                            },
+                            "{translates.save_label}"
+                        }
                     }
                 }
             }
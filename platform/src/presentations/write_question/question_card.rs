@@ -0,0 +1,350 @@
+#![allow(non_snake_case)]
+use dioxus::prelude::*;
+
+use super::{controller::Controller, i18n, logic_editor::LogicEditor};
+use crate::{
+    api::v1::surveys::questions::QuestionDraft,
+    models::question::{QuestionType, LIKERT_MAX_POINTS, NUMBER_MAX_DECIMALS, RATING_MAX},
+    prelude::*,
+};
+
+// QuestionCard edits one question of the survey.
+#[component]
+pub fn QuestionCard(
+    lang: Language,
+    index: usize,
+    total: usize,
+    question: QuestionDraft,
+    locked: bool,
+) -> Element {
+    let mut ctrl: Controller = use_context();
+    let translates = i18n::translate(lang);
+    let kind = question.question.kind();
+    let hint = question.question.hint().clone();
+    let options = question.question.options().cloned();
+    let limits = match question.question {
+        QuestionType::MultipleChoice { min, max, .. } => Some((min, max)),
+        _ => None,
+    };
+    // the bounds of a number or a date, with the input type to edit them
+    let range = match &question.question {
+        QuestionType::Number { min, max, .. } => Some(("text", min.clone(), max.clone())),
+        QuestionType::Date { min, max, .. } | QuestionType::DateRange { min, max, .. } => {
+            Some(("date", min.clone(), max.clone()))
+        }
+        _ => None,
+    };
+    let number = match &question.question {
+        QuestionType::Number { decimals, step, .. } => Some((*decimals, step.clone())),
+        _ => None,
+    };
+    let pattern = match &question.question {
+        QuestionType::Pattern { pattern, .. } => Some(pattern.clone()),
+        _ => None,
+    };
+    let ranking = matches!(question.question, QuestionType::Ranking { .. });
+    // carried forward options are copied from an earlier question, see `set_carry_forward`
+    let carried = question.carry_forward.is_some();
+    let parse = |value: String| value.trim().parse::<usize>().ok();
+    let (points, max_points) = match question.question {
+        QuestionType::Likert { points, .. } => (Some(points), LIKERT_MAX_POINTS),
+        QuestionType::Rating { max, .. } => (Some(max), RATING_MAX),
+        _ => (None, 0),
+    };
+    let labels = match &question.question {
+        QuestionType::Likert {
+            min_label,
+            max_label,
+            ..
+        } => Some((min_label.clone(), max_label.clone())),
+        _ => None,
+    };
+    let matrix = match &question.question {
+        QuestionType::Matrix {
+            rows,
+            columns,
+            multi,
+            ..
+        } => Some((rows.clone(), columns.clone(), *multi)),
+        _ => None,
+    };
+
+    rsx! {
+        div {
+            class: "flex flex-col w-full rounded-[10px] bg-white px-[30px] py-[25px] mb-[20px]",
+            div {
+                class: "flex flex-row w-full justify-between items-center mb-[15px]",
+                div { class: "text-[20px] font-semibold text-[#2168c3]", "Q{index + 1}" }
+                div {
+                    class: "flex flex-row items-center text-[16px] font-normal text-[#4c4c4c]",
+                    label {
+                        class: "flex flex-row items-center mr-[20px] cursor-pointer",
+                        input {
+                            class: "mr-[5px]",
+                            "type": "checkbox",
+                            disabled: locked,
+                            checked: question.required,
+                            onchange: move |e| ctrl.set_required(index, e.checked()),
+                        }
+                        {translates.required.clone()}
+                    }
+                    select {
+                        class: "mr-[20px] p-[5px] border border-[#9f9f9f] rounded-[5px]",
+                        disabled: locked,
+                        value: kind,
+                        onchange: move |e| ctrl.set_kind(index, e.value()),
+                        for k in QuestionType::KINDS {
+                            option { value: k, selected: k == kind, {translates.kind_label(k)} }
+                        }
+                    }
+                    if !locked {
+                        if index > 0 {
+                            div {
+                                class: "mr-[10px] cursor-pointer",
+                                onclick: move |_| ctrl.move_question(index, -1),
+                                {translates.move_up.clone()}
+                            }
+                        }
+                        if index + 1 < total {
+                            div {
+                                class: "mr-[10px] cursor-pointer",
+                                onclick: move |_| ctrl.move_question(index, 1),
+                                {translates.move_down.clone()}
+                            }
+                        }
+                        div {
+                            class: "text-[#ff0000] cursor-pointer",
+                            onclick: move |_| ctrl.remove_question(index),
+                            {translates.remove.clone()}
+                        }
+                    }
+                }
+            }
+            input {
+                class: "w-full text-[21px] text-black font-normal p-[5px] mb-[10px] border-b border-[#9f9f9f] outline-none",
+                "type": "text",
+                disabled: locked,
+                placeholder: translates.question_title_hint.clone(),
+                value: question.title.clone(),
+                oninput: move |e| ctrl.set_title(index, e.value()),
+            }
+            input {
+                class: "w-full text-[16px] text-[#8a8a8a] font-normal p-[5px] mb-[10px] border-b border-[#e0e0e0] outline-none",
+                "type": "text",
+                disabled: locked,
+                placeholder: translates.hint_placeholder.clone(),
+                value: hint.unwrap_or_default(),
+                oninput: move |e| ctrl.set_hint(index, e.value()),
+            }
+            if let Some(options) = options {
+                for (i, option) in options.into_iter().enumerate() {
+                    div {
+                        class: "flex flex-row w-full items-center mb-[5px]",
+                        if ranking {
+                            div { class: "w-[20px] mr-[10px] text-[16px] text-[#8a8a8a]", "{i + 1}." }
+                        } else if limits.is_some() {
+                            div { class: "w-[20px] h-[20px] mr-[10px] rounded-[3px] border border-[#9f9f9f]" }
+                        } else {
+                            div { class: "w-[20px] h-[20px] mr-[10px] rounded-full border border-[#9f9f9f]" }
+                        }
+                        input {
+                            class: "flex flex-1 text-[18px] text-black font-normal p-[5px] border-b border-[#e0e0e0] outline-none",
+                            "type": "text",
+                            disabled: locked || carried,
+                            placeholder: "{translates.option_hint} {i + 1}",
+                            value: option,
+                            oninput: move |e| ctrl.set_option(index, i, e.value()),
+                        }
+                        if !locked && !carried {
+                            div {
+                                class: "ml-[10px] text-[16px] text-[#8a8a8a] cursor-pointer",
+                                onclick: move |_| ctrl.remove_option(index, i),
+                                "✕"
+                            }
+                        }
+                    }
+                }
+                if !locked && !carried {
+                    div {
+                        class: "mt-[5px] text-[16px] font-medium text-[#2168c3] cursor-pointer",
+                        onclick: move |_| ctrl.add_option(index),
+                        {translates.add_option.clone()}
+                    }
+                }
+            }
+            if let Some((min, max)) = limits {
+                div {
+                    class: "flex flex-row w-full items-center mt-[15px] text-[16px] font-normal text-[#4c4c4c]",
+                    div { class: "mr-[10px]", {translates.min_selections.clone()} }
+                    input {
+                        class: "w-[70px] mr-[30px] p-[5px] border border-[#9f9f9f] rounded-[5px]",
+                        "type": "number",
+                        min: 0,
+                        disabled: locked,
+                        value: min.map(|v| v.to_string()).unwrap_or_default(),
+                        oninput: move |e| ctrl.set_selection_limits(index, parse(e.value()), max),
+                    }
+                    div { class: "mr-[10px]", {translates.max_selections.clone()} }
+                    input {
+                        class: "w-[70px] p-[5px] border border-[#9f9f9f] rounded-[5px]",
+                        "type": "number",
+                        min: 1,
+                        disabled: locked,
+                        value: max.map(|v| v.to_string()).unwrap_or_default(),
+                        oninput: move |e| ctrl.set_selection_limits(index, min, parse(e.value())),
+                    }
+                }
+            }
+            if let Some(points) = points {
+                div {
+                    class: "flex flex-row w-full items-center mt-[15px] text-[16px] font-normal text-[#4c4c4c]",
+                    div { class: "mr-[10px]", {translates.points.clone()} }
+                    input {
+                        class: "w-[70px] mr-[30px] p-[5px] border border-[#9f9f9f] rounded-[5px]",
+                        "type": "number",
+                        min: 2,
+                        max: max_points as i64,
+                        disabled: locked,
+                        value: points.to_string(),
+                        oninput: move |e| {
+                            if let Ok(points) = e.value().trim().parse::<u8>() {
+                                ctrl.set_points(index, points);
+                            }
+                        },
+                    }
+                    if let Some((min_label, max_label)) = labels {
+                        input {
+                            class: "w-[200px] mr-[10px] p-[5px] border border-[#9f9f9f] rounded-[5px]",
+                            "type": "text",
+                            disabled: locked,
+                            placeholder: translates.min_label_hint.clone(),
+                            value: min_label,
+                            oninput: move |e| ctrl.set_scale_label(index, false, e.value()),
+                        }
+                        input {
+                            class: "w-[200px] p-[5px] border border-[#9f9f9f] rounded-[5px]",
+                            "type": "text",
+                            disabled: locked,
+                            placeholder: translates.max_label_hint.clone(),
+                            value: max_label,
+                            oninput: move |e| ctrl.set_scale_label(index, true, e.value()),
+                        }
+                    }
+                }
+            }
+            if let Some((input_type, min, max)) = range {
+                div {
+                    class: "flex flex-row w-full items-center mt-[15px] text-[16px] font-normal text-[#4c4c4c]",
+                    div { class: "mr-[10px]", {translates.min_value.clone()} }
+                    input {
+                        class: "w-[160px] mr-[30px] p-[5px] border border-[#9f9f9f] rounded-[5px]",
+                        "type": input_type,
+                        disabled: locked,
+                        value: min.clone().unwrap_or_default(),
+                        oninput: {
+                            let max = max.clone();
+                            move |e: FormEvent| ctrl.set_range(index, Some(e.value()), max.clone())
+                        },
+                    }
+                    div { class: "mr-[10px]", {translates.max_value.clone()} }
+                    input {
+                        class: "w-[160px] mr-[30px] p-[5px] border border-[#9f9f9f] rounded-[5px]",
+                        "type": input_type,
+                        disabled: locked,
+                        value: max.unwrap_or_default(),
+                        oninput: move |e| ctrl.set_range(index, min.clone(), Some(e.value())),
+                    }
+                    if let Some((decimals, step)) = number {
+                        div { class: "mr-[10px]", {translates.step.clone()} }
+                        input {
+                            class: "w-[100px] mr-[30px] p-[5px] border border-[#9f9f9f] rounded-[5px]",
+                            "type": "text",
+                            disabled: locked,
+                            value: step.unwrap_or_default(),
+                            oninput: move |e| ctrl.set_step(index, e.value()),
+                        }
+                        if decimals > 0 {
+                            div { class: "mr-[10px]", {translates.decimals.clone()} }
+                            input {
+                                class: "w-[70px] p-[5px] border border-[#9f9f9f] rounded-[5px]",
+                                "type": "number",
+                                min: 1,
+                                max: NUMBER_MAX_DECIMALS as i64,
+                                disabled: locked,
+                                value: decimals.to_string(),
+                                oninput: move |e| {
+                                    if let Ok(decimals) = e.value().trim().parse::<u8>() {
+                                        ctrl.set_decimals(index, decimals.max(1));
+                                    }
+                                },
+                            }
+                        }
+                    }
+                }
+            }
+            if let Some(pattern) = pattern {
+                input {
+                    class: "w-full mt-[15px] text-[16px] text-black font-normal p-[5px] border border-[#9f9f9f] rounded-[5px] font-mono",
+                    "type": "text",
+                    disabled: locked,
+                    placeholder: translates.pattern_hint.clone(),
+                    value: pattern,
+                    oninput: move |e| ctrl.set_pattern(index, e.value()),
+                }
+            }
+            if let Some((rows, columns, multi)) = matrix {
+                label {
+                    class: "flex flex-row items-center mb-[15px] text-[16px] font-normal text-[#4c4c4c] cursor-pointer",
+                    input {
+                        class: "mr-[5px]",
+                        "type": "checkbox",
+                        disabled: locked,
+                        checked: multi,
+                        onchange: move |e| ctrl.set_multi(index, e.checked()),
+                    }
+                    {translates.multi_select.clone()}
+                }
+                div {
+                    class: "flex flex-row w-full",
+                    for (column, items) in [(false, rows), (true, columns)] {
+                        div {
+                            class: "flex flex-col flex-1 mr-[20px]",
+                            div {
+                                class: "text-[16px] font-medium text-[#4c4c4c] mb-[5px]",
+                                if column { {translates.columns.clone()} } else { {translates.rows.clone()} }
+                            }
+                            for (i, item) in items.into_iter().enumerate() {
+                                div {
+                                    class: "flex flex-row w-full items-center mb-[5px]",
+                                    input {
+                                        class: "flex flex-1 text-[18px] text-black font-normal p-[5px] border-b border-[#e0e0e0] outline-none",
+                                        "type": "text",
+                                        disabled: locked,
+                                        placeholder: if column { "{translates.column_hint} {i + 1}" } else { "{translates.row_hint} {i + 1}" },
+                                        value: item,
+                                        oninput: move |e| ctrl.set_matrix_item(index, column, i, e.value()),
+                                    }
+                                    if !locked {
+                                        div {
+                                            class: "ml-[10px] text-[16px] text-[#8a8a8a] cursor-pointer",
+                                            onclick: move |_| ctrl.remove_matrix_item(index, column, i),
+                                            "✕"
+                                        }
+                                    }
+                                }
+                            }
+                            if !locked {
+                                div {
+                                    class: "mt-[5px] text-[16px] font-medium text-[#2168c3] cursor-pointer",
+                                    onclick: move |_| ctrl.add_matrix_item(index, column),
+                                    if column { {translates.add_column.clone()} } else { {translates.add_row.clone()} }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            LogicEditor { lang, index, question: question.clone(), locked }
+        }
+    }
+}
@@ -1,22 +1,73 @@
 #![allow(non_snake_case)]
 use dioxus::prelude::*;
+use dioxus_logger::tracing;
+
+use crate::api::{
+    common::ApiError,
+    v1::surveys::{
+        create_survey,
+        edit::{update_survey, UpdateSurveyRequest},
+        get_survey,
+        schedule::{schedule_survey, ScheduleSurveyRequest},
+        CreateSurveyRequest,
+    },
+};
+use crate::utils::time::{format_kst, parse_kst};
 
 #[derive(Debug, Clone, PartialEq, Copy)]
 pub struct Controller {
     pub survey_title: Signal<String>,
+    pub survey_description: Signal<String>,
+    // `datetime-local` values in KST
+    start_at: Signal<String>,
+    end_at: Signal<String>,
+    saved_schedule: Signal<(String, String)>,
+    survey_id: Signal<Option<String>>,
+    error: Signal<Option<ApiError>>,
 }
 
 impl Controller {
-    pub fn init() -> Self {
-        let ctrl = Self {
+    // init loads the title and description of `survey_id` when editing an existing survey.
+    pub fn init(survey_id: Option<String>) -> Self {
+        let mut ctrl = Self {
             survey_title: use_signal(|| "".to_string()),
+            survey_description: use_signal(|| "".to_string()),
+            start_at: use_signal(|| "".to_string()),
+            end_at: use_signal(|| "".to_string()),
+            saved_schedule: use_signal(|| ("".to_string(), "".to_string())),
+            survey_id: use_signal(|| survey_id),
+            error: use_signal(|| None),
         };
 
         use_context_provider(|| ctrl);
 
+        let _ = use_effect(move || {
+            if let Some(survey_id) = (ctrl.survey_id)() {
+                spawn(async move {
+                    match get_survey(survey_id).await {
+                        Ok(res) => {
+                            ctrl.survey_title.set(res.survey.title);
+                            ctrl.survey_description.set(res.survey.description);
+                            let schedule = res.survey.schedule.unwrap_or_default();
+                            let start_at = schedule.start_at.map(format_kst).unwrap_or_default();
+                            let end_at = schedule.end_at.map(format_kst).unwrap_or_default();
+                            ctrl.start_at.set(start_at.clone());
+                            ctrl.end_at.set(end_at.clone());
+                            ctrl.saved_schedule.set((start_at, end_at));
+                        }
+                        Err(e) => ctrl.set_error(e),
+                    }
+                });
+            }
+        });
+
         ctrl
     }
 
+    pub fn is_editing(&self) -> bool {
+        self.survey_id.peek().is_some()
+    }
+
     pub fn get_survey_title(&self) -> String {
         (self.survey_title)()
     }
@@ -24,4 +75,105 @@ impl Controller {
     pub fn set_survey_title(&mut self, title: String) {
         self.survey_title.set(title);
     }
+
+    pub fn get_survey_description(&self) -> String {
+        (self.survey_description)()
+    }
+
+    pub fn set_survey_description(&mut self, description: String) {
+        self.survey_description.set(description);
+    }
+
+    pub fn get_start_at(&self) -> String {
+        (self.start_at)()
+    }
+
+    pub fn set_start_at(&mut self, start_at: String) {
+        self.start_at.set(start_at);
+    }
+
+    pub fn get_end_at(&self) -> String {
+        (self.end_at)()
+    }
+
+    pub fn set_end_at(&mut self, end_at: String) {
+        self.end_at.set(end_at);
+    }
+
+    pub fn get_error(&self) -> Option<ApiError> {
+        (self.error)()
+    }
+
+    // save creates a new survey or updates the edited one, and returns its id.
+    pub async fn save(&mut self) -> Option<String> {
+        let survey_id = self.survey_id.peek().clone();
+        let res = match survey_id {
+            Some(survey_id) => match update_survey(UpdateSurveyRequest {
+                survey_id,
+                title: Some(self.get_survey_title()),
+                description: Some(self.get_survey_description()),
+            })
+            .await
+            {
+                Ok(survey) => self.save_schedule(survey.id).await,
+                Err(e) => Err(e),
+            },
+            None => create_survey(CreateSurveyRequest {
+                title: self.get_survey_title(),
+                description: self.get_survey_description(),
+                questions: vec![],
+            })
+            .await
+            .map(|res| res.id),
+        };
+
+        match res {
+            Ok(id) => {
+                self.error.set(None);
+                Some(id)
+            }
+            Err(e) => {
+                self.set_error(e);
+                None
+            }
+        }
+    }
+
+    // save_schedule updates the schedule of `survey_id` if it was changed.
+    async fn save_schedule(
+        &mut self,
+        survey_id: String,
+    ) -> Result<String, ServerFnError<ApiError>> {
+        let schedule = (self.get_start_at(), self.get_end_at());
+        if schedule == *self.saved_schedule.peek() {
+            return Ok(survey_id);
+        }
+
+        let parse = |value: &str, field: &str| match value {
+            "" => Ok(None),
+            value => parse_kst(value)
+                .map(Some)
+                .ok_or(ApiError::InvalidInput(field.to_string())),
+        };
+        let req = ScheduleSurveyRequest {
+            survey_id,
+            start_at: parse(&schedule.0, "start_at")?,
+            end_at: parse(&schedule.1, "end_at")?,
+        };
+        let survey = schedule_survey(req).await?;
+        self.saved_schedule.set(schedule);
+
+        Ok(survey.id)
+    }
+
+    fn set_error(&mut self, e: ServerFnError<ApiError>) {
+        match e {
+            ServerFnError::WrappedServerError(e) => self.error.set(Some(e)),
+            e => {
+                tracing::error!("failed to save survey: {:?}", e);
+                self.error
+                    .set(Some(ApiError::InternalServerError(e.to_string())));
+            }
+        }
+    }
 }
@@ -4,8 +4,17 @@ pub struct WriteTitleTranslate {
     pub temporary_storage: String,
     pub write_title: String,
     pub write_title_hint: String,
+    pub write_description_hint: String,
     pub cancel: String,
     pub store: String,
+    pub start_at: String,
+    pub end_at: String,
+    pub schedule_hint: String,
+    pub empty_title: String,
+    pub invalid_schedule: String,
+    pub schedule_not_allowed: String,
+    pub forbidden: String,
+    pub failed: String,
 }
 
 pub fn translate(lang: Language) -> WriteTitleTranslate {
@@ -14,15 +23,33 @@ pub fn translate(lang: Language) -> WriteTitleTranslate {
             temporary_storage: "Temporary Storage".to_string(),
             write_title: "Write a Questionnaire Title".to_string(),
             write_title_hint: "Please enter the title of the questionnaire.".to_string(),
+            write_description_hint: "Please enter a description of the questionnaire.".to_string(),
             cancel: "Cancel".to_string(),
             store: "Save".to_string(),
+            start_at: "Start".to_string(),
+            end_at: "End".to_string(),
+            schedule_hint: "The survey opens and closes automatically at these times (KST).".to_string(),
+            empty_title: "Please enter the title of the questionnaire.".to_string(),
+            invalid_schedule: "The start must be in the future and the end after the start.".to_string(),
+            schedule_not_allowed: "Only a draft can be scheduled to start, and a finished or archived survey cannot be scheduled.".to_string(),
+            forbidden: "You do not have permission to create a questionnaire.".to_string(),
+            failed: "Failed to save the questionnaire. Please try again.".to_string(),
         },
         Language::Ko => WriteTitleTranslate {
             temporary_storage: "임시 저장".to_string(),
             write_title: "설문지 제목 작성하기".to_string(),
             write_title_hint: "설문지의 제목을 입력해주세요.".to_string(),
+            write_description_hint: "설문지의 설명을 입력해주세요.".to_string(),
             cancel: "취소".to_string(),
             store: "저장".to_string(),
+            start_at: "시작".to_string(),
+            end_at: "종료".to_string(),
+            schedule_hint: "설정한 시간(한국 시간)에 설문이 자동으로 시작되고 종료됩니다.".to_string(),
+            empty_title: "설문지의 제목을 입력해주세요.".to_string(),
+            invalid_schedule: "시작 시간은 현재 이후, 종료 시간은 시작 시간 이후여야 합니다.".to_string(),
+            schedule_not_allowed: "시작 예약은 작성 중인 설문만 가능하며, 종료되었거나 보관된 설문은 예약할 수 없습니다.".to_string(),
+            forbidden: "설문지를 만들 권한이 없습니다.".to_string(),
+            failed: "설문지를 저장하지 못했습니다. 다시 시도해 주세요.".to_string(),
         },
     }
 }
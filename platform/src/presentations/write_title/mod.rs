@@ -1,5 +1,5 @@
 #![allow(non_snake_case)]
-use crate::prelude::*;
+use crate::{api::common::ApiError, prelude::*};
 use dioxus::prelude::*;
 
 mod controller;
@@ -10,10 +10,49 @@ pub struct WriteTitleProps {
     lang: Language,
 }
 
+#[derive(PartialEq, Props, Clone)]
+pub struct EditTitleProps {
+    lang: Language,
+    survey_id: String,
+}
+
+#[derive(PartialEq, Props, Clone)]
+pub struct TitleFormProps {
+    lang: Language,
+    // None creates a new survey
+    survey_id: Option<String>,
+}
+
 #[component]
 pub fn WriteTitlePage(props: WriteTitleProps) -> Element {
-    let mut ctrl = controller::Controller::init();
+    rsx! {
+        TitleForm { lang: props.lang, survey_id: None }
+    }
+}
+
+#[component]
+pub fn EditTitlePage(props: EditTitleProps) -> Element {
+    rsx! {
+        TitleForm { lang: props.lang, survey_id: Some(props.survey_id) }
+    }
+}
+
+#[component]
+pub fn TitleForm(props: TitleFormProps) -> Element {
+    let mut ctrl = controller::Controller::init(props.survey_id.clone());
     let translates = i18n::translate(props.lang.clone());
+    let navigator = use_navigator();
+    let lang = props.lang;
+    let error_message = ctrl.get_error().map(|e| match e {
+        ApiError::InvalidInput(field) if field == "start_at" || field == "end_at" => {
+            translates.invalid_schedule.clone()
+        }
+        ApiError::InvalidInput(_) => translates.empty_title.clone(),
+        ApiError::InvalidTransition => translates.schedule_not_allowed.clone(),
+        ApiError::Forbidden => translates.forbidden.clone(),
+        _ => translates.failed.clone(),
+    });
+
     rsx! {
         div {
             class: "flex flex-col w-full h-full justify-start items-center",
@@ -27,7 +66,7 @@ pub fn WriteTitlePage(props: WriteTitleProps) -> Element {
             div {
                 class: "flex flex-col max-w-[1200px] min-w-[600px] w-full h-full justify-start items-start mt-[25px] px-[50px]",
                 div {
-                    class: "flex flex-col w-full justify-start items-start max-w-[1200px] h-[300px] rounded-[10px] border-solid border border-[#9f9f9f] bg-white px-[33px] py-[55px]",
+                    class: "flex flex-col w-full justify-start items-start max-w-[1200px] min-h-[300px] rounded-[10px] border-solid border border-[#9f9f9f] bg-white px-[33px] py-[55px]",
                     div {
                         class: "text-[#2168c3] font-semibold text-[30px] mb-[20px]",
                         {translates.write_title}
@@ -45,6 +84,52 @@ pub fn WriteTitlePage(props: WriteTitleProps) -> Element {
                             },
                         }
                     },
+                    div {
+                        class: "flex flex-row w-full h-[55px] justify-start items-start mb-[20px]",
+                        input {
+                            class: "flex flex-1 text-[18px] text-[#8a8a8a] font-normal",
+                            "type": "text",
+                            style: "border:0px; padding: 5px; border-color: transparent; outline-style: none; box-shadow: none; border-bottom: 1px solid #9f9f9f;",
+                            placeholder: translates.write_description_hint,
+                            value: ctrl.get_survey_description(),
+                            onchange: move |e| {
+                                ctrl.set_survey_description(e.value());
+                            },
+                        }
+                    },
+                    if ctrl.is_editing() {
+                        div {
+                            class: "flex flex-row w-full justify-start items-center mb-[10px] text-[18px] font-normal text-[#4c4c4c]",
+                            div { class: "mr-[10px]", {translates.start_at} }
+                            input {
+                                class: "mr-[30px] p-[5px] border border-[#9f9f9f] rounded-[5px]",
+                                "type": "datetime-local",
+                                value: ctrl.get_start_at(),
+                                onchange: move |e| {
+                                    ctrl.set_start_at(e.value());
+                                },
+                            }
+                            div { class: "mr-[10px]", {translates.end_at} }
+                            input {
+                                class: "p-[5px] border border-[#9f9f9f] rounded-[5px]",
+                                "type": "datetime-local",
+                                value: ctrl.get_end_at(),
+                                onchange: move |e| {
+                                    ctrl.set_end_at(e.value());
+                                },
+                            }
+                        }
+                        div {
+                            class: "flex flex-row w-full justify-start items-start mb-[20px] text-[15px] font-normal text-[#8a8a8a]",
+                            {translates.schedule_hint}
+                        }
+                    }
+                    if let Some(error_message) = error_message {
+                        div {
+                            class: "flex flex-row w-full justify-end items-start mb-[10px] text-[16px] font-normal text-[#ff0000]",
+                            "{error_message}"
+                        }
+                    }
                     div {
                         class: "flex flex-row w-full justify-end items-start mb-[20px]",
                         Link {
@@ -57,7 +142,17 @@ pub fn WriteTitlePage(props: WriteTitleProps) -> Element {
                             }
                         }
                         div {
-                            class: "flex flex-row w-[85px] h-[45px] justify-center items-center rounded-[5px] bg-[#2168c3] text-[20px] font-normal text-white mr-[7px]",
+                            class: "flex flex-row w-[85px] h-[45px] justify-center items-center rounded-[5px] bg-[#2168c3] text-[20px] font-normal text-white mr-[7px] cursor-pointer",
+                            onclick: move |_| async move {
+                                let created = !ctrl.is_editing();
+                                if let Some(survey_id) = ctrl.save().await {
+                                    if created {
+                                        navigator.push(Route::WriteQuestionPage { lang, survey_id });
+                                    } else {
+                                        navigator.push(Route::DashboardPage { lang });
+                                    }
+                                }
+                            },
                             {translates.store}
                         }
                     }
@@ -4,10 +4,14 @@ use crate::prelude::*;
 use crate::presentations::create::CreatePage;
 use crate::presentations::dashboard::DashboardPage;
 use crate::presentations::find_email::FindEmailPage;
+use crate::presentations::invitation::AcceptInvitationPage;
 use crate::presentations::login::LoginPage;
+use crate::presentations::members::MembersPage;
 use crate::presentations::reset_password::ResetPasswordPage;
+use crate::presentations::respond::RespondPage;
+use crate::presentations::result::ResultPage;
 use crate::presentations::write_question::WriteQuestionPage;
-use crate::presentations::write_title::WriteTitlePage;
+use crate::presentations::write_title::{EditTitlePage, WriteTitlePage};
 use crate::utils::context::{default_lang, Language};
 
 #[derive(Clone, Routable, Debug, PartialEq)]
@@ -19,8 +23,16 @@ pub enum Route {
             DashboardPage { lang: Language },
             #[route("/write-title")]
             WriteTitlePage { lang: Language },
-            #[route("/write-question/:title")]
-            WriteQuestionPage { lang: Language, title: String },
+            #[route("/edit-title/:survey_id")]
+            EditTitlePage { lang: Language, survey_id: String },
+            #[route("/write-question/:survey_id")]
+            WriteQuestionPage { lang: Language, survey_id: String },
+            #[route("/result/:survey_id")]
+            ResultPage { lang: Language, survey_id: String },
+            #[route("/invitations/:token")]
+            AcceptInvitationPage { lang: Language, token: String },
+            #[route("/members")]
+            MembersPage { lang: Language },
         #[end_layout]
 
         #[route("/")]
@@ -31,6 +43,8 @@ pub enum Route {
         FindEmailPage { lang: Language },
         #[route("/reset-password")]
         ResetPasswordPage { lang: Language },
+        #[route("/s/:survey_id")]
+        RespondPage { lang: Language, survey_id: String },
 
     #[end_nest]
 
@@ -0,0 +1,152 @@
+use crate::{
+    api::{common::ApiError, v1::users::signup::SignupUserRequest},
+    models::{
+        role::RoleBinding,
+        user::{
+            is_valid_business_number, is_valid_password, LoginAttempt, Terms, TermsAgreement, User,
+            UserEmail, UserKind, UserProfile,
+        },
+        verification::VerificationKind,
+    },
+};
+
+use super::{database, organization, password, verification};
+
+// login checks the password of `email`; failed attempts are limited per email, see `LoginAttempt`.
+pub async fn login(email: &str, password: &str, now: u64) -> Result<User, ApiError> {
+    let key = LoginAttempt::key(email);
+    let attempt = database::get::<LoginAttempt>(&key).await?;
+    if let Some(ref attempt) = attempt {
+        if attempt.is_locked(now) {
+            return Err(ApiError::TooManyAttempts(attempt.locked_until - now));
+        }
+    }
+
+    let user = database::find_one::<User>("gsi1-index", "gsi1", &User::gsi1(email)).await?;
+    // an unknown email is checked against a dummy hash, so that it takes as long as a known one
+    let hashed = user
+        .as_ref()
+        .map_or(password::dummy_hash(), |user| user.password.as_str());
+    let verified = password::verify_password(password, hashed);
+
+    match user {
+        Some(user) if verified => {
+            if attempt.is_some() {
+                database::delete(&key).await?;
+            }
+
+            Ok(user)
+        }
+        _ => {
+            database::put_if_absent(&LoginAttempt::new(email)).await?;
+            let mut attempt = database::add::<LoginAttempt>(&key, "failures", 1).await?;
+            if attempt.lock(now) {
+                database::put_fields(&attempt, &["locked_until", "updated_at"]).await?;
+            }
+
+            Err(ApiError::InvalidCredentials)
+        }
+    }
+}
+
+// signup creates the account of a verified email, with an organization of its own.
+pub async fn signup(req: SignupUserRequest, now: u64) -> Result<User, ApiError> {
+    if !is_valid_password(&req.password) {
+        return Err(ApiError::InvalidPassword);
+    }
+
+    if let UserKind::Corporation {
+        ref business_number,
+        ..
+    } = req.kind
+    {
+        if !is_valid_business_number(business_number) {
+            return Err(ApiError::InvalidBusinessNumber);
+        }
+    }
+
+    // the client clock may run ahead of the server's
+    let agreed_terms: Vec<TermsAgreement> = req
+        .agreed_terms
+        .iter()
+        .filter(|a| a.agreed_at + TermsAgreement::MAX_AGE >= now)
+        .map(|a| TermsAgreement {
+            terms: a.terms,
+            agreed_at: a.agreed_at.min(now),
+        })
+        .collect();
+    if Terms::essentials()
+        .iter()
+        .any(|t| !agreed_terms.iter().any(|a| a.terms == *t))
+    {
+        return Err(ApiError::TermsNotAgreed);
+    }
+
+    verification::require_verified(VerificationKind::Contact, &req.email, now).await?;
+
+    // users who signed up before `UserEmail` have no item reserving their email
+    let gsi1 = User::gsi1(&req.email);
+    if database::find_one::<User>("gsi1-index", "gsi1", &gsi1)
+        .await?
+        .is_some()
+    {
+        return Err(ApiError::DuplicateEmail);
+    }
+
+    let user_id = database::new_id("user");
+    let email = UserEmail::new(&req.email, &user_id, now);
+    if !database::put_if_absent(&email).await? {
+        return Err(ApiError::DuplicateEmail);
+    }
+
+    let gsi2 = User::gsi2(&req.phone);
+    let organization_name = match req.kind {
+        UserKind::Corporation { ref company, .. } => company.clone(),
+        UserKind::Individual => req.name.clone(),
+    };
+    // the email is released on failure so that signup can be retried
+    let organization = match organization::create(&organization_name, &user_id, now).await {
+        Ok(organization) => organization,
+        Err(e) => {
+            database::delete(&email.id).await?;
+            return Err(e);
+        }
+    };
+
+    let user = User {
+        id: user_id,
+        r#type: "user".to_string(),
+        email: req.email.trim().to_lowercase(),
+        password: password::hash_password(&req.password)?,
+        name: req.name,
+        phone: req.phone,
+        address: req.address,
+        kind: req.kind,
+        agreed_terms,
+        organization_id: organization.id,
+        sessions_valid_after: 0,
+        created_at: now,
+        updated_at: now,
+        gsi1,
+        gsi2,
+    };
+    if let Err(e) = database::put(&user).await {
+        database::delete(&email.id).await?;
+        return Err(e);
+    }
+    verification::consume(VerificationKind::Contact, &req.email).await?;
+
+    Ok(user)
+}
+
+// profile returns the profile of `user` with its role in the organization it is working in,
+// which is loaded once at sign-in so that the client can hide the actions it does not allow.
+pub async fn profile(user: &User) -> Result<UserProfile, ApiError> {
+    let binding =
+        database::get::<RoleBinding>(&RoleBinding::key(&user.organization_id, &user.id)).await?;
+
+    Ok(UserProfile {
+        role: binding.map(|binding| binding.role),
+        ..user.profile()
+    })
+}
@@ -0,0 +1,27 @@
+use dioxus_logger::tracing;
+
+use crate::{api::common::ApiError, models::audit::AuditLog};
+
+use super::database;
+
+pub async fn record(
+    action: &str,
+    actor: &str,
+    target: &str,
+    detail: String,
+    now: u64,
+) -> Result<(), ApiError> {
+    tracing::info!(action, actor, target, "{detail}");
+
+    database::put(&AuditLog {
+        id: database::new_id("audit"),
+        r#type: "audit".to_string(),
+        action: action.to_string(),
+        actor: actor.to_string(),
+        target: target.to_string(),
+        detail,
+        created_at: now,
+        gsi1: AuditLog::gsi1(target),
+    })
+    .await
+}
@@ -0,0 +1,72 @@
+use crate::{
+    api::common::ApiError,
+    models::{
+        role::{Permission, Role, RoleBinding},
+        survey::SurveySummary,
+        user::User,
+    },
+};
+
+use super::database;
+
+async fn binding(resource_id: &str, user_id: &str) -> Result<Option<Role>, ApiError> {
+    Ok(
+        database::get::<RoleBinding>(&RoleBinding::key(resource_id, user_id))
+            .await?
+            .map(|b| b.role),
+    )
+}
+
+pub async fn organization_role(
+    user: &User,
+    organization_id: &str,
+) -> Result<Option<Role>, ApiError> {
+    binding(organization_id, &user.id).await
+}
+
+// survey_role is the higher of the role on the owning organization and the role granted on the survey.
+pub async fn survey_role(user: &User, survey: &SurveySummary) -> Result<Option<Role>, ApiError> {
+    let organization = organization_role(user, &survey.gsi1).await?;
+    let survey = binding(&survey.id, &user.id).await?;
+
+    Ok(organization
+        .into_iter()
+        .chain(survey)
+        .max_by_key(|r| r.rank()))
+}
+
+pub async fn require_organization(
+    user: &User,
+    organization_id: &str,
+    permission: Permission,
+) -> Result<Role, ApiError> {
+    match organization_role(user, organization_id).await? {
+        Some(role) if role.allows(permission) => Ok(role),
+        _ => Err(ApiError::Forbidden),
+    }
+}
+
+pub async fn require_survey(
+    user: &User,
+    survey: &SurveySummary,
+    permission: Permission,
+) -> Result<Role, ApiError> {
+    match survey_role(user, survey).await? {
+        Some(role) if role.allows(permission) => Ok(role),
+        _ => Err(ApiError::Forbidden),
+    }
+}
+
+// load_survey loads a survey and checks that `user` may act on it.
+pub async fn load_survey(
+    user: &User,
+    survey_id: &str,
+    permission: Permission,
+) -> Result<SurveySummary, ApiError> {
+    let survey = database::get_typed::<SurveySummary>(survey_id, "survey")
+        .await?
+        .ok_or(ApiError::NotFound)?;
+    require_survey(user, &survey, permission).await?;
+
+    Ok(survey)
+}
@@ -4,7 +4,10 @@ use dioxus::prelude::*;
 
 use serde::{Deserialize, Serialize};
 
-use crate::routes::Route;
+use crate::{
+    models::{role::Permission, user::UserProfile},
+    routes::Route,
+};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IitpContext {
@@ -73,3 +76,60 @@ pub struct LoginPopupState(pub bool, pub Option<Route>);
 pub fn use_login_context_provider() {
     use_context_provider(|| Signal::new(LoginPopupState(false, None)));
 }
+
+// AuthContext holds the signed-in user with its role, which are loaded once at sign-in.
+// `checked` turns true once the session has been checked against the server.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AuthContext {
+    pub checked: bool,
+    pub user: Option<UserProfile>,
+}
+
+impl AuthContext {
+    pub fn is_authenticated(&self) -> bool {
+        self.user.is_some()
+    }
+
+    // can tells whether the role of the user allows `permission`, so that the UI can hide
+    // what the server would refuse. The server checks every request regardless.
+    pub fn can(&self, permission: Permission) -> bool {
+        self.user
+            .as_ref()
+            .and_then(|user| user.role)
+            .is_some_and(|role| role.allows(permission))
+    }
+}
+
+pub fn use_auth_context_provider() {
+    use_context_provider(|| Signal::new(AuthContext::default()));
+}
+
+pub fn use_auth_context() -> Signal<AuthContext> {
+    use_context()
+}
+
+pub fn use_login_context() -> Signal<LoginPopupState> {
+    use_context()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::role::Role;
+
+    #[test]
+    fn auth_context_allows_the_actions_of_the_role() {
+        let signed_in = |role| AuthContext {
+            checked: true,
+            user: Some(UserProfile {
+                role,
+                ..Default::default()
+            }),
+        };
+
+        assert!(signed_in(Some(Role::Editor)).can(Permission::Edit));
+        assert!(!signed_in(Some(Role::Viewer)).can(Permission::Edit));
+        assert!(!signed_in(None).can(Permission::Read));
+        assert!(!AuthContext::default().can(Permission::Read));
+    }
+}
@@ -0,0 +1,396 @@
+use std::{collections::HashMap, sync::OnceLock};
+
+use rand::RngCore;
+use serde::{de::DeserializeOwned, Serialize};
+
+use aws_sdk_dynamodb::{
+    error::SdkError,
+    types::{AttributeValue, Delete, Put, TransactWriteItem, Update},
+};
+use dioxus_logger::tracing;
+use tokio::sync::OnceCell;
+
+use crate::api::common::ApiError;
+
+static LOGGER: OnceLock<slog::Logger> = OnceLock::new();
+static TABLE_NAME: OnceLock<String> = OnceLock::new();
+static SDK: OnceCell<aws_sdk_dynamodb::Client> = OnceCell::const_new();
+
+pub fn log() -> slog::Logger {
+    LOGGER
+        .get_or_init(|| slog::Logger::root(slog::Discard, slog::o!()))
+        .clone()
+}
+
+// init configures easy-dynamodb from the environment.
+// Set DYNAMODB_ENDPOINT (e.g. http://localhost:8000) to use a local DynamoDB.
+pub fn init() {
+    let env = |key: &str, default: &str| std::env::var(key).unwrap_or(default.to_string());
+    let _ = TABLE_NAME.set(env("TABLE_NAME", "voice-korea-dev"));
+
+    easy_dynamodb::init(
+        log(),
+        env("AWS_ACCESS_KEY_ID", ""),
+        env("AWS_SECRET_ACCESS_KEY", ""),
+        env("AWS_REGION", "ap-northeast-2"),
+        table_name().to_string(),
+        "id".to_string(),
+        std::env::var("DYNAMODB_ENDPOINT").ok(),
+    );
+}
+
+fn table_name() -> &'static str {
+    TABLE_NAME.get().map_or("voice-korea-dev", |t| t.as_str())
+}
+
+// sdk returns a client for the writes easy-dynamodb does not support, such as conditional ones.
+// It uses the same table and items serialized by serde_dynamo, as easy-dynamodb does.
+async fn sdk() -> &'static aws_sdk_dynamodb::Client {
+    SDK.get_or_init(|| async {
+        let region = std::env::var("AWS_REGION").unwrap_or("ap-northeast-2".to_string());
+        let mut config = aws_config::defaults(aws_config::BehaviorVersion::latest())
+            .region(aws_config::Region::new(region));
+        if let Ok(endpoint) = std::env::var("DYNAMODB_ENDPOINT") {
+            config = config.endpoint_url(endpoint);
+        }
+
+        aws_sdk_dynamodb::Client::new(&config.load().await)
+    })
+    .await
+}
+
+fn to_item<T>(doc: &T) -> Result<HashMap<String, AttributeValue>, ApiError>
+where
+    T: Serialize,
+{
+    serde_dynamo::to_item(doc).map_err(ApiError::internal)
+}
+
+// new_id returns a random key such as `user-3f9c0a...`.
+pub fn new_id(prefix: &str) -> String {
+    let mut bytes = [0u8; 16];
+    rand::thread_rng().fill_bytes(&mut bytes);
+
+    format!("{}-{}", prefix, hex::encode(bytes))
+}
+
+pub async fn get<T>(key: &str) -> Result<Option<T>, ApiError>
+where
+    T: DeserializeOwned,
+{
+    let cli = easy_dynamodb::get_client(log());
+
+    cli.get::<T>(key).await.map_err(ApiError::dynamo)
+}
+
+// get_typed loads the item at `key` when its type is `r#type`.
+// An item of another type, or one that does not decode as `T`, is reported as missing.
+pub async fn get_typed<T>(key: &str, r#type: &str) -> Result<Option<T>, ApiError>
+where
+    T: DeserializeOwned,
+{
+    let item = sdk()
+        .await
+        .get_item()
+        .table_name(table_name())
+        .key("id", AttributeValue::S(key.to_string()))
+        .send()
+        .await
+        .map_err(ApiError::dynamo)?
+        .item;
+
+    Ok(item
+        .filter(|i| matches!(i.get("type"), Some(AttributeValue::S(t)) if t == r#type))
+        .and_then(|i| serde_dynamo::from_item(i).ok()))
+}
+
+pub async fn put<T>(doc: T) -> Result<(), ApiError>
+where
+    T: Serialize,
+{
+    let cli = easy_dynamodb::get_client(log());
+
+    cli.create(doc).await.map_err(ApiError::dynamo)
+}
+
+pub async fn delete(key: &str) -> Result<(), ApiError> {
+    let cli = easy_dynamodb::get_client(log());
+
+    cli.delete(key).await.map_err(ApiError::dynamo)
+}
+
+// find_one returns the first item of `index` whose `field` equals `value`.
+pub async fn find_one<T>(index: &str, field: &str, value: &str) -> Result<Option<T>, ApiError>
+where
+    T: DeserializeOwned,
+{
+    let cli = easy_dynamodb::get_client(log());
+
+    match cli
+        .find::<T, &str>(index, None, Some(1), vec![(field, value)])
+        .await
+    {
+        Ok((Some(items), _)) => Ok(items.into_iter().next()),
+        Ok((None, _)) => Ok(None),
+        Err(e) => Err(ApiError::dynamo(e)),
+    }
+}
+
+// put_if_absent writes `doc` unless an item with its key exists, and returns whether it was written.
+pub async fn put_if_absent<T>(doc: &T) -> Result<bool, ApiError>
+where
+    T: Serialize,
+{
+    let res = sdk()
+        .await
+        .put_item()
+        .table_name(table_name())
+        .set_item(Some(to_item(doc)?))
+        .condition_expression("attribute_not_exists(id)")
+        .send()
+        .await;
+
+    match res {
+        Ok(_) => Ok(true),
+        Err(SdkError::ServiceError(e)) if e.err().is_conditional_check_failed_exception() => {
+            Ok(false)
+        }
+        Err(e) => Err(ApiError::dynamo(e)),
+    }
+}
+
+// take deletes the item at `key` and returns it, or `None` if there is none, e.g. because
+// another request took it first; a single-use item taken this way is used only once.
+pub async fn take<T>(key: &str) -> Result<Option<T>, ApiError>
+where
+    T: DeserializeOwned,
+{
+    let res = sdk()
+        .await
+        .delete_item()
+        .table_name(table_name())
+        .key("id", AttributeValue::S(key.to_string()))
+        .condition_expression("attribute_exists(id)")
+        .return_values(aws_sdk_dynamodb::types::ReturnValue::AllOld)
+        .send()
+        .await;
+
+    match res {
+        Ok(out) => serde_dynamo::from_item(out.attributes.unwrap_or_default())
+            .map(Some)
+            .map_err(ApiError::internal),
+        Err(SdkError::ServiceError(e)) if e.err().is_conditional_check_failed_exception() => {
+            Ok(None)
+        }
+        Err(e) => Err(ApiError::dynamo(e)),
+    }
+}
+
+// put_fields writes only `fields` of `doc` to its stored item and leaves its other attributes,
+// such as counters added to at the same time, as they are.
+pub async fn put_fields<T>(doc: &T, fields: &[&str]) -> Result<(), ApiError>
+where
+    T: Serialize,
+{
+    let (key, set) = set_fields(doc, fields)?;
+
+    match sdk()
+        .await
+        .update_item()
+        .table_name(table_name())
+        .key("id", key)
+        .condition_expression("attribute_exists(id)")
+        .update_expression(set.expression)
+        .set_expression_attribute_names(Some(set.names))
+        .set_expression_attribute_values(Some(set.values))
+        .send()
+        .await
+    {
+        Ok(_) => Ok(()),
+        Err(SdkError::ServiceError(e)) if e.err().is_conditional_check_failed_exception() => {
+            Err(ApiError::NotFound)
+        }
+        Err(e) => Err(ApiError::dynamo(e)),
+    }
+}
+
+struct SetFields {
+    expression: String,
+    names: HashMap<String, String>,
+    values: HashMap<String, AttributeValue>,
+}
+
+// set_fields returns the key of `doc` and an update expression setting its `fields`.
+fn set_fields<T>(doc: &T, fields: &[&str]) -> Result<(AttributeValue, SetFields), ApiError>
+where
+    T: Serialize,
+{
+    let mut item = to_item(doc)?;
+    let key = item
+        .remove("id")
+        .ok_or_else(|| ApiError::internal("the item has no id"))?;
+
+    let mut set = SetFields {
+        expression: String::new(),
+        names: HashMap::new(),
+        values: HashMap::new(),
+    };
+    let mut sets = vec![];
+    for (i, field) in fields.iter().enumerate() {
+        let value = item
+            .remove(*field)
+            .ok_or_else(|| ApiError::internal(format!("the item has no {field}")))?;
+        set.names.insert(format!("#f{i}"), field.to_string());
+        set.values.insert(format!(":v{i}"), value);
+        sets.push(format!("#f{i} = :v{i}"));
+    }
+    set.expression = format!("SET {}", sets.join(", "));
+
+    Ok((key, set))
+}
+
+// DynamoDB takes at most this many writes in a transaction.
+pub const MAX_TRANSACTION_WRITES: usize = 100;
+
+// transact puts `docs`, deletes the items at `keys` and writes the `fields` of `guard`, as `put_fields`
+// does, in one transaction which only succeeds while the stored `check` field of `guard` still has
+// the value `expected` and its `unset` fields are still missing, null or 0.
+// It fails with `ApiError::Conflict` when another request changed them meanwhile.
+// As the write of `guard` is one of the transaction, `docs` and `keys` are fewer than `MAX_TRANSACTION_WRITES`.
+pub async fn transact<T, G, V>(
+    docs: &[T],
+    keys: &[&str],
+    guard: &G,
+    fields: &[&str],
+    check: &str,
+    expected: V,
+    unset: &[&str],
+) -> Result<(), ApiError>
+where
+    T: Serialize,
+    G: Serialize,
+    V: Serialize,
+{
+    if docs.len() + keys.len() >= MAX_TRANSACTION_WRITES {
+        return Err(ApiError::internal(format!(
+            "{} writes do not fit in a transaction",
+            docs.len() + keys.len() + 1
+        )));
+    }
+
+    let (key, mut set) = set_fields(guard, fields)?;
+    let mut conditions = vec!["attribute_exists(id) AND #check = :expected".to_string()];
+    set.names.insert("#check".to_string(), check.to_string());
+    set.values.insert(
+        ":expected".to_string(),
+        serde_dynamo::to_attribute_value(expected).map_err(ApiError::internal)?,
+    );
+    for (i, field) in unset.iter().enumerate() {
+        set.names.insert(format!("#u{i}"), field.to_string());
+        conditions.push(format!(
+            "(attribute_not_exists(#u{i}) OR attribute_type(#u{i}, :null) OR #u{i} = :zero)"
+        ));
+    }
+    if !unset.is_empty() {
+        set.values
+            .insert(":null".to_string(), AttributeValue::S("NULL".to_string()));
+        set.values
+            .insert(":zero".to_string(), AttributeValue::N("0".to_string()));
+    }
+
+    let mut writes = vec![];
+    for doc in docs {
+        let put = Put::builder()
+            .table_name(table_name())
+            .set_item(Some(to_item(doc)?))
+            .build()
+            .map_err(ApiError::internal)?;
+        writes.push(TransactWriteItem::builder().put(put).build());
+    }
+    for key in keys {
+        let delete = Delete::builder()
+            .table_name(table_name())
+            .key("id", AttributeValue::S(key.to_string()))
+            .build()
+            .map_err(ApiError::internal)?;
+        writes.push(TransactWriteItem::builder().delete(delete).build());
+    }
+    let update = Update::builder()
+        .table_name(table_name())
+        .key("id", key)
+        .update_expression(set.expression)
+        .condition_expression(conditions.join(" AND "))
+        .set_expression_attribute_names(Some(set.names))
+        .set_expression_attribute_values(Some(set.values))
+        .build()
+        .map_err(ApiError::internal)?;
+    writes.push(TransactWriteItem::builder().update(update).build());
+
+    match sdk()
+        .await
+        .transact_write_items()
+        .set_transact_items(Some(writes))
+        .send()
+        .await
+    {
+        Ok(_) => Ok(()),
+        Err(SdkError::ServiceError(e)) if e.err().is_transaction_canceled_exception() => {
+            tracing::warn!("transaction canceled: {:?}", e.err());
+            Err(ApiError::Conflict)
+        }
+        Err(e) => Err(ApiError::dynamo(e)),
+    }
+}
+
+// add adds `by` to the number `field` of the item at `key` in one write and returns the item after it,
+// so that concurrent requests each see their own count.
+// A `field` stored as null, as `None` is, is set to `by` instead.
+pub async fn add<T>(key: &str, field: &str, by: u64) -> Result<T, ApiError>
+where
+    T: DeserializeOwned,
+{
+    let update = |expression: &str, condition: &str| {
+        let (expression, condition) = (expression.to_string(), condition.to_string());
+        async move {
+            sdk()
+                .await
+                .update_item()
+                .table_name(table_name())
+                .key("id", AttributeValue::S(key.to_string()))
+                .update_expression(expression)
+                .condition_expression(condition)
+                .expression_attribute_names("#f", field)
+                .expression_attribute_values(":by", AttributeValue::N(by.to_string()))
+                .expression_attribute_values(":null", AttributeValue::S("NULL".to_string()))
+                .return_values(aws_sdk_dynamodb::types::ReturnValue::AllNew)
+                .send()
+                .await
+        }
+    };
+
+    // the field may turn from null to a number between the two writes, so they are retried
+    for _ in 0..3 {
+        for (expression, condition) in [
+            (
+                "ADD #f :by",
+                "attribute_exists(id) AND NOT attribute_type(#f, :null)",
+            ),
+            (
+                "SET #f = :by",
+                "attribute_exists(id) AND attribute_type(#f, :null)",
+            ),
+        ] {
+            match update(expression, condition).await {
+                Ok(out) => {
+                    return serde_dynamo::from_item(out.attributes.unwrap_or_default())
+                        .map_err(ApiError::internal)
+                }
+                Err(SdkError::ServiceError(e))
+                    if e.err().is_conditional_check_failed_exception() => {}
+                Err(e) => return Err(ApiError::dynamo(e)),
+            }
+        }
+    }
+
+    Err(ApiError::NotFound)
+}
@@ -0,0 +1,93 @@
+use crate::{
+    api::common::ApiError,
+    models::{
+        schedule::ScheduledJob,
+        survey::{SurveyAction, SurveyStatus, SurveySummary},
+    },
+};
+
+use super::{audit, database};
+
+pub const SYSTEM_ACTOR: &str = "system";
+
+// transition moves a survey to the status after `action`, records it in the audit trail
+// and stores the survey. A survey running until an end time is closed then by the scheduler.
+pub async fn transition(
+    survey: &mut SurveySummary,
+    action: SurveyAction,
+    ended_at: Option<u64>,
+    actor: &str,
+    now: u64,
+) -> Result<(), ApiError> {
+    if survey.archived_at.is_some() {
+        return Err(ApiError::InvalidTransition);
+    }
+    if action == SurveyAction::Launch && survey.questions == 0 {
+        return Err(ApiError::InvalidInput("questions".to_string()));
+    }
+
+    let next = survey
+        .status
+        .transition(action, ended_at, now)
+        .ok_or(ApiError::InvalidTransition)?;
+    if let SurveyStatus::InProgress {
+        started_at,
+        ended_at: Some(ended_at),
+    } = next
+    {
+        if ended_at <= started_at || ended_at <= now {
+            return Err(ApiError::InvalidInput("ended_at".to_string()));
+        }
+        database::put(&ScheduledJob::new(
+            &survey.id,
+            SurveyAction::Close,
+            ended_at,
+            now,
+        ))
+        .await?;
+    }
+
+    apply(survey, next, actor, &format!("{:?}", action), now).await
+}
+
+// close_if_due finishes a survey which passed its end time or filled its quotas.
+pub async fn close_if_due(survey: &mut SurveySummary, now: u64) -> Result<bool, ApiError> {
+    if !survey.should_close(now) {
+        return Ok(false);
+    }
+
+    apply(
+        survey,
+        SurveyStatus::Finished,
+        SYSTEM_ACTOR,
+        "auto close",
+        now,
+    )
+    .await?;
+
+    Ok(true)
+}
+
+async fn apply(
+    survey: &mut SurveySummary,
+    next: SurveyStatus,
+    actor: &str,
+    reason: &str,
+    now: u64,
+) -> Result<(), ApiError> {
+    let previous = survey.status.name();
+    survey.status = next;
+    survey.gsi2 = survey.status_index();
+    survey.updated_at = now;
+    // the response count may be added to meanwhile, so only the status is written
+    database::put_fields(&*survey, &["status", "gsi2", "updated_at"]).await?;
+
+    audit::record(
+        "survey_transition",
+        actor,
+        &survey.id,
+        format!("{previous} -> {} ({reason})", survey.status.name()),
+        now,
+    )
+    .await
+}
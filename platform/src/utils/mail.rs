@@ -0,0 +1,167 @@
+use std::{
+    io::Write,
+    sync::{Mutex, OnceLock},
+};
+
+use async_trait::async_trait;
+use dioxus_logger::tracing;
+use lettre::{
+    message::Mailbox, transport::smtp::authentication::Credentials, AsyncSmtpTransport,
+    AsyncTransport, Message, Tokio1Executor,
+};
+
+use crate::api::common::ApiError;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mail {
+    pub to: String,
+    pub subject: String,
+    pub body: String,
+}
+
+#[async_trait]
+pub trait MailSender: Send + Sync {
+    async fn send(&self, mail: Mail) -> Result<(), ApiError>;
+}
+
+pub struct SmtpMailSender {
+    from: Mailbox,
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+}
+
+impl SmtpMailSender {
+    pub fn new(
+        host: &str,
+        username: String,
+        password: String,
+        from: &str,
+    ) -> Result<Self, ApiError> {
+        let transport = AsyncSmtpTransport::<Tokio1Executor>::relay(host)
+            .map_err(ApiError::internal)?
+            .credentials(Credentials::new(username, password))
+            .build();
+        let from = from.parse().map_err(ApiError::internal)?;
+
+        Ok(SmtpMailSender { from, transport })
+    }
+}
+
+#[async_trait]
+impl MailSender for SmtpMailSender {
+    async fn send(&self, mail: Mail) -> Result<(), ApiError> {
+        let to: Mailbox = mail.to.parse().map_err(ApiError::internal)?;
+        let message = Message::builder()
+            .from(self.from.clone())
+            .to(to)
+            .subject(mail.subject)
+            .body(mail.body)
+            .map_err(ApiError::internal)?;
+
+        self.transport
+            .send(message)
+            .await
+            .map_err(ApiError::internal)?;
+
+        Ok(())
+    }
+}
+
+// FileMailSender appends mails to a local file instead of delivering them.
+pub struct FileMailSender {
+    path: String,
+}
+
+impl FileMailSender {
+    pub fn new(path: &str) -> Self {
+        FileMailSender {
+            path: path.to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl MailSender for FileMailSender {
+    async fn send(&self, mail: Mail) -> Result<(), ApiError> {
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .map_err(ApiError::internal)?;
+
+        writeln!(
+            file,
+            "To: {}\nSubject: {}\n\n{}\n---",
+            mail.to, mail.subject, mail.body
+        )
+        .map_err(ApiError::internal)
+    }
+}
+
+// MemoryMailSender keeps mails in memory so that they can be inspected.
+#[derive(Default)]
+pub struct MemoryMailSender {
+    pub outbox: Mutex<Vec<Mail>>,
+}
+
+#[async_trait]
+impl MailSender for MemoryMailSender {
+    async fn send(&self, mail: Mail) -> Result<(), ApiError> {
+        self.outbox.lock().map_err(ApiError::internal)?.push(mail);
+
+        Ok(())
+    }
+}
+
+static MAIL_SENDER: OnceLock<Result<Box<dyn MailSender>, String>> = OnceLock::new();
+
+// new_mail_sender is selected by MAIL_SENDER (smtp, file or memory).
+// SMTP uses SMTP_HOST, SMTP_USERNAME, SMTP_PASSWORD and MAIL_FROM.
+// Only debug builds fall back to writing mails to MAIL_FILE, so that a deployed server
+// can not leave verification codes in a file instead of sending them.
+fn new_mail_sender() -> Result<Box<dyn MailSender>, String> {
+    let env = |key: &str, default: &str| std::env::var(key).unwrap_or(default.to_string());
+    let kind = match std::env::var("MAIL_SENDER") {
+        Ok(kind) => kind,
+        Err(_) if cfg!(debug_assertions) => "file".to_string(),
+        Err(_) => return Err("MAIL_SENDER is not set".to_string()),
+    };
+
+    match kind.as_str() {
+        "smtp" => {
+            let host = env("SMTP_HOST", "");
+            if host.is_empty() {
+                return Err("SMTP_HOST is not set".to_string());
+            }
+            let sender = SmtpMailSender::new(
+                &host,
+                env("SMTP_USERNAME", ""),
+                env("SMTP_PASSWORD", ""),
+                &env("MAIL_FROM", "Voice Korea <no-reply@biyard.co>"),
+            )
+            .map_err(|_| "failed to initialize smtp mail sender".to_string())?;
+
+            Ok(Box::new(sender))
+        }
+        "memory" => Ok(Box::new(MemoryMailSender::default())),
+        "file" => {
+            let path = env("MAIL_FILE", "mails.log");
+            tracing::info!("mails will be written to {path}");
+
+            Ok(Box::new(FileMailSender::new(&path)))
+        }
+        kind => Err(format!("unknown MAIL_SENDER: {kind}")),
+    }
+}
+
+pub fn mail_sender() -> Result<&'static dyn MailSender, ApiError> {
+    MAIL_SENDER
+        .get_or_init(new_mail_sender)
+        .as_ref()
+        .map(|sender| sender.as_ref())
+        .map_err(ApiError::internal)
+}
+
+// init checks MAIL_SENDER so that the server refuses to start without a way to send mails.
+pub fn init() -> Result<(), ApiError> {
+    mail_sender().map(|_| ())
+}
@@ -0,0 +1,90 @@
+use crate::{
+    api::common::{ApiError, CommonQueryResponse},
+    models::{
+        organization::Organization,
+        role::{Role, RoleBinding},
+        user::User,
+    },
+};
+
+use super::database;
+
+// create makes a new organization owned by `user_id`.
+pub async fn create(name: &str, user_id: &str, now: u64) -> Result<Organization, ApiError> {
+    let organization = Organization {
+        id: database::new_id(Organization::ID_PREFIX),
+        r#type: "organization".to_string(),
+        name: name.trim().to_string(),
+        created_by: user_id.to_string(),
+        created_at: now,
+        updated_at: now,
+    };
+    database::put(&organization).await?;
+    database::put(&RoleBinding::new(
+        &organization.id,
+        user_id,
+        Role::Owner,
+        now,
+    ))
+    .await?;
+
+    Ok(organization)
+}
+
+pub async fn members(organization_id: &str) -> Result<Vec<RoleBinding>, ApiError> {
+    let res = CommonQueryResponse::<RoleBinding>::query(
+        &database::log(),
+        "gsi2-index",
+        None,
+        Some(100),
+        vec![("gsi2", RoleBinding::gsi2(organization_id))],
+    )
+    .await
+    .map_err(ApiError::dynamo)?;
+
+    Ok(res.items)
+}
+
+// ensure_other_owner fails if `user_id` is the only owner of the organization,
+// so that an organization is never left without an owner.
+pub async fn ensure_other_owner(organization_id: &str, user_id: &str) -> Result<(), ApiError> {
+    let owners = members(organization_id)
+        .await?
+        .into_iter()
+        .filter(|m| m.role == Role::Owner && m.user_id != user_id)
+        .count();
+    if owners == 0 {
+        return Err(ApiError::LastOwner);
+    }
+
+    Ok(())
+}
+
+// detach moves a user who no longer belongs to `organization_id` to another organization
+// of theirs, or to none, so that the user stops working in the organization.
+pub async fn detach(user_id: &str, organization_id: &str, now: u64) -> Result<(), ApiError> {
+    let mut user = match database::get::<User>(user_id).await? {
+        Some(user) if user.organization_id == organization_id => user,
+        _ => return Ok(()),
+    };
+
+    let bindings = CommonQueryResponse::<RoleBinding>::query(
+        &database::log(),
+        "gsi1-index",
+        None,
+        Some(100),
+        vec![("gsi1", RoleBinding::gsi1(user_id))],
+    )
+    .await
+    .map_err(ApiError::dynamo)?;
+    // the index may still list the removed binding
+    user.organization_id = bindings
+        .items
+        .into_iter()
+        .map(|b| b.resource_id)
+        .find(|id| id != organization_id && Organization::is_organization_id(id))
+        .unwrap_or_default();
+    user.updated_at = now;
+
+    database::put_fields(&user, &["organization_id", "updated_at"]).await
+}
@@ -0,0 +1,76 @@
+use std::sync::OnceLock;
+
+use argon2::{
+    password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString},
+    Argon2,
+};
+use rand::RngCore;
+
+use crate::api::common::ApiError;
+
+static DUMMY_HASH: OnceLock<String> = OnceLock::new();
+
+// hash_password returns the Argon2id hash of `password` in the PHC string format,
+// such as `$argon2id$v=19$m=19456,t=2,p=1$<salt>$<hash>`, which keeps the salt and
+// the parameters with the hash so that they can be raised without breaking stored ones.
+pub fn hash_password(password: &str) -> Result<String, ApiError> {
+    let mut salt = [0u8; 16];
+    rand::thread_rng().fill_bytes(&mut salt);
+    let salt = SaltString::encode_b64(&salt).map_err(ApiError::internal)?;
+
+    Argon2::default()
+        .hash_password(password.as_bytes(), &salt)
+        .map(|hashed| hashed.to_string())
+        .map_err(ApiError::internal)
+}
+
+// verify_password checks `password` against `hashed` with the parameters stored in it.
+pub fn verify_password(password: &str, hashed: &str) -> bool {
+    match PasswordHash::new(hashed) {
+        Ok(hashed) => Argon2::default()
+            .verify_password(password.as_bytes(), &hashed)
+            .is_ok(),
+        Err(_) => false,
+    }
+}
+
+// dummy_hash is checked against when there is no stored hash, such as for an unknown email,
+// so that the response takes as long as for a known one.
+pub fn dummy_hash() -> &'static str {
+    DUMMY_HASH.get_or_init(|| hash_password("dummy-password").unwrap_or_default())
+}
+
+pub fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+
+    a.iter()
+        .zip(b.iter())
+        .fold(0u8, |acc, (x, y)| acc | (x ^ y))
+        == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn verify_hashed_password() {
+        let hashed = hash_password("secret-1!").unwrap();
+
+        assert!(hashed.starts_with("$argon2id$"));
+        assert!(verify_password("secret-1!", &hashed));
+        assert!(!verify_password("secret-2!", &hashed));
+        assert!(!verify_password("secret-1!", dummy_hash()));
+        assert!(!verify_password("secret-1!", "not a hash"));
+    }
+
+    #[test]
+    fn hashes_are_salted() {
+        assert_ne!(
+            hash_password("secret-1!").unwrap(),
+            hash_password("secret-1!").unwrap()
+        );
+    }
+}
@@ -0,0 +1,273 @@
+use std::collections::HashSet;
+
+use crate::{
+    api::common::{ApiError, CommonQueryResponse},
+    models::{
+        question::{
+            carry_options, check, check_templates, renumber_question, LogicError, Question,
+            QuestionType, MAX_QUESTIONS,
+        },
+        role::Permission,
+        survey::SurveySummary,
+        user::User,
+    },
+};
+
+use super::{authz, database};
+
+// list returns the questions of a survey in a stable order,
+// which is also the order of the answers in a response.
+pub async fn list(survey_id: &str) -> Result<Vec<Question>, ApiError> {
+    let mut questions = vec![];
+    let mut bookmark = None;
+    loop {
+        let page = CommonQueryResponse::<Question>::query(
+            &database::log(),
+            "gsi1-index",
+            bookmark,
+            Some(MAX_QUESTIONS as i32),
+            vec![("gsi1", survey_id.to_string())],
+        )
+        .await
+        .map_err(ApiError::dynamo)?;
+        questions.extend(page.items);
+
+        bookmark = page.bookmark;
+        if bookmark.is_none() {
+            break;
+        }
+    }
+    questions.sort_by(|a, b| (a.position, &a.id).cmp(&(b.position, &b.id)));
+
+    Ok(questions)
+}
+
+// load_editable loads a survey whose questions `user` may change.
+pub async fn load_editable(user: &User, survey_id: &str) -> Result<SurveySummary, ApiError> {
+    let survey = authz::load_survey(user, survey_id, Permission::Edit).await?;
+    if survey.archived_at.is_some() || survey.questions_locked() {
+        return Err(ApiError::InvalidTransition);
+    }
+
+    Ok(survey)
+}
+
+// validate checks a question before it is stored; `field` names it in the error.
+pub fn validate(field: &str, title: &str, question: &QuestionType) -> Result<(), ApiError> {
+    if title.trim().is_empty() {
+        return Err(ApiError::InvalidInput(format!("{field}.title")));
+    }
+    if !question.is_valid() {
+        return Err(ApiError::InvalidInput(format!("{field}.question")));
+    }
+
+    Ok(())
+}
+
+// validate_all checks `questions` as they are stored: their number, each question and their logic.
+// Every path storing questions runs it, so that stored questions are always valid.
+pub fn validate_all(questions: &[Question]) -> Result<(), ApiError> {
+    // a question sent twice would be stored once but counted twice
+    let mut ids = HashSet::new();
+    if questions.len() > MAX_QUESTIONS || !questions.iter().all(|q| ids.insert(&q.id)) {
+        return Err(ApiError::InvalidInput("questions".to_string()));
+    }
+    for (i, q) in questions.iter().enumerate() {
+        validate(&format!("questions[{i}]"), &q.title, &q.question)?;
+    }
+
+    validate_logic(questions)
+}
+
+// validate_logic checks the display conditions and jumps of `questions`, in their order.
+pub fn validate_logic(questions: &[Question]) -> Result<(), ApiError> {
+    check(questions).map_err(|e| {
+        ApiError::InvalidInput(match e {
+            LogicError::Display(i) => format!("questions[{i}].logic.display"),
+            LogicError::Jump(i) | LogicError::Cycle(i) => format!("questions[{i}].logic.jumps"),
+        })
+    })?;
+    check_templates(questions)
+        .map_err(|i| ApiError::InvalidInput(format!("questions[{i}].logic.templates")))
+}
+
+// renumber keeps the `{Qn}` references and carried forward options of `questions`
+// on the same questions after they are inserted, removed or moved from `before`.
+pub fn renumber(questions: &mut [Question], before: &[Question]) {
+    let ids: Vec<String> = questions.iter().map(|q| q.id.clone()).collect();
+    let map = |n: usize| {
+        let id = &before.get(n.checked_sub(1)?)?.id;
+        ids.iter().position(|i| i == id).map(|i| i + 1)
+    };
+    for q in questions.iter_mut() {
+        renumber_question(&mut q.title, &mut q.question, &mut q.carry_forward, &map);
+    }
+}
+
+// sync numbers `questions` by their order, copies the options they carry forward, stores the ones
+// changed from `existing`, deletes the existing ones left out and stores the question count of the survey,
+// all in one transaction. It fails with `ApiError::Conflict` when another request changed the survey
+// or a response to it was stored since it was loaded, so `existing` must be listed after loading it.
+// Changes which do not fit in one transaction are rejected as `ApiError::InvalidInput("questions")`.
+pub async fn sync(
+    survey: &mut SurveySummary,
+    questions: &mut [Question],
+    existing: &[Question],
+    now: u64,
+) -> Result<(), ApiError> {
+    for (position, question) in questions.iter_mut().enumerate() {
+        question.position = position as u32;
+    }
+    carry_options(questions, |q| q.carry_forward, |q| &mut q.question);
+    let changed: Vec<&Question> = questions.iter().filter(|q| !existing.contains(q)).collect();
+    let removed: Vec<&str> = existing
+        .iter()
+        .filter(|q| !questions.iter().any(|kept| kept.id == q.id))
+        .map(|q| q.id.as_str())
+        .collect();
+    if changed.len() + removed.len() >= database::MAX_TRANSACTION_WRITES {
+        return Err(ApiError::InvalidInput("questions".to_string()));
+    }
+
+    let loaded = survey.updated_at;
+    survey.questions = questions.len() as u64;
+    // the next sync checks it, so it changes even within the same second
+    survey.updated_at = now.max(loaded + 1);
+    database::transact(
+        &changed,
+        &removed,
+        &*survey,
+        &["questions", "updated_at"],
+        "updated_at",
+        loaded,
+        // the questions of a survey with responses are locked
+        &["responses"],
+    )
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn question(question: QuestionType) -> Question {
+        Question {
+            id: "question-1".to_string(),
+            r#type: "question".to_string(),
+            survey_id: "survey-1".to_string(),
+            title: "title".to_string(),
+            question,
+            required: false,
+            position: 0,
+            logic: Default::default(),
+            carry_forward: None,
+            gsi1: "survey-1".to_string(),
+        }
+    }
+
+    fn invalid(question: QuestionType) -> bool {
+        validate_all(&[self::question(question)])
+            == Err(ApiError::InvalidInput("questions[0].question".to_string()))
+    }
+
+    #[test]
+    fn empty_scales_are_rejected() {
+        assert!(invalid(QuestionType::Likert {
+            question: None,
+            points: 0,
+            min_label: "no".to_string(),
+            max_label: "yes".to_string(),
+        }));
+        assert!(invalid(QuestionType::Rating {
+            question: None,
+            max: 0
+        }));
+    }
+
+    #[test]
+    fn invalid_pattern_is_rejected() {
+        assert!(invalid(QuestionType::Pattern {
+            question: None,
+            pattern: "(".to_string(),
+        }));
+    }
+
+    #[test]
+    fn question_sent_twice_is_rejected() {
+        let mut questions = vec![question(QuestionType::Text(None)); 2];
+
+        assert_eq!(
+            validate_all(&questions),
+            Err(ApiError::InvalidInput("questions".to_string()))
+        );
+        questions[1].id = "question-2".to_string();
+        assert!(validate_all(&questions).is_ok());
+    }
+
+    #[test]
+    fn too_many_questions_are_rejected() {
+        let questions: Vec<Question> = (0..=MAX_QUESTIONS)
+            .map(|i| Question {
+                id: format!("question-{i}"),
+                ..question(QuestionType::Text(None))
+            })
+            .collect();
+
+        assert!(validate_all(&questions[..MAX_QUESTIONS]).is_ok());
+        assert_eq!(
+            validate_all(&questions),
+            Err(ApiError::InvalidInput("questions".to_string()))
+        );
+    }
+
+    // questions numbered from 1 with `titles`; the last one carries forward the first
+    fn numbered(titles: &[&str]) -> Vec<Question> {
+        let mut questions: Vec<Question> = titles
+            .iter()
+            .enumerate()
+            .map(|(i, title)| Question {
+                id: format!("question-{}", i + 1),
+                title: title.to_string(),
+                ..question(QuestionType::Text(None))
+            })
+            .collect();
+        if let Some(last) = questions.last_mut() {
+            last.carry_forward = Some(1);
+        }
+
+        questions
+    }
+
+    fn titles(questions: &[Question]) -> Vec<&str> {
+        questions.iter().map(|q| q.title.as_str()).collect()
+    }
+
+    #[test]
+    fn references_follow_moved_questions() {
+        let before = numbered(&["first", "after {Q1}", "after {Q2} and {Q1}"]);
+        let mut questions = before.clone();
+        let moved = questions.remove(0);
+        questions.push(moved);
+
+        renumber(&mut questions, &before);
+
+        assert_eq!(
+            titles(&questions),
+            ["after {Q3}", "after {Q1} and {Q3}", "first"]
+        );
+        assert_eq!(questions[1].carry_forward, Some(3));
+    }
+
+    #[test]
+    fn references_to_deleted_questions_are_rejected() {
+        let before = numbered(&["first", "after {Q1}", "after {Q2} and {Q1}"]);
+        let mut questions = before.clone();
+        questions.remove(0);
+
+        renumber(&mut questions, &before);
+
+        assert_eq!(titles(&questions), ["after {Q0}", "after {Q1} and {Q0}"]);
+        assert_eq!(questions[1].carry_forward, Some(0));
+        assert!(validate_logic(&questions).is_err());
+    }
+}
@@ -0,0 +1,134 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use dioxus_logger::tracing;
+
+use crate::{
+    api::common::{ApiError, CommonQueryResponse},
+    models::{
+        schedule::ScheduledJob,
+        survey::{SurveyAction, SurveySummary},
+    },
+};
+
+use super::{database, lifecycle};
+
+pub const SCHEDULER_ACTOR: &str = "scheduler";
+pub const INTERVAL_SECONDS: u64 = 60;
+
+pub trait Clock: Send + Sync {
+    fn now(&self) -> u64;
+}
+
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> u64 {
+        chrono::Utc::now().timestamp() as u64
+    }
+}
+
+// FakeClock is a manually advanced clock to run the scheduler at a given time in tests.
+pub struct FakeClock(AtomicU64);
+
+impl FakeClock {
+    pub fn new(now: u64) -> Self {
+        FakeClock(AtomicU64::new(now))
+    }
+
+    pub fn set(&self, now: u64) {
+        self.0.store(now, Ordering::SeqCst);
+    }
+
+    pub fn advance(&self, seconds: u64) {
+        self.0.fetch_add(seconds, Ordering::SeqCst);
+    }
+}
+
+impl Clock for FakeClock {
+    fn now(&self) -> u64 {
+        self.0.load(Ordering::SeqCst)
+    }
+}
+
+// run_due applies every pending job whose time has come and returns how many ran.
+// A job whose transition is no longer valid, e.g. a survey launched by hand, is dropped.
+pub async fn run_due(clock: &dyn Clock) -> Result<usize, ApiError> {
+    let now = clock.now();
+    let mut bookmark = None;
+    let mut ran = 0;
+
+    loop {
+        let res = CommonQueryResponse::<ScheduledJob>::query(
+            &database::log(),
+            "gsi1-index",
+            bookmark,
+            Some(100),
+            vec![("gsi1", ScheduledJob::PENDING)],
+        )
+        .await
+        .map_err(ApiError::dynamo)?;
+
+        for job in res.items.into_iter().filter(|j| j.run_at <= now) {
+            run(&job, now).await?;
+            database::delete(&job.id).await?;
+            ran += 1;
+        }
+
+        match res.bookmark {
+            Some(next) => bookmark = Some(next),
+            None => break,
+        }
+    }
+
+    Ok(ran)
+}
+
+async fn run(job: &ScheduledJob, now: u64) -> Result<(), ApiError> {
+    let mut survey = match database::get_typed::<SurveySummary>(&job.survey_id, "survey").await? {
+        Some(survey) => survey,
+        None => return Ok(()),
+    };
+    // a survey past its end or with its quotas filled closes before any other action
+    if lifecycle::close_if_due(&mut survey, now).await? {
+        tracing::info!("closed {} when due", survey.id);
+    }
+    let ended_at = match job.action {
+        SurveyAction::Launch => survey.schedule.and_then(|s| s.end_at),
+        _ => None,
+    };
+
+    match lifecycle::transition(&mut survey, job.action, ended_at, SCHEDULER_ACTOR, now).await {
+        Ok(_) => tracing::info!("scheduled {:?} of {}", job.action, survey.id),
+        Err(e @ ApiError::DynamoException(_)) => return Err(e),
+        Err(e) => tracing::warn!(
+            "skipped scheduled {:?} of {}: {:?}",
+            job.action,
+            survey.id,
+            e
+        ),
+    }
+
+    Ok(())
+}
+
+// spawn runs the scheduler every minute on a background thread.
+// It is used by the long-running server; the lambda build is triggered by `run_scheduler` instead.
+pub fn spawn() {
+    std::thread::spawn(|| {
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .expect("failed to build scheduler runtime");
+
+        rt.block_on(async {
+            let mut interval =
+                tokio::time::interval(std::time::Duration::from_secs(INTERVAL_SECONDS));
+            loop {
+                interval.tick().await;
+                if let Err(e) = run_due(&SystemClock).await {
+                    tracing::error!("scheduler failed: {:?}", e);
+                }
+            }
+        });
+    });
+}
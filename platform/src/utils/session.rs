@@ -0,0 +1,296 @@
+use std::sync::OnceLock;
+
+use dioxus::prelude::*;
+use dioxus_logger::tracing;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use super::{database, password::constant_time_eq};
+use crate::{api::common::ApiError, models::user::User};
+
+pub const SESSION_COOKIE: &str = "voice_korea_session";
+pub const SESSION_TTL: u64 = 12 * 60 * 60;
+pub const SECRET_MIN_LENGTH: usize = 32;
+
+static SECRET: OnceLock<Result<String, String>> = OnceLock::new();
+
+// secret is SESSION_SECRET; there is no default, so that a misconfigured server
+// can not issue sessions anyone could forge.
+fn secret() -> Result<&'static [u8], ApiError> {
+    SECRET
+        .get_or_init(|| match std::env::var("SESSION_SECRET") {
+            Ok(secret) if secret.len() >= SECRET_MIN_LENGTH => Ok(secret),
+            Ok(_) => Err(format!(
+                "SESSION_SECRET must be at least {SECRET_MIN_LENGTH} bytes"
+            )),
+            Err(_) => Err("SESSION_SECRET is not set".to_string()),
+        })
+        .as_ref()
+        .map(|secret| secret.as_bytes())
+        .map_err(ApiError::internal)
+}
+
+// init checks SESSION_SECRET so that the server refuses to start without it.
+pub fn init() -> Result<(), ApiError> {
+    secret().map(|_| ())
+}
+
+fn sign(payload: &str) -> Result<String, ApiError> {
+    sign_with(secret()?, payload)
+}
+
+fn sign_with(key: &[u8], payload: &str) -> Result<String, ApiError> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).map_err(ApiError::internal)?;
+    mac.update(payload.as_bytes());
+
+    Ok(hex::encode(mac.finalize().into_bytes()))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Session {
+    pub user_id: String,
+    pub issued_at: u64,
+    pub expires_at: u64,
+}
+
+impl Session {
+    pub fn new(user_id: &str, now: u64) -> Self {
+        Session {
+            user_id: user_id.to_string(),
+            issued_at: now,
+            expires_at: now + SESSION_TTL,
+        }
+    }
+
+    // A token is `{user_id}.{issued_at}.{expires_at}.{signature}`.
+    pub fn token(&self) -> Result<String, ApiError> {
+        let payload = format!("{}.{}.{}", self.user_id, self.issued_at, self.expires_at);
+        let signature = sign(&payload)?;
+
+        Ok(format!("{payload}.{signature}"))
+    }
+
+    pub fn from_token(token: &str, now: u64) -> Option<Self> {
+        let (payload, signature) = token.rsplit_once('.')?;
+        if !constant_time_eq(sign(payload).ok()?.as_bytes(), signature.as_bytes()) {
+            return None;
+        }
+
+        let mut parts = payload.split('.');
+        let session = Session {
+            user_id: parts.next()?.to_string(),
+            issued_at: parts.next()?.parse().ok()?,
+            expires_at: parts.next()?.parse().ok()?,
+        };
+
+        if parts.next().is_some() || session.expires_at <= now {
+            return None;
+        }
+
+        Some(session)
+    }
+
+    // for_user issues a session that outlives the last revocation of `user`,
+    // even if it is issued within the same second.
+    pub fn for_user(user: &User, now: u64) -> Self {
+        Session::new(&user.id, now.max(user.sessions_valid_after + 1))
+    }
+
+    // is_revoked is true if the session was issued before or at the time the user revoked sessions.
+    pub fn is_revoked(&self, user: &User) -> bool {
+        self.issued_at <= user.sessions_valid_after
+    }
+}
+
+// Respondent is who answers a survey without signing in. `get_survey_form` issues its proof id
+// in a signed token, so that `answer_survey` does not take a proof id made up by the client.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Respondent {
+    pub survey_id: String,
+    pub proof_id: String,
+}
+
+impl Respondent {
+    // sign signs with a key derived from SESSION_SECRET for respondents,
+    // so that a respondent token never passes for a session or the other way around.
+    fn sign(payload: &str) -> Result<String, ApiError> {
+        let key = hex::decode(sign("respondent")?).map_err(ApiError::internal)?;
+
+        sign_with(&key, payload)
+    }
+
+    // A token is `respondent:{survey_id}.{proof_id}.{signature}`.
+    pub fn token(&self) -> Result<String, ApiError> {
+        let payload = format!("respondent:{}.{}", self.survey_id, self.proof_id);
+        let signature = Self::sign(&payload)?;
+
+        Ok(format!("{payload}.{signature}"))
+    }
+
+    pub fn from_token(token: &str) -> Option<Self> {
+        let (payload, signature) = token.rsplit_once('.')?;
+        if !constant_time_eq(Self::sign(payload).ok()?.as_bytes(), signature.as_bytes()) {
+            return None;
+        }
+
+        let (survey_id, proof_id) = payload.strip_prefix("respondent:")?.split_once('.')?;
+
+        Some(Respondent {
+            survey_id: survey_id.to_string(),
+            proof_id: proof_id.to_string(),
+        })
+    }
+}
+
+pub fn set_session_cookie(session: &Session) -> Result<(), ApiError> {
+    set_cookie(format!(
+        "{SESSION_COOKIE}={}; Path=/; Max-Age={SESSION_TTL}; HttpOnly; Secure; SameSite=Lax",
+        session.token()?
+    ));
+
+    Ok(())
+}
+
+pub fn clear_session_cookie() {
+    set_cookie(format!(
+        "{SESSION_COOKIE}=; Path=/; Max-Age=0; HttpOnly; Secure; SameSite=Lax"
+    ));
+}
+
+fn set_cookie(cookie: String) {
+    match http::HeaderValue::from_str(&cookie) {
+        Ok(value) => {
+            server_context()
+                .response_parts_mut()
+                .headers
+                .append(http::header::SET_COOKIE, value);
+        }
+        Err(e) => tracing::error!("failed to build session cookie: {:?}", e),
+    }
+}
+
+// session_token reads the session token from the cookies of the current request.
+pub fn session_token() -> Option<String> {
+    let ctx = server_context();
+    let req = ctx.request_parts();
+
+    req.headers
+        .get_all(http::header::COOKIE)
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .flat_map(|v| v.split(';'))
+        .filter_map(|c| c.trim().split_once('='))
+        .find(|(name, _)| *name == SESSION_COOKIE)
+        .map(|(_, value)| value.to_string())
+}
+
+// current_user authenticates the current request by its session cookie.
+// Every server function that needs a signed-in user must start with it
+// so that unauthenticated calls are rejected with `ApiError::Unauthorized`.
+pub async fn current_user(now: u64) -> Result<User, ApiError> {
+    let token = session_token().ok_or(ApiError::Unauthorized)?;
+
+    authenticate(&token, now).await
+}
+
+// authenticate returns the user of a session token unless it is invalid, expired or revoked.
+pub async fn authenticate(token: &str, now: u64) -> Result<User, ApiError> {
+    let session = Session::from_token(token, now).ok_or(ApiError::Unauthorized)?;
+
+    let user = database::get::<User>(&session.user_id)
+        .await?
+        .ok_or(ApiError::Unauthorized)?;
+    if session.is_revoked(&user) {
+        return Err(ApiError::Unauthorized);
+    }
+
+    Ok(user)
+}
+
+// revoke invalidates the sessions issued to `user` so far, on every device.
+pub async fn revoke(user: &mut User, now: u64) -> Result<(), ApiError> {
+    user.sessions_valid_after = now;
+    user.updated_at = now;
+
+    database::put(&*user).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn init() {
+        std::env::set_var("SESSION_SECRET", "a-session-secret-for-the-unit-tests");
+        super::init().unwrap();
+    }
+
+    #[test]
+    fn token_round_trip() {
+        init();
+        let session = Session::new("user-1", 100);
+        let token = session.token().unwrap();
+
+        assert_eq!(Session::from_token(&token, 100), Some(session));
+    }
+
+    #[test]
+    fn tampered_token_is_rejected() {
+        init();
+        let token = Session::new("user-1", 100).token().unwrap();
+        let forged = token.replacen("user-1", "user-2", 1);
+
+        assert_eq!(Session::from_token(&forged, 100), None);
+        assert_eq!(Session::from_token("user-1.100.200", 100), None);
+    }
+
+    #[test]
+    fn expired_token_is_rejected() {
+        init();
+        let token = Session::new("user-1", 100).token().unwrap();
+
+        assert!(Session::from_token(&token, 100 + SESSION_TTL - 1).is_some());
+        assert_eq!(Session::from_token(&token, 100 + SESSION_TTL), None);
+    }
+
+    #[test]
+    fn respondent_token_round_trip() {
+        init();
+        let respondent = Respondent {
+            survey_id: "survey-1".to_string(),
+            proof_id: "proof.1".to_string(),
+        };
+        let token = respondent.token().unwrap();
+
+        assert_eq!(Respondent::from_token(&token), Some(respondent));
+        assert_eq!(
+            Respondent::from_token(&token.replacen("survey-1", "survey-2", 1)),
+            None
+        );
+    }
+
+    #[test]
+    fn session_and_respondent_tokens_are_apart() {
+        init();
+        let session = Session::new("user-1", 100).token().unwrap();
+        let respondent = Respondent {
+            survey_id: "survey-1".to_string(),
+            proof_id: "1.2".to_string(),
+        }
+        .token()
+        .unwrap();
+
+        assert_eq!(Respondent::from_token(&session), None);
+        assert_eq!(Session::from_token(&respondent, 1), None);
+    }
+
+    #[test]
+    fn revoked_session() {
+        let session = Session::new("user-1", 100);
+        let mut user = User::default();
+
+        assert!(!session.is_revoked(&user));
+        user.sessions_valid_after = 100;
+        assert!(session.is_revoked(&user));
+        assert!(!Session::for_user(&user, 100).is_revoked(&user));
+    }
+}
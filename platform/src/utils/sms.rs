@@ -0,0 +1,215 @@
+use std::sync::OnceLock;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::api::common::ApiError;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sms {
+    // digits only, see `normalize_phone`
+    pub to: String,
+    pub body: String,
+}
+
+#[async_trait]
+pub trait SmsSender: Send + Sync {
+    async fn send(&self, sms: Sms) -> Result<(), ApiError>;
+}
+
+// NhnSmsSender delivers messages with the NHN Cloud SMS API.
+// `from` must be a sender number registered to the app.
+pub struct NhnSmsSender {
+    url: String,
+    secret_key: String,
+    from: String,
+    client: reqwest::Client,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct NhnSmsRequest<'a> {
+    body: &'a str,
+    send_no: &'a str,
+    recipient_list: Vec<NhnSmsRecipient<'a>>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct NhnSmsRecipient<'a> {
+    recipient_no: &'a str,
+}
+
+#[derive(Deserialize)]
+struct NhnSmsResponse {
+    header: NhnSmsResponseHeader,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct NhnSmsResponseHeader {
+    is_successful: bool,
+    result_code: i64,
+    result_message: String,
+}
+
+impl NhnSmsSender {
+    pub fn new(app_key: &str, secret_key: String, from: String) -> Self {
+        NhnSmsSender {
+            url: format!("https://api-sms.cloud.toast.com/sms/v3.0/appKeys/{app_key}/sender/sms"),
+            secret_key,
+            from,
+            client: reqwest::Client::new(),
+        }
+    }
+}
+
+#[async_trait]
+impl SmsSender for NhnSmsSender {
+    async fn send(&self, sms: Sms) -> Result<(), ApiError> {
+        let res: NhnSmsResponse = self
+            .client
+            .post(&self.url)
+            .header("X-Secret-Key", &self.secret_key)
+            .json(&NhnSmsRequest {
+                body: &sms.body,
+                send_no: &self.from,
+                recipient_list: vec![NhnSmsRecipient {
+                    recipient_no: &sms.to,
+                }],
+            })
+            .send()
+            .await
+            .map_err(ApiError::internal)?
+            .json()
+            .await
+            .map_err(ApiError::internal)?;
+
+        let header = res.header;
+        if !header.is_successful {
+            return Err(ApiError::internal(format!(
+                "nhn sms failed with {}: {}",
+                header.result_code, header.result_message
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+// FakeSmsSender keeps the latest messages in memory instead of delivering them.
+// It is only built for debug builds and tests.
+#[cfg(any(test, debug_assertions))]
+#[derive(Default)]
+pub struct FakeSmsSender {
+    pub outbox: std::sync::Mutex<std::collections::VecDeque<Sms>>,
+}
+
+#[cfg(any(test, debug_assertions))]
+impl FakeSmsSender {
+    pub const OUTBOX_SIZE: usize = 100;
+}
+
+#[cfg(any(test, debug_assertions))]
+#[async_trait]
+impl SmsSender for FakeSmsSender {
+    async fn send(&self, sms: Sms) -> Result<(), ApiError> {
+        let mut outbox = self.outbox.lock().map_err(ApiError::internal)?;
+        if outbox.len() >= Self::OUTBOX_SIZE {
+            outbox.pop_front();
+        }
+        outbox.push_back(sms);
+
+        Ok(())
+    }
+}
+
+static SMS_SENDER: OnceLock<Result<Box<dyn SmsSender>, String>> = OnceLock::new();
+
+// new_sms_sender is selected by SMS_SENDER (nhn or fake).
+// NHN Cloud uses NHN_SMS_APP_KEY, NHN_SMS_SECRET_KEY and SMS_FROM.
+// Only debug builds may use the fake sender, which is also their default.
+fn new_sms_sender() -> Result<Box<dyn SmsSender>, String> {
+    let env = |key: &str| std::env::var(key).map_err(|_| format!("{key} is not set"));
+    let kind = match std::env::var("SMS_SENDER") {
+        Ok(kind) => kind,
+        Err(_) if cfg!(debug_assertions) => "fake".to_string(),
+        Err(_) => return Err("SMS_SENDER is not set".to_string()),
+    };
+
+    match kind.as_str() {
+        "nhn" => Ok(Box::new(NhnSmsSender::new(
+            &env("NHN_SMS_APP_KEY")?,
+            env("NHN_SMS_SECRET_KEY")?,
+            env("SMS_FROM")?,
+        ))),
+        #[cfg(debug_assertions)]
+        "fake" => Ok(Box::new(FakeSmsSender::default())),
+        kind => Err(format!("unknown SMS_SENDER: {kind}")),
+    }
+}
+
+pub fn sms_sender() -> Result<&'static dyn SmsSender, ApiError> {
+    SMS_SENDER
+        .get_or_init(new_sms_sender)
+        .as_ref()
+        .map(|sender| sender.as_ref())
+        .map_err(ApiError::internal)
+}
+
+// init checks SMS_SENDER so that the server refuses to start without a way to send messages.
+pub fn init() -> Result<(), ApiError> {
+    sms_sender().map(|_| ())
+}
+
+// set_sms_sender replaces the configured sender, e.g. in tests.
+// It must be called before the first message is sent.
+pub fn set_sms_sender(sender: Box<dyn SmsSender>) -> Result<(), ApiError> {
+    SMS_SENDER
+        .set(Ok(sender))
+        .map_err(|_| ApiError::internal("sms sender is already set"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nhn_request_body() {
+        let req = NhnSmsRequest {
+            body: "hello",
+            send_no: "0212345678",
+            recipient_list: vec![NhnSmsRecipient {
+                recipient_no: "01012345678",
+            }],
+        };
+
+        assert_eq!(
+            serde_json::to_value(req).unwrap(),
+            serde_json::json!({
+                "body": "hello",
+                "sendNo": "0212345678",
+                "recipientList": [{ "recipientNo": "01012345678" }],
+            })
+        );
+    }
+
+    #[test]
+    fn fake_outbox_keeps_the_latest_messages() {
+        let sender = FakeSmsSender::default();
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        for i in 0..=FakeSmsSender::OUTBOX_SIZE {
+            rt.block_on(sender.send(Sms {
+                to: "01012345678".to_string(),
+                body: i.to_string(),
+            }))
+            .unwrap();
+        }
+
+        let outbox = sender.outbox.lock().unwrap();
+        assert_eq!(outbox.len(), FakeSmsSender::OUTBOX_SIZE);
+        assert_eq!(outbox.front().unwrap().body, "1");
+    }
+}
@@ -0,0 +1,62 @@
+use crate::{
+    api::common::{ApiError, CommonQueryResponse},
+    models::survey::SurveySummary,
+};
+
+use super::{database, lifecycle};
+
+pub const MAX_PAGE_SIZE: i32 = 100;
+
+// list returns a page of `size` surveys of an organization starting at `bookmark`,
+// those in `status` (see `SurveyStatus::name`) or, without it, those not archived.
+// Due surveys are closed as they are read, and surveys closed out of `status` or archived
+// are skipped after they are read, so more items are read until the page is full.
+pub async fn list(
+    organization_id: &str,
+    status: Option<&str>,
+    size: i32,
+    mut bookmark: Option<String>,
+    now: u64,
+) -> Result<CommonQueryResponse<SurveySummary>, ApiError> {
+    let size = size.clamp(1, MAX_PAGE_SIZE) as usize;
+    let (index, filter) = match status {
+        Some(status) => (
+            "gsi2-index",
+            ("gsi2", SurveySummary::gsi2(organization_id, status)),
+        ),
+        None => ("gsi1-index", ("gsi1", organization_id.to_string())),
+    };
+
+    let mut items = vec![];
+    loop {
+        // read no more than the rest of the page, so that the bookmark follows the last item kept
+        let page = CommonQueryResponse::<SurveySummary>::query(
+            &database::log(),
+            index,
+            bookmark,
+            Some((size - items.len()) as i32),
+            vec![filter.clone()],
+        )
+        .await
+        .map_err(ApiError::dynamo)?;
+        for mut survey in page.items {
+            lifecycle::close_if_due(&mut survey, now).await?;
+            let listed = match status {
+                Some(status) => {
+                    survey.status_index() == SurveySummary::gsi2(organization_id, status)
+                }
+                None => survey.archived_at.is_none(),
+            };
+            if listed {
+                items.push(survey);
+            }
+        }
+
+        bookmark = page.bookmark;
+        if bookmark.is_none() || items.len() >= size {
+            break;
+        }
+    }
+
+    Ok(CommonQueryResponse { items, bookmark })
+}
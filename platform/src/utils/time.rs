@@ -0,0 +1,21 @@
+use chrono::{DateTime, FixedOffset, NaiveDateTime, TimeZone};
+
+// Surveys are scheduled in Korea Standard Time (UTC+9, no daylight saving).
+pub fn kst() -> FixedOffset {
+    FixedOffset::east_opt(9 * 60 * 60).expect("valid offset")
+}
+
+// parse_kst reads a `datetime-local` input value such as `2024-09-06T09:00` in KST.
+pub fn parse_kst(value: &str) -> Option<u64> {
+    let naive = NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M").ok()?;
+    let timestamp = kst().from_local_datetime(&naive).single()?.timestamp();
+
+    u64::try_from(timestamp).ok()
+}
+
+// format_kst formats a timestamp as a `datetime-local` input value in KST.
+pub fn format_kst(timestamp: u64) -> String {
+    DateTime::from_timestamp(timestamp as i64, 0)
+        .map(|d| d.with_timezone(&kst()).format("%Y-%m-%dT%H:%M").to_string())
+        .unwrap_or_default()
+}
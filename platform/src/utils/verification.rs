@@ -0,0 +1,92 @@
+use rand::Rng;
+
+use crate::{
+    api::common::ApiError,
+    models::verification::{Verification, VerificationKind},
+};
+
+use super::{database, password};
+
+// issue stores a new 6-digit code of `kind` for `target` and returns it to be delivered.
+pub async fn issue(kind: VerificationKind, target: &str, now: u64) -> Result<String, ApiError> {
+    let key = Verification::key(kind, target);
+
+    if let Some(prev) = database::get::<Verification>(&key).await? {
+        if prev.verified_at.is_none() && prev.created_at + Verification::RESEND_INTERVAL > now {
+            return Err(ApiError::TooManyAttempts(
+                prev.created_at + Verification::RESEND_INTERVAL - now,
+            ));
+        }
+    }
+
+    let code = format!("{:06}", rand::thread_rng().gen_range(0..1_000_000));
+    database::put(&Verification {
+        id: key.clone(),
+        r#type: "verification".to_string(),
+        target: target.trim().to_lowercase(),
+        code: password::hash_password(&code)?,
+        attempts: 0,
+        expired_at: now + Verification::CODE_TTL,
+        verified_at: None,
+        created_at: now,
+    })
+    .await?;
+
+    Ok(code)
+}
+
+// check marks `target` as verified if `code` matches the issued one.
+// The code is compared even if `target` is already verified, so that a wrong code never passes.
+pub async fn check(
+    kind: VerificationKind,
+    target: &str,
+    code: &str,
+    now: u64,
+) -> Result<(), ApiError> {
+    let key = Verification::key(kind, target);
+    let mut verification = database::get::<Verification>(&key)
+        .await?
+        .ok_or(ApiError::InvalidVerificationCode)?;
+    let verified = verification.is_verified(now);
+
+    if verification.attempts >= Verification::MAX_ATTEMPTS
+        || (!verified && verification.expired_at <= now)
+    {
+        return Err(ApiError::VerificationExpired);
+    }
+
+    // each attempt is counted in one write before the code is compared,
+    // so that concurrent guesses can not get past MAX_ATTEMPTS
+    let counted = database::add::<Verification>(&key, "attempts", 1).await?;
+    if counted.attempts > Verification::MAX_ATTEMPTS {
+        return Err(ApiError::VerificationExpired);
+    }
+
+    if !password::verify_password(code.trim(), &verification.code) {
+        return Err(ApiError::InvalidVerificationCode);
+    }
+
+    if verified {
+        return Ok(());
+    }
+
+    verification.verified_at = Some(now);
+    database::put_fields(&verification, &["verified_at"]).await
+}
+
+// require_verified fails unless `target` was verified recently.
+pub async fn require_verified(
+    kind: VerificationKind,
+    target: &str,
+    now: u64,
+) -> Result<(), ApiError> {
+    match database::get::<Verification>(&Verification::key(kind, target)).await? {
+        Some(v) if v.is_verified(now) => Ok(()),
+        _ => Err(ApiError::NotVerified),
+    }
+}
+
+// consume removes the verification so that it cannot be used twice.
+pub async fn consume(kind: VerificationKind, target: &str) -> Result<(), ApiError> {
+    database::delete(&Verification::key(kind, target)).await
+}
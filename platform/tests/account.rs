@@ -0,0 +1,252 @@
+#![cfg(feature = "server")]
+mod common;
+
+use dioxus::prelude::ServerFnError;
+use voice_korea::{
+    api::{
+        common::ApiError,
+        v1::users::{
+            reset::{reset_password, ResetPasswordRequest},
+            signup::SignupUserRequest,
+        },
+    },
+    models::{
+        role::Role,
+        user::{LoginAttempt, PasswordResetToken, Terms, TermsAgreement, User, UserKind},
+        verification::VerificationKind,
+    },
+    utils::{
+        account, database,
+        session::{self, Session},
+        verification,
+    },
+};
+
+const PASSWORD: &str = "voice12!";
+
+fn now() -> u64 {
+    chrono::Utc::now().timestamp() as u64
+}
+
+fn request(email: &str) -> SignupUserRequest {
+    request_agreed_at(email, now())
+}
+
+fn request_agreed_at(email: &str, agreed_at: u64) -> SignupUserRequest {
+    SignupUserRequest {
+        email: email.to_string(),
+        password: PASSWORD.to_string(),
+        name: "Tester".to_string(),
+        phone: "010-1234-5678".to_string(),
+        address: "Seoul".to_string(),
+        kind: UserKind::Individual,
+        agreed_terms: Terms::essentials()
+            .into_iter()
+            .map(|terms| TermsAgreement { terms, agreed_at })
+            .collect(),
+    }
+}
+
+async fn verify(email: &str, now: u64) {
+    let code = verification::issue(VerificationKind::Contact, email, now)
+        .await
+        .unwrap();
+    verification::check(VerificationKind::Contact, email, &code, now)
+        .await
+        .unwrap();
+}
+
+#[test]
+#[ignore = "needs DynamoDB Local at DYNAMODB_ENDPOINT"]
+fn signup_requires_verification_and_unique_email() {
+    common::run(async {
+        let email = common::unique("signup");
+        let now = now();
+
+        assert_eq!(
+            account::signup(request(&email), now).await.unwrap_err(),
+            ApiError::NotVerified
+        );
+
+        verify(&email, now).await;
+        assert_eq!(
+            account::signup(
+                request_agreed_at(&email, now - TermsAgreement::MAX_AGE - 1),
+                now
+            )
+            .await
+            .unwrap_err(),
+            ApiError::TermsNotAgreed
+        );
+        let user = account::signup(request_agreed_at(&email, now - 60), now)
+            .await
+            .unwrap();
+        assert_eq!(user.email, email);
+        assert!(user.agreed_terms.iter().all(|a| a.agreed_at == now - 60));
+        assert_ne!(user.password, PASSWORD);
+        assert!(!user.organization_id.is_empty());
+        assert_eq!(
+            account::profile(&user).await.unwrap().role,
+            Some(Role::Owner)
+        );
+
+        verify(&email, now).await;
+        assert_eq!(
+            account::signup(request(&email.to_uppercase()), now)
+                .await
+                .unwrap_err(),
+            ApiError::DuplicateEmail
+        );
+    });
+}
+
+#[test]
+#[ignore = "needs DynamoDB Local at DYNAMODB_ENDPOINT"]
+fn concurrent_signups_of_an_email_create_one_user() {
+    common::run(async {
+        let email = common::unique("concurrent");
+        let now = now();
+        verify(&email, now).await;
+
+        let (first, second) = tokio::join!(
+            account::signup(request(&email), now),
+            account::signup(request(&email), now)
+        );
+        assert_eq!(
+            [&first, &second].iter().filter(|r| r.is_ok()).count(),
+            1,
+            "{first:?} {second:?}"
+        );
+    });
+}
+
+#[test]
+#[ignore = "needs DynamoDB Local at DYNAMODB_ENDPOINT"]
+fn login_checks_password_and_limits_failures() {
+    common::run(async {
+        let email = common::unique("login");
+        let now = now();
+        verify(&email, now).await;
+        let user = account::signup(request(&email), now).await.unwrap();
+
+        assert_eq!(
+            account::login(&email, PASSWORD, now).await.unwrap().id,
+            user.id
+        );
+        assert_eq!(
+            account::login(&common::unique("unknown"), PASSWORD, now)
+                .await
+                .unwrap_err(),
+            ApiError::InvalidCredentials
+        );
+
+        for _ in 0..LoginAttempt::MAX_FAILURES {
+            assert_eq!(
+                account::login(&email, "wrong12!", now).await.unwrap_err(),
+                ApiError::InvalidCredentials
+            );
+        }
+        // even the right password is refused while locked
+        assert_eq!(
+            account::login(&email, PASSWORD, now).await.unwrap_err(),
+            ApiError::TooManyAttempts(LoginAttempt::LOCK_SECONDS)
+        );
+        assert!(
+            account::login(&email, PASSWORD, now + LoginAttempt::LOCK_SECONDS)
+                .await
+                .is_ok()
+        );
+    });
+}
+
+#[test]
+#[ignore = "needs DynamoDB Local at DYNAMODB_ENDPOINT"]
+fn session_round_trip_and_revocation() {
+    common::run(async {
+        let email = common::unique("session");
+        let now = now();
+        verify(&email, now).await;
+        let user = account::signup(request(&email), now).await.unwrap();
+
+        let token = Session::new(&user.id, now).token().unwrap();
+        let mut signed_in = session::authenticate(&token, now).await.unwrap();
+        assert_eq!(signed_in.id, user.id);
+
+        // revoked within the second the session was issued
+        session::revoke(&mut signed_in, now).await.unwrap();
+        assert_eq!(
+            session::authenticate(&token, now).await.unwrap_err(),
+            ApiError::Unauthorized
+        );
+        let token = Session::for_user(&signed_in, now).token().unwrap();
+        assert!(session::authenticate(&token, now).await.is_ok());
+    });
+}
+
+#[test]
+#[ignore = "needs DynamoDB Local at DYNAMODB_ENDPOINT"]
+fn concurrent_failures_are_all_counted() {
+    common::run(async {
+        let email = common::unique("concurrent-login");
+        let now = now();
+
+        let mut logins = tokio::task::JoinSet::new();
+        for _ in 0..LoginAttempt::MAX_FAILURES {
+            let email = email.clone();
+            logins.spawn(async move { account::login(&email, "wrong12!", now).await });
+        }
+        while let Some(res) = logins.join_next().await {
+            assert_eq!(res.unwrap().unwrap_err(), ApiError::InvalidCredentials);
+        }
+
+        assert_eq!(
+            account::login(&email, PASSWORD, now).await.unwrap_err(),
+            ApiError::TooManyAttempts(LoginAttempt::LOCK_SECONDS)
+        );
+    });
+}
+
+#[test]
+#[ignore = "needs DynamoDB Local at DYNAMODB_ENDPOINT"]
+fn reset_token_is_used_once() {
+    common::run(async {
+        let email = common::unique("reset");
+        let now = now();
+        verify(&email, now).await;
+        let user = account::signup(request(&email), now).await.unwrap();
+        let token = common::unique("token");
+        database::put(&PasswordResetToken {
+            id: PasswordResetToken::key(&token),
+            r#type: "password_reset_token".to_string(),
+            user_id: user.id.clone(),
+            expired_at: now + PasswordResetToken::TTL,
+            created_at: now,
+        })
+        .await
+        .unwrap();
+
+        let mut resets = tokio::task::JoinSet::new();
+        for new_password in ["voice34!", "voice56!"] {
+            let req = ResetPasswordRequest {
+                token: token.clone(),
+                new_password: new_password.to_string(),
+            };
+            resets.spawn(async move { reset_password(req).await });
+        }
+        let mut reset = 0;
+        while let Some(res) = resets.join_next().await {
+            match res.unwrap() {
+                Ok(()) => reset += 1,
+                Err(e) => assert_eq!(
+                    e,
+                    ServerFnError::WrappedServerError(ApiError::InvalidResetToken)
+                ),
+            }
+        }
+        assert_eq!(reset, 1);
+
+        let user = database::get::<User>(&user.id).await.unwrap().unwrap();
+        assert!(!user.organization_id.is_empty());
+        assert!(account::login(&email, PASSWORD, now).await.is_err());
+    });
+}
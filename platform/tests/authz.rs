@@ -0,0 +1,152 @@
+#![cfg(feature = "server")]
+mod common;
+
+use voice_korea::{
+    api::common::ApiError,
+    models::{
+        role::{Permission, Role, RoleBinding},
+        survey::SurveySummary,
+        user::User,
+    },
+    utils::{authz, database, organization, question},
+};
+
+fn user(now: u64) -> User {
+    User {
+        id: database::new_id("user"),
+        r#type: "user".to_string(),
+        created_at: now,
+        updated_at: now,
+        ..Default::default()
+    }
+}
+
+// setup creates an organization of `owner` with a draft survey.
+async fn setup(owner: &User, now: u64) -> (String, SurveySummary) {
+    let organization = organization::create("Voice Korea", &owner.id, now)
+        .await
+        .unwrap();
+    let survey = SurveySummary {
+        id: database::new_id("survey"),
+        r#type: "survey".to_string(),
+        title: "Survey".to_string(),
+        updated_at: now,
+        gsi1: organization.id.clone(),
+        gsi2: SurveySummary::gsi2(&organization.id, "draft"),
+        ..Default::default()
+    };
+    database::put(&survey).await.unwrap();
+
+    (organization.id, survey)
+}
+
+#[test]
+#[ignore = "needs DynamoDB Local at DYNAMODB_ENDPOINT"]
+fn organization_viewer_may_only_read() {
+    common::run(async {
+        let now = chrono::Utc::now().timestamp() as u64;
+        let (owner, viewer) = (user(now), user(now));
+        let (organization_id, survey) = setup(&owner, now).await;
+        database::put(&RoleBinding::new(
+            &organization_id,
+            &viewer.id,
+            Role::Viewer,
+            now,
+        ))
+        .await
+        .unwrap();
+
+        assert_eq!(
+            authz::require_organization(&viewer, &organization_id, Permission::Read).await,
+            Ok(Role::Viewer)
+        );
+        for permission in [Permission::Edit, Permission::ViewResults] {
+            assert_eq!(
+                authz::require_organization(&viewer, &organization_id, permission).await,
+                Err(ApiError::Forbidden)
+            );
+            assert_eq!(
+                authz::load_survey(&viewer, &survey.id, permission)
+                    .await
+                    .unwrap_err(),
+                ApiError::Forbidden
+            );
+        }
+        assert!(authz::load_survey(&viewer, &survey.id, Permission::Read)
+            .await
+            .is_ok());
+
+        assert_eq!(
+            question::load_editable(&viewer, &survey.id)
+                .await
+                .unwrap_err(),
+            ApiError::Forbidden
+        );
+        assert!(question::load_editable(&owner, &survey.id).await.is_ok());
+    });
+}
+
+#[test]
+#[ignore = "needs DynamoDB Local at DYNAMODB_ENDPOINT"]
+fn survey_viewer_may_only_read_the_survey() {
+    common::run(async {
+        let now = chrono::Utc::now().timestamp() as u64;
+        let (owner, viewer, stranger) = (user(now), user(now), user(now));
+        let (organization_id, survey) = setup(&owner, now).await;
+        database::put(&RoleBinding::new(&survey.id, &viewer.id, Role::Viewer, now))
+            .await
+            .unwrap();
+
+        assert!(authz::load_survey(&viewer, &survey.id, Permission::Read)
+            .await
+            .is_ok());
+        for permission in [Permission::Edit, Permission::ViewResults] {
+            assert_eq!(
+                authz::load_survey(&viewer, &survey.id, permission)
+                    .await
+                    .unwrap_err(),
+                ApiError::Forbidden
+            );
+        }
+        assert_eq!(
+            question::load_editable(&viewer, &survey.id)
+                .await
+                .unwrap_err(),
+            ApiError::Forbidden
+        );
+        // the survey binding grants nothing on the organization
+        assert_eq!(
+            authz::require_organization(&viewer, &organization_id, Permission::Read).await,
+            Err(ApiError::Forbidden)
+        );
+
+        assert_eq!(
+            authz::load_survey(&stranger, &survey.id, Permission::Read)
+                .await
+                .unwrap_err(),
+            ApiError::Forbidden
+        );
+    });
+}
+
+#[test]
+#[ignore = "needs DynamoDB Local at DYNAMODB_ENDPOINT"]
+fn other_items_are_not_surveys() {
+    common::run(async {
+        let now = chrono::Utc::now().timestamp() as u64;
+        let owner = user(now);
+        let (organization_id, _) = setup(&owner, now).await;
+
+        // the owner may read anything in the organization, but a role binding is no survey
+        assert_eq!(
+            authz::load_survey(
+                &owner,
+                &RoleBinding::key(&organization_id, &owner.id),
+                Permission::Read
+            )
+            .await
+            .unwrap_err(),
+            ApiError::NotFound
+        );
+    });
+}
@@ -0,0 +1,126 @@
+// Shared setup of the tests against DynamoDB Local, which are ignored by default:
+//
+//   docker run -p 8000:8000 amazon/dynamodb-local
+//   DYNAMODB_ENDPOINT=http://localhost:8000 cargo test --features server -- --ignored
+#![allow(dead_code)]
+
+use std::{
+    future::Future,
+    sync::{Once, OnceLock},
+};
+
+use aws_sdk_dynamodb::{
+    config::{Credentials, Region},
+    types::{
+        AttributeDefinition, BillingMode, GlobalSecondaryIndex, KeySchemaElement, KeyType,
+        Projection, ProjectionType, ScalarAttributeType,
+    },
+};
+
+pub const TABLE_NAME: &str = "voice-korea-test";
+
+// run drives `f` on a runtime shared by the tests, so that clients are not bound to a dropped one.
+pub fn run<F: Future>(f: F) -> F::Output {
+    static RUNTIME: OnceLock<tokio::runtime::Runtime> = OnceLock::new();
+    let runtime = RUNTIME.get_or_init(|| {
+        tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()
+            .expect("failed to build a runtime")
+    });
+
+    runtime.block_on(async {
+        setup().await;
+        f.await
+    })
+}
+
+async fn setup() {
+    static ENV: Once = Once::new();
+    static TABLE: tokio::sync::OnceCell<()> = tokio::sync::OnceCell::const_new();
+
+    ENV.call_once(|| {
+        let defaults = [
+            ("TABLE_NAME", TABLE_NAME),
+            ("AWS_ACCESS_KEY_ID", "local"),
+            ("AWS_SECRET_ACCESS_KEY", "local"),
+            ("SESSION_SECRET", "a-session-secret-for-the-dynamodb-tests"),
+        ];
+        for (key, value) in defaults {
+            if std::env::var(key).is_err() {
+                std::env::set_var(key, value);
+            }
+        }
+        voice_korea::utils::session::init().expect("invalid SESSION_SECRET");
+        voice_korea::utils::database::init();
+    });
+    TABLE.get_or_init(create_table).await;
+}
+
+// create_table creates the table with the indexes the queries use, unless it exists.
+async fn create_table() {
+    let endpoint =
+        std::env::var("DYNAMODB_ENDPOINT").expect("DYNAMODB_ENDPOINT must point to DynamoDB Local");
+    let config = aws_config::defaults(aws_config::BehaviorVersion::latest())
+        .endpoint_url(endpoint)
+        .region(Region::new("ap-northeast-2"))
+        .credentials_provider(Credentials::new("local", "local", None, None, "tests"))
+        .load()
+        .await;
+    let client = aws_sdk_dynamodb::Client::new(&config);
+
+    let attribute = |name: &str| {
+        AttributeDefinition::builder()
+            .attribute_name(name)
+            .attribute_type(ScalarAttributeType::S)
+            .build()
+            .unwrap()
+    };
+    let key = |name: &str| {
+        KeySchemaElement::builder()
+            .attribute_name(name)
+            .key_type(KeyType::Hash)
+            .build()
+            .unwrap()
+    };
+    let index = |name: &str| {
+        GlobalSecondaryIndex::builder()
+            .index_name(format!("{name}-index"))
+            .key_schema(key(name))
+            .projection(
+                Projection::builder()
+                    .projection_type(ProjectionType::All)
+                    .build(),
+            )
+            .build()
+            .unwrap()
+    };
+
+    let created = client
+        .create_table()
+        .table_name(std::env::var("TABLE_NAME").unwrap())
+        .billing_mode(BillingMode::PayPerRequest)
+        .attribute_definitions(attribute("id"))
+        .attribute_definitions(attribute("gsi1"))
+        .attribute_definitions(attribute("gsi2"))
+        .key_schema(key("id"))
+        .global_secondary_indexes(index("gsi1"))
+        .global_secondary_indexes(index("gsi2"))
+        .send()
+        .await;
+    if let Err(e) = created {
+        let e = e.into_service_error();
+        assert!(
+            e.is_resource_in_use_exception(),
+            "failed to create the table: {e:?}"
+        );
+    }
+}
+
+// unique returns an address no other test run uses, since the table is kept between runs.
+pub fn unique(name: &str) -> String {
+    format!(
+        "{name}-{}@voice-korea.test",
+        voice_korea::utils::database::new_id("t")
+    )
+}
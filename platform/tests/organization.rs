@@ -0,0 +1,84 @@
+#![cfg(feature = "server")]
+mod common;
+
+use voice_korea::{
+    models::{
+        role::{Role, RoleBinding},
+        user::User,
+    },
+    utils::{database, organization},
+};
+
+// join puts a user working in `organization_id`, where it is an editor.
+async fn join(organization_id: &str, now: u64) -> User {
+    let email = common::unique("member");
+    let user = User {
+        id: database::new_id("user"),
+        r#type: "user".to_string(),
+        gsi1: User::gsi1(&email),
+        gsi2: User::gsi2("010-1234-5678"),
+        email,
+        organization_id: organization_id.to_string(),
+        created_at: now,
+        updated_at: now,
+        ..Default::default()
+    };
+    database::put(&user).await.unwrap();
+    database::put(&RoleBinding::new(
+        organization_id,
+        &user.id,
+        Role::Editor,
+        now,
+    ))
+    .await
+    .unwrap();
+
+    user
+}
+
+// remove deletes the binding as `remove_member` does.
+async fn remove(organization_id: &str, user_id: &str, now: u64) -> User {
+    database::delete(&RoleBinding::key(organization_id, user_id))
+        .await
+        .unwrap();
+    organization::detach(user_id, organization_id, now)
+        .await
+        .unwrap();
+
+    database::get::<User>(user_id).await.unwrap().unwrap()
+}
+
+#[test]
+#[ignore = "needs DynamoDB Local at DYNAMODB_ENDPOINT"]
+fn removed_member_moves_to_another_organization() {
+    common::run(async {
+        let now = chrono::Utc::now().timestamp() as u64;
+        let shared = organization::create("Shared", &database::new_id("user"), now)
+            .await
+            .unwrap();
+        let member = join(&shared.id, now).await;
+        let own = organization::create("Own", &member.id, now).await.unwrap();
+
+        assert_eq!(
+            remove(&shared.id, &member.id, now).await.organization_id,
+            own.id
+        );
+    });
+}
+
+#[test]
+#[ignore = "needs DynamoDB Local at DYNAMODB_ENDPOINT"]
+fn removed_member_without_another_organization_has_none() {
+    common::run(async {
+        let now = chrono::Utc::now().timestamp() as u64;
+        let shared = organization::create("Shared", &database::new_id("user"), now)
+            .await
+            .unwrap();
+        let member = join(&shared.id, now).await;
+
+        assert_eq!(
+            remove(&shared.id, &member.id, now).await.organization_id,
+            ""
+        );
+    });
+}
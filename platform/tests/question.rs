@@ -0,0 +1,173 @@
+#![cfg(feature = "server")]
+mod common;
+
+use voice_korea::{
+    api::common::ApiError,
+    models::{
+        question::{Question, QuestionType, MAX_QUESTIONS},
+        survey::SurveySummary,
+    },
+    utils::{database, question},
+};
+
+fn text(survey_id: &str, title: &str) -> Question {
+    Question {
+        id: database::new_id("question"),
+        r#type: "question".to_string(),
+        survey_id: survey_id.to_string(),
+        title: title.to_string(),
+        question: QuestionType::Text(None),
+        required: false,
+        position: 0,
+        logic: Default::default(),
+        carry_forward: None,
+        gsi1: survey_id.to_string(),
+    }
+}
+
+// draft puts an empty draft survey.
+async fn draft(now: u64) -> SurveySummary {
+    let organization_id = database::new_id("organization");
+    let survey = SurveySummary {
+        id: database::new_id("survey"),
+        r#type: "survey".to_string(),
+        title: "Survey".to_string(),
+        updated_at: now,
+        gsi2: SurveySummary::gsi2(&organization_id, "draft"),
+        gsi1: organization_id,
+        ..Default::default()
+    };
+    database::put(&survey).await.unwrap();
+
+    survey
+}
+
+#[test]
+#[ignore = "needs DynamoDB Local at DYNAMODB_ENDPOINT"]
+fn list_returns_questions_beyond_a_page() {
+    common::run(async {
+        let survey_id = database::new_id("survey");
+        let count = MAX_QUESTIONS + 5;
+        for position in 0..count {
+            database::put(&Question {
+                position: position as u32,
+                ..text(&survey_id, &format!("Q{}", position + 1))
+            })
+            .await
+            .unwrap();
+        }
+
+        let questions = question::list(&survey_id).await.unwrap();
+
+        assert_eq!(questions.len(), count);
+        assert!(questions
+            .iter()
+            .enumerate()
+            .all(|(i, q)| q.position == i as u32));
+    });
+}
+
+#[test]
+#[ignore = "needs DynamoDB Local at DYNAMODB_ENDPOINT"]
+fn sync_of_a_survey_changed_meanwhile_is_rejected() {
+    common::run(async {
+        let now = chrono::Utc::now().timestamp() as u64;
+        let survey = draft(now).await;
+        let (mut first, mut second) = (survey.clone(), survey.clone());
+
+        let mut kept = vec![text(&survey.id, "first")];
+        question::sync(&mut first, &mut kept, &[], now)
+            .await
+            .unwrap();
+        // within the same second, the second request still sees the survey it loaded changed
+        let mut lost = vec![text(&survey.id, "second")];
+        assert_eq!(
+            question::sync(&mut second, &mut lost, &[], now).await,
+            Err(ApiError::Conflict)
+        );
+
+        assert_eq!(question::list(&survey.id).await.unwrap(), kept);
+        let stored = database::get::<SurveySummary>(&survey.id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.questions, 1);
+        assert_eq!(stored.updated_at, first.updated_at);
+    });
+}
+
+#[test]
+#[ignore = "needs DynamoDB Local at DYNAMODB_ENDPOINT"]
+fn sync_of_a_survey_answered_meanwhile_is_rejected() {
+    common::run(async {
+        let now = chrono::Utc::now().timestamp() as u64;
+        let mut survey = draft(now).await;
+        database::add::<SurveySummary>(&survey.id, "responses", 1)
+            .await
+            .unwrap();
+
+        let mut questions = vec![text(&survey.id, "first")];
+        assert_eq!(
+            question::sync(&mut survey, &mut questions, &[], now).await,
+            Err(ApiError::Conflict)
+        );
+        assert!(question::list(&survey.id).await.unwrap().is_empty());
+    });
+}
+
+#[test]
+#[ignore = "needs DynamoDB Local at DYNAMODB_ENDPOINT"]
+fn sync_deletes_the_questions_left_out() {
+    common::run(async {
+        let now = chrono::Utc::now().timestamp() as u64;
+        let mut survey = draft(now).await;
+        let mut questions: Vec<Question> = (0..60)
+            .map(|i| text(&survey.id, &format!("Q{}", i + 1)))
+            .collect();
+        question::sync(&mut survey, &mut questions, &[], now)
+            .await
+            .unwrap();
+
+        let existing = question::list(&survey.id).await.unwrap();
+        let mut replaced: Vec<Question> = (0..30)
+            .map(|i| text(&survey.id, &format!("new Q{}", i + 1)))
+            .collect();
+        question::sync(&mut survey, &mut replaced, &existing, now)
+            .await
+            .unwrap();
+
+        assert_eq!(question::list(&survey.id).await.unwrap(), replaced);
+        let stored = database::get::<SurveySummary>(&survey.id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.questions, 30);
+    });
+}
+
+#[test]
+#[ignore = "needs DynamoDB Local at DYNAMODB_ENDPOINT"]
+fn sync_of_more_writes_than_a_transaction_takes_is_rejected() {
+    common::run(async {
+        let now = chrono::Utc::now().timestamp() as u64;
+        let mut survey = draft(now).await;
+        let mut questions: Vec<Question> = (0..30)
+            .map(|i| text(&survey.id, &format!("Q{}", i + 1)))
+            .collect();
+        question::sync(&mut survey, &mut questions, &[], now)
+            .await
+            .unwrap();
+
+        // 30 deletes and 70 puts
+        let existing = question::list(&survey.id).await.unwrap();
+        let mut replaced: Vec<Question> = (0..70)
+            .map(|i| text(&survey.id, &format!("new Q{}", i + 1)))
+            .collect();
+        assert_eq!(
+            question::sync(&mut survey, &mut replaced, &existing, now).await,
+            Err(ApiError::InvalidInput("questions".to_string()))
+        );
+
+        assert_eq!(question::list(&survey.id).await.unwrap(), existing);
+    });
+}
@@ -0,0 +1,144 @@
+#![cfg(feature = "server")]
+mod common;
+
+use dioxus::prelude::ServerFnError;
+use voice_korea::{
+    api::{
+        common::ApiError,
+        v1::surveys::{answer_survey, respond::get_survey_form, AnswerSurveyRequest},
+    },
+    models::survey::{Quota, SurveyStatus, SurveySummary},
+    utils::database,
+};
+
+// launch puts a survey without questions running since `now`.
+async fn launch(expected_responses: Option<u64>, quotas: Option<Vec<Quota>>, now: u64) -> String {
+    let mut survey = SurveySummary {
+        id: database::new_id("survey"),
+        r#type: "survey".to_string(),
+        title: "Survey".to_string(),
+        updated_at: now,
+        status: SurveyStatus::InProgress {
+            started_at: now,
+            ended_at: None,
+        },
+        expected_responses,
+        quotas,
+        gsi1: database::new_id("organization"),
+        ..Default::default()
+    };
+    survey.gsi2 = survey.status_index();
+    database::put(&survey).await.unwrap();
+
+    survey.id
+}
+
+async fn answer(survey_id: &str, token: String) -> Result<(), ApiError> {
+    answer_survey(AnswerSurveyRequest {
+        survey_id: survey_id.to_string(),
+        token,
+        answers: vec![],
+    })
+    .await
+    .map_err(|e| match e {
+        ServerFnError::WrappedServerError(e) => e,
+        e => panic!("{e:?}"),
+    })
+}
+
+async fn token(survey_id: &str, token: Option<String>, proof_id: Option<&str>) -> String {
+    get_survey_form(
+        survey_id.to_string(),
+        token,
+        proof_id.map(|p| p.to_string()),
+    )
+    .await
+    .unwrap()
+    .token
+}
+
+async fn load(survey_id: &str) -> SurveySummary {
+    database::get_typed(survey_id, "survey")
+        .await
+        .unwrap()
+        .unwrap()
+}
+
+#[test]
+#[ignore = "needs DynamoDB Local at DYNAMODB_ENDPOINT"]
+fn respondent_answers_once_with_its_token() {
+    common::run(async {
+        let now = chrono::Utc::now().timestamp() as u64;
+        let survey_id = launch(None, None, now).await;
+        let first = token(&survey_id, None, None).await;
+
+        // the form keeps the respondent of a token of the survey
+        assert_eq!(token(&survey_id, Some(first.clone()), None).await, first);
+        assert_ne!(token(&survey_id, None, None).await, first);
+
+        let (a, b) = tokio::join!(
+            answer(&survey_id, first.clone()),
+            answer(&survey_id, first.clone())
+        );
+        let mut results = vec![a, b];
+        results.sort_by_key(|r| r.is_err());
+        assert_eq!(results, vec![Ok(()), Err(ApiError::AlreadyAnswered)]);
+        assert_eq!(load(&survey_id).await.responses, Some(1));
+
+        // a token of another survey or a forged one identifies no respondent
+        let other = launch(None, None, now).await;
+        assert_eq!(
+            answer(&other, first.clone()).await,
+            Err(ApiError::InvalidInput("token".to_string()))
+        );
+        assert_eq!(
+            answer(&survey_id, format!("respondent:{survey_id}.proof-1.00")).await,
+            Err(ApiError::InvalidInput("token".to_string()))
+        );
+    });
+}
+
+#[test]
+#[ignore = "needs DynamoDB Local at DYNAMODB_ENDPOINT"]
+fn concurrent_answers_are_all_counted() {
+    common::run(async {
+        let now = chrono::Utc::now().timestamp() as u64;
+        let survey_id = launch(Some(5), None, now).await;
+        let mut tokens = vec![];
+        for _ in 0..6 {
+            tokens.push(token(&survey_id, None, None).await);
+        }
+        let last = tokens.pop().unwrap();
+
+        let mut answers = tokio::task::JoinSet::new();
+        for token in tokens {
+            let survey_id = survey_id.clone();
+            answers.spawn(async move { answer(&survey_id, token).await });
+        }
+        while let Some(result) = answers.join_next().await {
+            assert_eq!(result.unwrap(), Ok(()));
+        }
+
+        let survey = load(&survey_id).await;
+        assert_eq!(survey.responses, Some(5));
+        assert_eq!(survey.status, SurveyStatus::Finished);
+        assert_eq!(answer(&survey_id, last).await, Err(ApiError::SurveyClosed));
+    });
+}
+
+#[test]
+#[ignore = "needs DynamoDB Local at DYNAMODB_ENDPOINT"]
+fn panel_survey_accepts_only_its_panels() {
+    common::run(async {
+        let now = chrono::Utc::now().timestamp() as u64;
+        let panel = database::new_id("panel");
+        let survey_id = launch(None, Some(vec![Quota::Panel(panel.clone())]), now).await;
+
+        let stranger = token(&survey_id, None, Some("someone")).await;
+        assert_eq!(answer(&survey_id, stranger).await, Err(ApiError::Forbidden));
+
+        let member = token(&survey_id, None, Some(&panel)).await;
+        assert_eq!(answer(&survey_id, member).await, Ok(()));
+        assert_eq!(load(&survey_id).await.status, SurveyStatus::Finished);
+    });
+}
@@ -0,0 +1,117 @@
+#![cfg(feature = "server")]
+mod common;
+
+use voice_korea::{
+    models::{
+        schedule::{ScheduledJob, SurveySchedule},
+        survey::{SurveyAction, SurveyStatus, SurveySummary},
+    },
+    utils::{
+        database,
+        scheduler::{self, Clock, FakeClock},
+    },
+};
+
+// schedule puts a draft survey opening at `opens_at` and closing at `closes_at`,
+// with its jobs as `schedule_survey` stores them.
+async fn schedule(opens_at: u64, closes_at: u64, now: u64) -> SurveySummary {
+    let organization_id = database::new_id("organization");
+    let mut survey = SurveySummary {
+        id: database::new_id("survey"),
+        r#type: "survey".to_string(),
+        title: "Survey".to_string(),
+        updated_at: now,
+        questions: 1,
+        schedule: Some(SurveySchedule {
+            start_at: Some(opens_at),
+            end_at: Some(closes_at),
+        }),
+        gsi1: organization_id,
+        ..Default::default()
+    };
+    survey.gsi2 = survey.status_index();
+    database::put(&survey).await.unwrap();
+    for (action, run_at) in [
+        (SurveyAction::Launch, opens_at),
+        (SurveyAction::Close, closes_at),
+    ] {
+        database::put(&ScheduledJob::new(&survey.id, action, run_at, now))
+            .await
+            .unwrap();
+    }
+
+    survey
+}
+
+async fn status(survey_id: &str) -> SurveyStatus {
+    database::get_typed::<SurveySummary>(survey_id, "survey")
+        .await
+        .unwrap()
+        .unwrap()
+        .status
+}
+
+async fn pending(survey_id: &str, action: SurveyAction) -> bool {
+    database::get::<ScheduledJob>(&ScheduledJob::key(survey_id, action))
+        .await
+        .unwrap()
+        .is_some()
+}
+
+#[test]
+#[ignore = "needs DynamoDB Local at DYNAMODB_ENDPOINT"]
+fn scheduled_survey_opens_and_closes() {
+    common::run(async {
+        // run_due runs the jobs of every test, so this one runs far after the others
+        let clock = FakeClock::new(chrono::Utc::now().timestamp() as u64 + 1_000_000);
+        let (opens_at, closes_at) = (clock.now() + 100, clock.now() + 200);
+        let survey = schedule(opens_at, closes_at, clock.now()).await;
+
+        scheduler::run_due(&clock).await.unwrap();
+        assert_eq!(status(&survey.id).await, SurveyStatus::Draft);
+
+        clock.set(opens_at);
+        scheduler::run_due(&clock).await.unwrap();
+        assert_eq!(
+            status(&survey.id).await,
+            SurveyStatus::InProgress {
+                started_at: opens_at,
+                ended_at: Some(closes_at),
+            }
+        );
+        assert!(!pending(&survey.id, SurveyAction::Launch).await);
+
+        clock.advance(closes_at - opens_at - 1);
+        scheduler::run_due(&clock).await.unwrap();
+        assert!(matches!(
+            status(&survey.id).await,
+            SurveyStatus::InProgress { .. }
+        ));
+
+        clock.advance(1);
+        scheduler::run_due(&clock).await.unwrap();
+        assert_eq!(status(&survey.id).await, SurveyStatus::Finished);
+        assert!(!pending(&survey.id, SurveyAction::Close).await);
+    });
+}
+
+#[test]
+#[ignore = "needs DynamoDB Local at DYNAMODB_ENDPOINT"]
+fn survey_closed_by_hand_drops_its_close_job() {
+    common::run(async {
+        let clock = FakeClock::new(chrono::Utc::now().timestamp() as u64);
+        let (opens_at, closes_at) = (clock.now() + 100, clock.now() + 200);
+        let mut survey = schedule(opens_at, closes_at, clock.now()).await;
+
+        clock.advance(150);
+        scheduler::run_due(&clock).await.unwrap();
+        survey.status = SurveyStatus::Finished;
+        survey.gsi2 = survey.status_index();
+        database::put(&survey).await.unwrap();
+
+        clock.advance(100);
+        scheduler::run_due(&clock).await.unwrap();
+        assert_eq!(status(&survey.id).await, SurveyStatus::Finished);
+        assert!(!pending(&survey.id, SurveyAction::Close).await);
+    });
+}
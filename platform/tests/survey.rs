@@ -0,0 +1,102 @@
+#![cfg(feature = "server")]
+mod common;
+
+use voice_korea::{
+    models::survey::{SurveyStatus, SurveySummary},
+    utils::{database, survey},
+};
+
+// put stores a draft survey of `organization_id`, archived if `archived`.
+async fn put(organization_id: &str, archived: bool, now: u64) -> SurveySummary {
+    let mut survey = SurveySummary {
+        id: database::new_id("survey"),
+        r#type: "survey".to_string(),
+        title: "Survey".to_string(),
+        updated_at: now,
+        archived_at: archived.then_some(now),
+        gsi1: organization_id.to_string(),
+        ..Default::default()
+    };
+    survey.gsi2 = survey.status_index();
+    database::put(&survey).await.unwrap();
+
+    survey
+}
+
+#[test]
+#[ignore = "needs DynamoDB Local at DYNAMODB_ENDPOINT"]
+fn pages_are_filled_past_archived_surveys() {
+    common::run(async {
+        let now = chrono::Utc::now().timestamp() as u64;
+        let organization_id = database::new_id("organization");
+        for _ in 0..3 {
+            put(&organization_id, true, now).await;
+        }
+        let mut live = vec![];
+        for _ in 0..2 {
+            live.push(put(&organization_id, false, now).await.id);
+        }
+
+        let page = survey::list(&organization_id, None, 2, None, now)
+            .await
+            .unwrap();
+        let mut listed: Vec<String> = page.items.into_iter().map(|s| s.id).collect();
+        listed.sort();
+        live.sort();
+        assert_eq!(listed, live);
+
+        // one at a time, the bookmarks skip the archived surveys too
+        let (mut listed, mut bookmark) = (vec![], None);
+        loop {
+            let page = survey::list(&organization_id, None, 1, bookmark, now)
+                .await
+                .unwrap();
+            assert!(page.items.len() == 1 || page.bookmark.is_none());
+            listed.extend(page.items.into_iter().map(|s| s.id));
+            bookmark = page.bookmark;
+            if bookmark.is_none() {
+                break;
+            }
+        }
+        listed.sort();
+        assert_eq!(listed, live);
+
+        let archived = survey::list(
+            &organization_id,
+            Some(SurveySummary::ARCHIVED),
+            10,
+            None,
+            now,
+        )
+        .await
+        .unwrap();
+        assert_eq!(archived.items.len(), 3);
+    });
+}
+
+#[test]
+#[ignore = "needs DynamoDB Local at DYNAMODB_ENDPOINT"]
+fn surveys_closed_when_listed_leave_their_status() {
+    common::run(async {
+        let now = chrono::Utc::now().timestamp() as u64;
+        let organization_id = database::new_id("organization");
+        let mut due = put(&organization_id, false, now).await;
+        due.status = SurveyStatus::InProgress {
+            started_at: now - 20,
+            ended_at: Some(now - 10),
+        };
+        due.gsi2 = due.status_index();
+        database::put(&due).await.unwrap();
+
+        let running = survey::list(&organization_id, Some("in_progress"), 10, None, now)
+            .await
+            .unwrap();
+        assert!(running.items.is_empty());
+
+        let finished = survey::list(&organization_id, Some("finished"), 10, None, now)
+            .await
+            .unwrap();
+        assert_eq!(finished.items.len(), 1);
+        assert_eq!(finished.items[0].id, due.id);
+    });
+}
@@ -0,0 +1,135 @@
+#![cfg(feature = "server")]
+mod common;
+
+use voice_korea::{
+    api::common::ApiError,
+    models::verification::{Verification, VerificationKind},
+    utils::verification,
+};
+
+#[test]
+#[ignore = "needs DynamoDB Local at DYNAMODB_ENDPOINT"]
+fn verified_target_still_checks_the_code() {
+    common::run(async {
+        let email = common::unique("verified");
+        let now = chrono::Utc::now().timestamp() as u64;
+        let code = verification::issue(VerificationKind::Contact, &email, now)
+            .await
+            .unwrap();
+        let wrong = if code == "000000" { "000001" } else { "000000" };
+
+        assert_eq!(
+            verification::check(VerificationKind::Contact, &email, wrong, now)
+                .await
+                .unwrap_err(),
+            ApiError::InvalidVerificationCode
+        );
+        verification::check(VerificationKind::Contact, &email, &code, now)
+            .await
+            .unwrap();
+
+        // past the code TTL, but still verified
+        let later = now + Verification::CODE_TTL;
+        assert_eq!(
+            verification::check(VerificationKind::Contact, &email, wrong, later)
+                .await
+                .unwrap_err(),
+            ApiError::InvalidVerificationCode
+        );
+        verification::check(VerificationKind::Contact, &email, &code, later)
+            .await
+            .unwrap();
+        verification::require_verified(VerificationKind::Contact, &email, later)
+            .await
+            .unwrap();
+    });
+}
+
+#[test]
+#[ignore = "needs DynamoDB Local at DYNAMODB_ENDPOINT"]
+fn code_expires_after_max_attempts() {
+    common::run(async {
+        let email = common::unique("attempts");
+        let now = chrono::Utc::now().timestamp() as u64;
+        let code = verification::issue(VerificationKind::Contact, &email, now)
+            .await
+            .unwrap();
+        let wrong = if code == "000000" { "000001" } else { "000000" };
+
+        for _ in 0..Verification::MAX_ATTEMPTS {
+            assert_eq!(
+                verification::check(VerificationKind::Contact, &email, wrong, now)
+                    .await
+                    .unwrap_err(),
+                ApiError::InvalidVerificationCode
+            );
+        }
+        assert_eq!(
+            verification::check(VerificationKind::Contact, &email, &code, now)
+                .await
+                .unwrap_err(),
+            ApiError::VerificationExpired
+        );
+    });
+}
+
+#[test]
+#[ignore = "needs DynamoDB Local at DYNAMODB_ENDPOINT"]
+fn concurrent_attempts_are_all_counted() {
+    common::run(async {
+        let email = common::unique("concurrent-attempts");
+        let now = chrono::Utc::now().timestamp() as u64;
+        let code = verification::issue(VerificationKind::Contact, &email, now)
+            .await
+            .unwrap();
+        let wrong = if code == "000000" { "000001" } else { "000000" };
+
+        let mut checks = tokio::task::JoinSet::new();
+        for _ in 0..2 * Verification::MAX_ATTEMPTS {
+            let email = email.clone();
+            checks.spawn(async move {
+                verification::check(VerificationKind::Contact, &email, wrong, now).await
+            });
+        }
+        let mut compared = 0;
+        while let Some(res) = checks.join_next().await {
+            if res.unwrap().unwrap_err() == ApiError::InvalidVerificationCode {
+                compared += 1;
+            }
+        }
+
+        assert!(compared <= Verification::MAX_ATTEMPTS);
+        assert_eq!(
+            verification::check(VerificationKind::Contact, &email, &code, now)
+                .await
+                .unwrap_err(),
+            ApiError::VerificationExpired
+        );
+    });
+}
+
+#[test]
+#[ignore = "needs DynamoDB Local at DYNAMODB_ENDPOINT"]
+fn codes_of_different_kinds_are_apart() {
+    common::run(async {
+        let email = common::unique("kinds");
+        let now = chrono::Utc::now().timestamp() as u64;
+        let code = verification::issue(VerificationKind::PasswordReset, &email, now)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            verification::check(VerificationKind::Contact, &email, &code, now)
+                .await
+                .unwrap_err(),
+            ApiError::InvalidVerificationCode
+        );
+        // a contact code issued meanwhile leaves the reset code valid
+        verification::issue(VerificationKind::Contact, &email, now)
+            .await
+            .unwrap();
+        verification::check(VerificationKind::PasswordReset, &email, &code, now)
+            .await
+            .unwrap();
+    });
+}